@@ -0,0 +1,142 @@
+//! Discrete-time feedback controllers.
+//!
+//! Controllers are stepped once per simulation tick with the elapsed time
+//! and never allocate.
+
+/// Discrete-time PID controller.
+///
+/// Uses backward-Euler discretization: the integral accumulates
+/// `error * dt` and the derivative is `(error - prev_error) / dt`.
+///
+/// # Example
+///
+/// ```
+/// use vd_math::control::Pid;
+///
+/// let mut pid = Pid::new(2.0, 0.5, 0.0);
+/// pid.set_integral_limit(10.0);
+/// let command = pid.update(100.0, 90.0, 0.01);
+/// assert!(command > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pid {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    integral: f64,
+    prev_error: f64,
+    integral_limit: f64,
+}
+
+impl Pid {
+    /// Creates a new PID controller with the given gains and no integral limit.
+    #[must_use]
+    pub const fn new(kp: f64, ki: f64, kd: f64) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral: 0.0,
+            prev_error: 0.0,
+            integral_limit: f64::INFINITY,
+        }
+    }
+
+    /// Limits the accumulated integral to `[-limit, limit]` to prevent windup.
+    ///
+    /// The sign of `limit` is ignored.
+    pub fn set_integral_limit(&mut self, limit: f64) {
+        self.integral_limit = libm::fabs(limit);
+        self.integral = self.clamp_integral(self.integral);
+    }
+
+    /// Computes the control output for one time step.
+    ///
+    /// A non-positive `dt` skips the integral and derivative updates and
+    /// returns the proportional term plus the current integral term.
+    pub fn update(&mut self, setpoint: f64, measurement: f64, dt: f64) -> f64 {
+        let error = setpoint - measurement;
+
+        let derivative = if dt > 0.0 {
+            self.integral = self.clamp_integral(self.integral + error * dt);
+            (error - self.prev_error) / dt
+        } else {
+            0.0
+        };
+        self.prev_error = error;
+
+        self.kp * error + self.ki * self.integral + self.kd * derivative
+    }
+
+    /// Clears the integral and derivative history.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.prev_error = 0.0;
+    }
+
+    /// Returns the accumulated integral of the error.
+    #[must_use]
+    pub const fn integral(&self) -> f64 {
+        self.integral
+    }
+
+    /// Returns the integral limit.
+    #[must_use]
+    pub const fn integral_limit(&self) -> f64 {
+        self.integral_limit
+    }
+
+    fn clamp_integral(&self, value: f64) -> f64 {
+        libm::fmax(-self.integral_limit, libm::fmin(value, self.integral_limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pid_integrator_plant_converges() {
+        let mut pid = Pid::new(2.0, 1.0, 0.05);
+        let dt = 0.01;
+        let mut x = 0.0;
+
+        // Plant: dx/dt = u
+        for _ in 0..2000 {
+            let u = pid.update(1.0, x, dt);
+            x += u * dt;
+        }
+
+        assert!((x - 1.0).abs() < 1e-3, "x = {x}");
+    }
+
+    #[test]
+    fn test_pid_integral_windup_clamped() {
+        let mut pid = Pid::new(0.0, 1.0, 0.0);
+        pid.set_integral_limit(0.5);
+
+        for _ in 0..1000 {
+            pid.update(10.0, 0.0, 0.01);
+        }
+        assert!((pid.integral() - 0.5).abs() < 1e-10);
+
+        for _ in 0..1000 {
+            pid.update(-10.0, 0.0, 0.01);
+        }
+        assert!((pid.integral() + 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_pid_reset() {
+        let mut pid = Pid::new(1.0, 1.0, 1.0);
+        pid.update(5.0, 0.0, 0.1);
+        assert!(pid.integral() > 0.0);
+
+        pid.reset();
+        assert!(pid.integral().abs() < 1e-10);
+        // No derivative kick from stale history after reset
+        let out = pid.update(0.0, 0.0, 0.1);
+        assert!(out.abs() < 1e-10);
+    }
+}
@@ -0,0 +1,211 @@
+//! Discrete-time signal filters.
+//!
+//! Lightweight filters intended to be stepped once per simulation tick.
+//! None of the filters allocate, so they are safe to use in hot loops.
+
+/// First-order low-pass filter (exponential smoothing).
+///
+/// # Example
+///
+/// ```
+/// use vd_math::filters::LowPassFilter;
+///
+/// // 10 Hz cutoff sampled at 1 kHz
+/// let mut filter = LowPassFilter::new(10.0, 1000.0);
+/// let smoothed = filter.update(1.0);
+/// assert!(smoothed > 0.0 && smoothed < 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LowPassFilter {
+    alpha: f64,
+    state: f64,
+}
+
+impl LowPassFilter {
+    /// Creates a new low-pass filter with initial state 0.0.
+    ///
+    /// The smoothing factor is derived from the RC time constant
+    /// `tau = 1 / (2 * PI * cutoff_hz)` as `alpha = 1 - exp(-dt / tau)`.
+    #[must_use]
+    pub fn new(cutoff_hz: f64, sample_rate_hz: f64) -> Self {
+        let tau = 1.0 / (2.0 * core::f64::consts::PI * cutoff_hz);
+        let dt = 1.0 / sample_rate_hz;
+        Self {
+            alpha: 1.0 - libm::exp(-dt / tau),
+            state: 0.0,
+        }
+    }
+
+    /// Feeds a new sample and returns the filtered output.
+    #[inline]
+    pub fn update(&mut self, input: f64) -> f64 {
+        self.state += self.alpha * (input - self.state);
+        self.state
+    }
+
+    /// Resets the filter output to `value`.
+    #[inline]
+    pub fn reset(&mut self, value: f64) {
+        self.state = value;
+    }
+
+    /// Returns the current filter output.
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> f64 {
+        self.state
+    }
+
+    /// Returns the smoothing factor applied per sample.
+    #[inline]
+    #[must_use]
+    pub const fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+/// Slew-rate limiter bounding how fast a signal may change.
+///
+/// # Example
+///
+/// ```
+/// use vd_math::filters::RateLimiter;
+///
+/// // Throttle may open at most 2 units per second
+/// let mut limiter = RateLimiter::new(2.0);
+/// let out = limiter.update(1.0, 0.1);
+/// assert!((out - 0.2).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateLimiter {
+    max_rate: f64,
+    state: f64,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter with initial state 0.0.
+    ///
+    /// `max_rate` is the maximum change per second; its sign is ignored.
+    #[must_use]
+    pub fn new(max_rate: f64) -> Self {
+        Self {
+            max_rate: libm::fabs(max_rate),
+            state: 0.0,
+        }
+    }
+
+    /// Moves the output towards `input`, limited to `max_rate * dt`.
+    ///
+    /// A non-positive `dt` leaves the output unchanged.
+    #[inline]
+    pub fn update(&mut self, input: f64, dt: f64) -> f64 {
+        if dt > 0.0 {
+            let max_step = self.max_rate * dt;
+            let step = libm::fmax(-max_step, libm::fmin(input - self.state, max_step));
+            self.state += step;
+        }
+        self.state
+    }
+
+    /// Resets the output to `value`.
+    #[inline]
+    pub fn reset(&mut self, value: f64) {
+        self.state = value;
+    }
+
+    /// Returns the current output.
+    #[inline]
+    #[must_use]
+    pub const fn current(&self) -> f64 {
+        self.state
+    }
+
+    /// Returns the maximum rate of change per second.
+    #[inline]
+    #[must_use]
+    pub const fn max_rate(&self) -> f64 {
+        self.max_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_low_pass_step_response() {
+        let cutoff = 5.0;
+        let rate = 1000.0;
+        let tau = 1.0 / (2.0 * core::f64::consts::PI * cutoff);
+        let steps = libm::ceil(5.0 * tau * rate) as usize;
+
+        let mut filter = LowPassFilter::new(cutoff, rate);
+        let mut out = 0.0;
+        for _ in 0..steps {
+            out = filter.update(1.0);
+        }
+
+        assert!((out - 1.0).abs() < 0.01, "output {out} after 5 tau");
+        assert!(out < 1.0);
+    }
+
+    #[test]
+    fn test_low_pass_one_tau() {
+        let rate = 1000.0;
+        let mut filter = LowPassFilter::new(1.0, rate);
+        let tau_steps = libm::round(rate / (2.0 * core::f64::consts::PI)) as usize;
+        for _ in 0..tau_steps {
+            filter.update(1.0);
+        }
+
+        // ~63.2% after one time constant
+        assert!((filter.state() - 0.632).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_low_pass_reset() {
+        let mut filter = LowPassFilter::new(10.0, 100.0);
+        filter.update(5.0);
+        filter.reset(2.0);
+        assert!((filter.state() - 2.0).abs() < 1e-10);
+
+        // Steady state input leaves the output unchanged
+        assert!((filter.update(2.0) - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rate_limiter_step_input() {
+        let mut limiter = RateLimiter::new(1.0);
+        let mut prev = limiter.current();
+        for _ in 0..5 {
+            let out = limiter.update(10.0, 0.1);
+            assert!(out - prev <= 0.1 + 1e-12);
+            prev = out;
+        }
+        assert!((limiter.current() - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rate_limiter_reaches_target() {
+        let mut limiter = RateLimiter::new(1.0);
+        for _ in 0..25 {
+            limiter.update(2.0, 0.1);
+        }
+        assert!((limiter.current() - 2.0).abs() < 1e-10);
+
+        // Falling edge is limited symmetrically
+        let out = limiter.update(-2.0, 0.1);
+        assert!((out - 1.9).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rate_limiter_reset_and_zero_dt() {
+        let mut limiter = RateLimiter::new(-3.0);
+        assert!((limiter.max_rate() - 3.0).abs() < 1e-10);
+
+        limiter.reset(4.0);
+        assert!((limiter.update(0.0, 0.0) - 4.0).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,129 @@
+//! Fixed-step numerical integrators for ODE state propagation.
+//!
+//! Each integrator advances a state `x` governed by `dx/dt = f(x)` by a
+//! single time step. Scalar and [`Vec3`] states are supported.
+
+use core::ops::{Add, Mul};
+
+use crate::linear::Vec3;
+
+/// Fixed-step integrator for autonomous ODEs `dx/dt = f(x)`.
+pub trait Integrator {
+    /// Advances a scalar state by `dt`.
+    fn step(&self, state: f64, derivative_fn: impl Fn(f64) -> f64, dt: f64) -> f64;
+
+    /// Advances a vector state by `dt`.
+    fn step_vec3(&self, state: Vec3, f: impl Fn(Vec3) -> Vec3, dt: f64) -> Vec3;
+}
+
+/// First-order explicit (forward) Euler integrator.
+///
+/// Global error is O(dt).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EulerIntegrator;
+
+/// Classical fourth-order Runge-Kutta integrator.
+///
+/// Global error is O(dt^4), at the cost of four derivative evaluations per step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rk4Integrator;
+
+impl Integrator for EulerIntegrator {
+    #[inline]
+    fn step(&self, state: f64, derivative_fn: impl Fn(f64) -> f64, dt: f64) -> f64 {
+        euler_step(state, derivative_fn, dt)
+    }
+
+    #[inline]
+    fn step_vec3(&self, state: Vec3, f: impl Fn(Vec3) -> Vec3, dt: f64) -> Vec3 {
+        euler_step(state, f, dt)
+    }
+}
+
+impl Integrator for Rk4Integrator {
+    #[inline]
+    fn step(&self, state: f64, derivative_fn: impl Fn(f64) -> f64, dt: f64) -> f64 {
+        rk4_step(state, derivative_fn, dt)
+    }
+
+    #[inline]
+    fn step_vec3(&self, state: Vec3, f: impl Fn(Vec3) -> Vec3, dt: f64) -> Vec3 {
+        rk4_step(state, f, dt)
+    }
+}
+
+#[inline]
+fn euler_step<S>(state: S, f: impl Fn(S) -> S, dt: f64) -> S
+where
+    S: Copy + Add<Output = S> + Mul<f64, Output = S>,
+{
+    state + f(state) * dt
+}
+
+#[inline]
+fn rk4_step<S>(state: S, f: impl Fn(S) -> S, dt: f64) -> S
+where
+    S: Copy + Add<Output = S> + Mul<f64, Output = S>,
+{
+    let half = dt * 0.5;
+    let k1 = f(state);
+    let k2 = f(state + k1 * half);
+    let k3 = f(state + k2 * half);
+    let k4 = f(state + k3 * dt);
+    state + (k1 + k2 * 2.0 + k3 * 2.0 + k4) * (dt / 6.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integrate_decay<I: Integrator>(integrator: &I, dt: f64, steps: usize) -> f64 {
+        let mut x = 1.0;
+        for _ in 0..steps {
+            x = integrator.step(x, |x| -x, dt);
+        }
+        x
+    }
+
+    #[test]
+    fn test_euler_first_order_error() {
+        let exact = libm::exp(-1.0);
+        let err_fine = (integrate_decay(&EulerIntegrator, 0.001, 1000) - exact).abs();
+        let err_coarse = (integrate_decay(&EulerIntegrator, 0.002, 500) - exact).abs();
+
+        assert!(err_fine < 1e-3);
+        // Halving dt halves the error
+        let ratio = err_coarse / err_fine;
+        assert!((ratio - 2.0).abs() < 0.05, "ratio {ratio}");
+    }
+
+    #[test]
+    fn test_rk4_fourth_order_error() {
+        let exact = libm::exp(-1.0);
+        let err_fine = (integrate_decay(&Rk4Integrator, 0.001, 1000) - exact).abs();
+        assert!(err_fine < 1e-12, "error {err_fine}");
+
+        // Coarse steps keep the error well above round-off to check the order
+        let err_a = (integrate_decay(&Rk4Integrator, 0.1, 10) - exact).abs();
+        let err_b = (integrate_decay(&Rk4Integrator, 0.05, 20) - exact).abs();
+        let ratio = err_a / err_b;
+        assert!((ratio - 16.0).abs() < 1.0, "ratio {ratio}");
+    }
+
+    #[test]
+    fn test_step_vec3_rotation() {
+        // dx/dt = w x x with w = z-axis: rotation in the XY plane
+        let omega = Vec3::z();
+        let dt = 0.001;
+        let mut v = Vec3::x();
+        for _ in 0..1000 {
+            v = Rk4Integrator.step_vec3(v, |v| omega.cross(&v), dt);
+        }
+
+        assert!((v.x - libm::cos(1.0)).abs() < 1e-10);
+        assert!((v.y - libm::sin(1.0)).abs() < 1e-10);
+
+        let euler = EulerIntegrator.step_vec3(Vec3::x(), |v| omega.cross(&v), 0.1);
+        assert!((euler - Vec3::new(1.0, 0.1, 0.0)).magnitude() < 1e-12);
+    }
+}
@@ -5,6 +5,12 @@
 //! This crate provides:
 //! - Linear algebra wrappers around `nalgebra` types
 //! - 1D, 2D, and 3D lookup tables with efficient interpolation
+//! - Discrete-time signal filters for sensor and actuator models
+//! - Feedback controllers (PID)
+//! - Fixed-step ODE integrators (Euler, RK4)
+//! - Smooth transition functions (smoothstep, sigmoid)
+//! - Polynomial evaluation and composition (Horner's method)
+//! - Signal analysis helpers (RMS, peak hold)
 //!
 //! ## Features
 //!
@@ -34,8 +40,17 @@
 
 extern crate alloc;
 
+pub mod control;
+pub mod filters;
+pub mod integrator;
 pub mod linear;
 pub mod lut;
+pub mod polynomial;
+pub mod signal;
+pub mod smooth;
 
-pub use linear::{Mat3, Quat, Vec3};
-pub use lut::{Lut1D, Lut2D, Lut3D, LutError};
+pub use control::Pid;
+pub use filters::{LowPassFilter, RateLimiter};
+pub use integrator::{EulerIntegrator, Integrator, Rk4Integrator};
+pub use linear::{Aabb, Mat3, Quat, Ray, Vec3, Vec4};
+pub use lut::{Lut1D, Lut2D, Lut3D, LutBinaryError, LutError, ParseError};
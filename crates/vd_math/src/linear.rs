@@ -3,17 +3,162 @@
 //! This module provides convenient type aliases and helper functions
 //! for working with `nalgebra` types in the vehicle dynamics context.
 
-use nalgebra::{Matrix3, UnitQuaternion, Vector3};
+use nalgebra::{Matrix3, Quaternion, UnitQuaternion, Vector3, Vector4};
 
 /// 3D vector with f64 precision.
 pub type Vec3 = Vector3<f64>;
 
+/// 4D vector with f64 precision, used for homogeneous coordinates.
+pub type Vec4 = Vector4<f64>;
+
 /// 3x3 matrix with f64 precision.
 pub type Mat3 = Matrix3<f64>;
 
 /// Unit quaternion for rotations with f64 precision.
 pub type Quat = UnitQuaternion<f64>;
 
+/// Axis-aligned bounding box.
+///
+/// Bounds are inclusive: points on a face are inside the box, and boxes that
+/// share a face intersect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    /// Corner with the smallest coordinates.
+    pub min: Vec3,
+    /// Corner with the largest coordinates.
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Creates a box from its corners; `min` must not exceed `max` on any axis.
+    #[inline]
+    #[must_use]
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the smallest box containing every point, or `None` if
+    /// `points` is empty.
+    #[must_use]
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut aabb = Self::new(*first, *first);
+        for p in rest {
+            aabb.expand_to_include(p);
+        }
+        Some(aabb)
+    }
+
+    /// Returns true if `p` lies inside or on the boundary of the box.
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, p: &Vec3) -> bool {
+        (0..3).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    /// Returns true if the boxes overlap or touch.
+    #[inline]
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// Grows the box just enough to contain `p`.
+    #[inline]
+    pub fn expand_to_include(&mut self, p: &Vec3) {
+        self.min = self.min.inf(p);
+        self.max = self.max.sup(p);
+    }
+
+    /// Returns the centre of the box.
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns half the size of the box along each axis.
+    #[inline]
+    #[must_use]
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+}
+
+/// Half-line from `origin` along a unit `direction`, for sensor ray casts.
+///
+/// Because the direction is normalized, the ray parameter `t` is the
+/// distance from the origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray {
+    /// Start point of the ray.
+    pub origin: Vec3,
+    /// Unit direction of the ray.
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// Creates a ray, normalizing `direction`.
+    #[inline]
+    #[must_use]
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, t: f64) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the distance at which the ray hits the plane through `point`
+    /// with the given `normal`.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or the plane lies
+    /// behind the origin.
+    #[must_use]
+    pub fn intersect_plane(&self, normal: &Vec3, point: &Vec3) -> Option<f64> {
+        let denom = normal.dot(&self.direction);
+        if libm::fabs(denom) <= f64::EPSILON * normal.norm() {
+            return None;
+        }
+        let t = normal.dot(&(point - self.origin)) / denom;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Returns the entry and exit distances `(t_near, t_far)` of the ray
+    /// through `aabb`, using the slab method.
+    ///
+    /// `t_near` is negative when the origin is inside the box. Returns
+    /// `None` if the ray misses the box or the box lies behind the origin.
+    #[must_use]
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<(f64, f64)> {
+        let mut t_near = f64::NEG_INFINITY;
+        let mut t_far = f64::INFINITY;
+        for i in 0..3 {
+            let (o, d) = (self.origin[i], self.direction[i]);
+            if d == 0.0 {
+                // Parallel to this slab: either always inside it or never
+                if o < aabb.min[i] || o > aabb.max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (aabb.min[i] - o) / d;
+            let t2 = (aabb.max[i] - o) / d;
+            t_near = t_near.max(t1.min(t2));
+            t_far = t_far.min(t1.max(t2));
+        }
+        (t_near <= t_far && t_far >= 0.0).then_some((t_near, t_far))
+    }
+}
+
 /// Creates a new 3D vector from components.
 #[inline]
 #[must_use]
@@ -21,6 +166,28 @@ pub const fn vec3(x: f64, y: f64, z: f64) -> Vec3 {
     Vec3::new(x, y, z)
 }
 
+/// Creates a vector from an `[x, y, z]` array, e.g. one read from FFI.
+#[inline]
+#[must_use]
+pub const fn vec3_from_array(arr: &[f64; 3]) -> Vec3 {
+    Vec3::new(arr[0], arr[1], arr[2])
+}
+
+/// Borrows the components as an `[x, y, z]` array without copying.
+#[inline]
+#[must_use]
+pub const fn vec3_as_array(v: &Vec3) -> &[f64; 3] {
+    &v.data.0[0]
+}
+
+/// Returns a pointer to the `x` component, followed contiguously by `y` and
+/// `z`, for passing positions to C as `*const f64`.
+#[inline]
+#[must_use]
+pub const fn vec3_as_ptr(v: &Vec3) -> *const f64 {
+    vec3_as_array(v).as_ptr()
+}
+
 /// Creates the zero vector.
 #[inline]
 #[must_use]
@@ -49,6 +216,33 @@ pub fn vec3_z() -> Vec3 {
     Vec3::z()
 }
 
+/// Converts a point to homogeneous coordinates (`w = 1`).
+#[inline]
+#[must_use]
+pub fn to_homogeneous(v: &Vec3) -> Vec4 {
+    Vec4::new(v.x, v.y, v.z, 1.0)
+}
+
+/// Converts a direction to homogeneous coordinates (`w = 0`), so that
+/// translations leave it unchanged.
+#[inline]
+#[must_use]
+pub fn to_homogeneous_vector(v: &Vec3) -> Vec4 {
+    Vec4::new(v.x, v.y, v.z, 0.0)
+}
+
+/// Projects homogeneous coordinates back to 3D by dividing by `w`.
+///
+/// Returns `None` if `w` is zero, i.e. for directions and points at infinity.
+#[inline]
+#[must_use]
+pub fn perspective_divide(v: &Vec4) -> Option<Vec3> {
+    if v.w == 0.0 {
+        return None;
+    }
+    Some(Vec3::new(v.x / v.w, v.y / v.w, v.z / v.w))
+}
+
 /// Creates the identity matrix.
 #[inline]
 #[must_use]
@@ -65,6 +259,18 @@ pub fn mat3_from_euler(roll: f64, pitch: f64, yaw: f64) -> Mat3 {
     *quat.to_rotation_matrix().matrix()
 }
 
+/// Creates a rotation matrix from axis-angle representation.
+///
+/// Uses Rodrigues' rotation formula directly, without going through a
+/// quaternion. The axis is normalized first; `angle` is in radians.
+#[inline]
+#[must_use]
+pub fn mat3_from_axis_angle(axis: &Vec3, angle: f64) -> Mat3 {
+    let a = axis.normalize();
+    let (sin, cos) = libm::sincos(angle);
+    Mat3::identity() * cos + a * a.transpose() * (1.0 - cos) + a.cross_matrix() * sin
+}
+
 /// Creates an identity quaternion (no rotation).
 #[inline]
 #[must_use]
@@ -72,18 +278,57 @@ pub fn quat_identity() -> Quat {
     Quat::identity()
 }
 
+/// Returns the representative of `q` with a non-negative scalar part.
+///
+/// `q` and `-q` describe the same rotation; picking the positive-`w`
+/// hemisphere gives each orientation a single, comparable representation.
+#[inline]
+#[must_use]
+pub fn quat_canonical(q: Quat) -> Quat {
+    if q.w < 0.0 {
+        Quat::new_unchecked(-q.into_inner())
+    } else {
+        q
+    }
+}
+
 /// Creates a quaternion from Euler angles (roll, pitch, yaw) in radians.
+///
+/// The result is in canonical form (see [`quat_canonical`]).
 #[inline]
 #[must_use]
 pub fn quat_from_euler(roll: f64, pitch: f64, yaw: f64) -> Quat {
-    Quat::from_euler_angles(roll, pitch, yaw)
+    quat_canonical(Quat::from_euler_angles(roll, pitch, yaw))
 }
 
 /// Creates a quaternion from axis-angle representation.
+///
+/// The result is in canonical form (see [`quat_canonical`]), so angles
+/// beyond pi yield the equivalent shorter rotation about the opposite axis.
 #[inline]
 #[must_use]
 pub fn quat_from_axis_angle(axis: &Vec3, angle: f64) -> Quat {
-    Quat::from_axis_angle(&nalgebra::Unit::new_normalize(*axis), angle)
+    quat_canonical(Quat::from_axis_angle(
+        &nalgebra::Unit::new_normalize(*axis),
+        angle,
+    ))
+}
+
+/// Advances an orientation by one explicit Euler step of angular velocity.
+///
+/// `q` rotates body-frame vectors into the world frame and `omega` is the
+/// angular velocity expressed in the body frame (as measured by a gyro
+/// mounted on the body), in rad/s. The step applies the quaternion
+/// derivative `q_dot = 0.5 * q * (0, omega)` and renormalizes the result.
+///
+/// The first-order error grows with `|omega| * dt`, so keep the step small
+/// relative to the rotation rate.
+#[inline]
+#[must_use]
+pub fn quat_integrate_angular_velocity(q: &Quat, omega: &Vec3, dt: f64) -> Quat {
+    let q = q.into_inner();
+    let q_dot = q * Quaternion::from_imag(*omega) * 0.5;
+    Quat::new_normalize(q + q_dot * dt)
 }
 
 #[cfg(test)]
@@ -98,6 +343,17 @@ mod tests {
         assert!((v.z - 3.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_vec3_array_round_trip() {
+        let v = vec3(1.0, 2.0, 3.0);
+        let arr = vec3_as_array(&v);
+        assert_eq!(arr.as_slice(), &[1.0, 2.0, 3.0]);
+        assert_eq!(vec3_from_array(arr), v);
+
+        assert_eq!(vec3_as_ptr(&v), core::ptr::from_ref(&v.x));
+        assert_eq!(vec3_as_ptr(&v), arr.as_ptr());
+    }
+
     #[test]
     fn test_vec3_magnitude() {
         let v = vec3(3.0, 4.0, 0.0);
@@ -121,6 +377,116 @@ mod tests {
         assert!((z.z - 1.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_homogeneous_round_trip() {
+        let p = vec3(1.5, -2.0, 30.0);
+        let h = to_homogeneous(&p);
+        assert!((h.w - 1.0).abs() < 1e-12);
+        let back = perspective_divide(&h).expect("finite point");
+        assert!((back - p).magnitude() < 1e-12);
+
+        // Scaled homogeneous coordinates describe the same point
+        let back = perspective_divide(&(h * 4.0)).expect("finite point");
+        assert!((back - p).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_homogeneous_direction() {
+        let d = to_homogeneous_vector(&vec3_x());
+        assert!(d.w.abs() < 1e-12);
+        assert!((d.xyz() - vec3_x()).magnitude() < 1e-12);
+        assert_eq!(perspective_divide(&d), None);
+        assert_eq!(perspective_divide(&Vec4::new(1.0, 2.0, 3.0, -0.0)), None);
+    }
+
+    #[test]
+    fn test_aabb_contains_point() {
+        let aabb = Aabb::new(vec3(-1.0, 0.0, 2.0), vec3(1.0, 4.0, 3.0));
+        assert!(aabb.contains_point(&vec3(0.0, 2.0, 2.5)));
+        assert!(aabb.contains_point(&vec3(-1.0, 4.0, 2.0)));
+        assert!(aabb.contains_point(&vec3(1.0, 0.0, 3.0)));
+        assert!(!aabb.contains_point(&vec3(1.0 + 1e-9, 2.0, 2.5)));
+        assert!(!aabb.contains_point(&vec3(0.0, 2.0, 1.9)));
+
+        assert!((aabb.center() - vec3(0.0, 2.0, 2.5)).magnitude() < 1e-12);
+        assert!((aabb.half_extents() - vec3(1.0, 2.0, 0.5)).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_aabb_intersects() {
+        let a = Aabb::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0));
+        let touching = Aabb::new(vec3(1.0, 0.5, 0.5), vec3(2.0, 2.0, 2.0));
+        let overlapping = Aabb::new(vec3(0.5, -1.0, 0.5), vec3(0.6, 0.1, 3.0));
+        let apart = Aabb::new(vec3(0.0, 1.5, 0.0), vec3(1.0, 2.0, 1.0));
+
+        assert!(a.intersects(&touching) && touching.intersects(&a));
+        assert!(a.intersects(&overlapping));
+        assert!(!a.intersects(&apart) && !apart.intersects(&a));
+    }
+
+    #[test]
+    fn test_aabb_from_points() {
+        assert_eq!(Aabb::from_points(&[]), None);
+
+        let points = [
+            vec3(1.0, -2.0, 0.5),
+            vec3(-3.0, 4.0, 0.0),
+            vec3(0.0, 0.0, 7.0),
+        ];
+        let aabb = Aabb::from_points(&points).expect("non-empty");
+        assert!((aabb.min - vec3(-3.0, -2.0, 0.0)).magnitude() < 1e-12);
+        assert!((aabb.max - vec3(1.0, 4.0, 7.0)).magnitude() < 1e-12);
+        assert!(points.iter().all(|p| aabb.contains_point(p)));
+
+        let single = Aabb::from_points(&points[..1]).expect("non-empty");
+        assert_eq!(single.min, single.max);
+
+        let mut grown = single;
+        grown.expand_to_include(&vec3(2.0, -2.0, 0.0));
+        assert!((grown.max - vec3(2.0, -2.0, 0.5)).magnitude() < 1e-12);
+        assert!((grown.min - vec3(1.0, -2.0, 0.0)).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb() {
+        let aabb = Aabb::new(vec3(1.0, -1.0, -1.0), vec3(2.0, 1.0, 1.0));
+
+        let ray = Ray::new(vec3_zero(), vec3(5.0, 0.0, 0.0));
+        assert!((ray.direction - vec3_x()).magnitude() < 1e-12);
+        let (near, far) = ray.intersect_aabb(&aabb).expect("hit");
+        assert!((near - 1.0).abs() < 1e-12);
+        assert!((far - 2.0).abs() < 1e-12);
+        assert!((ray.at(near) - vec3(1.0, 0.0, 0.0)).magnitude() < 1e-12);
+
+        let inside = Ray::new(vec3(1.5, 0.0, 0.0), vec3_x());
+        let (near, far) = inside.intersect_aabb(&aabb).expect("hit");
+        assert!((near + 0.5).abs() < 1e-12);
+        assert!((far - 0.5).abs() < 1e-12);
+
+        let away = Ray::new(vec3_zero(), -vec3_x());
+        assert_eq!(away.intersect_aabb(&aabb), None);
+        let miss = Ray::new(vec3(0.0, 2.0, 0.0), vec3_x());
+        assert_eq!(miss.intersect_aabb(&aabb), None);
+        let diagonal = Ray::new(vec3(0.0, -1.0, 0.0), vec3(1.0, 1.0, 0.0));
+        assert!(diagonal.intersect_aabb(&aabb).is_some());
+    }
+
+    #[test]
+    fn test_ray_intersect_plane() {
+        let ground = vec3_z();
+        let ray = Ray::new(vec3(0.0, 0.0, 2.0), vec3(1.0, 0.0, -1.0));
+        let t = ray
+            .intersect_plane(&ground, &vec3_zero())
+            .expect("hits ground");
+        assert!((t - 2.0 * core::f64::consts::SQRT_2).abs() < 1e-12);
+        assert!(ray.at(t).z.abs() < 1e-12);
+
+        let parallel = Ray::new(vec3(0.0, 0.0, 2.0), vec3_x());
+        assert_eq!(parallel.intersect_plane(&ground, &vec3_zero()), None);
+        let upward = Ray::new(vec3(0.0, 0.0, 2.0), vec3_z());
+        assert_eq!(upward.intersect_plane(&ground, &vec3_zero()), None);
+    }
+
     #[test]
     fn test_mat3_identity() {
         let m = mat3_identity();
@@ -151,4 +517,109 @@ mod tests {
         assert!((result.y - 1.0).abs() < 1e-10);
         assert!((result.z).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_mat3_from_axis_angle_cases() {
+        use core::f64::consts::{FRAC_PI_2, PI};
+
+        let m = mat3_from_axis_angle(&vec3(1.0, 2.0, 3.0), 0.0);
+        assert!((m - mat3_identity()).norm() < 1e-12);
+
+        let m = mat3_from_axis_angle(&vec3_z(), FRAC_PI_2);
+        assert!((m * vec3_x() - vec3_y()).magnitude() < 1e-12);
+
+        // Axis length does not matter
+        let m = mat3_from_axis_angle(&vec3(0.0, 5.0, 0.0), PI);
+        assert!((m * vec3_x() + vec3_x()).magnitude() < 1e-12);
+        assert!((m * vec3_z() + vec3_z()).magnitude() < 1e-12);
+        assert!((m * vec3_y() - vec3_y()).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_mat3_from_axis_angle_matches_quat() {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut uniform = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+
+        for _ in 0..50 {
+            let axis = vec3(uniform(), uniform(), uniform());
+            let angle = uniform() * 10.0;
+            let expected = *quat_from_axis_angle(&axis, angle)
+                .to_rotation_matrix()
+                .matrix();
+            let m = mat3_from_axis_angle(&axis, angle);
+            assert!(
+                (m - expected).norm() < 1e-12,
+                "axis {axis:?}, angle {angle}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quat_canonical() {
+        use core::f64::consts::PI;
+
+        let q = quat_from_axis_angle(&vec3(1.0, -2.0, 0.5), 1.5 * PI);
+        assert!(q.w >= 0.0);
+        let raw = Quat::from_axis_angle(
+            &nalgebra::Unit::new_normalize(vec3(1.0, -2.0, 0.5)),
+            1.5 * PI,
+        );
+        assert!(raw.w < 0.0);
+        assert!(q.angle_to(&raw) < 1e-12);
+
+        let negated = Quat::new_unchecked(-q.into_inner());
+        assert_eq!(quat_canonical(negated), q);
+        assert_eq!(quat_canonical(quat_canonical(raw)), quat_canonical(raw));
+        assert!(quat_from_euler(3.0, -1.0, 2.5).w >= 0.0);
+
+        // Both signs interpolate along the same (short) path
+        let start = quat_identity();
+        let v = vec3(0.3, 1.0, -2.0);
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let a = quat_canonical(start.slerp(&q, t));
+            let b = quat_canonical(start.slerp(&negated, t));
+            assert!((a.into_inner() - b.into_inner()).norm() < 1e-12, "t = {t}");
+            assert!((a * v - b * v).magnitude() < 1e-12);
+        }
+        let halfway = start.slerp(&q, 0.5);
+        assert!((halfway.angle() - 0.5 * q.angle()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quat_integrate_angular_velocity() {
+        use core::f64::consts::PI;
+
+        let steps = 3142;
+        let dt = PI / f64::from(steps);
+        let omega = vec3_z();
+        let mut q = quat_identity();
+        for _ in 0..steps {
+            q = quat_integrate_angular_velocity(&q, &omega, dt);
+        }
+
+        let expected = quat_from_axis_angle(&vec3_z(), PI);
+        let error_deg = q.angle_to(&expected).to_degrees();
+        assert!(error_deg < 0.01, "error {error_deg} deg");
+    }
+
+    #[test]
+    fn test_quat_integrate_uses_body_frame() {
+        use core::f64::consts::FRAC_PI_2;
+
+        // Yawed 90 degrees, then spinning about the body X axis, which now
+        // points along world Y
+        let mut q = quat_from_axis_angle(&vec3_z(), FRAC_PI_2);
+        for _ in 0..1000 {
+            q = quat_integrate_angular_velocity(&q, &vec3_x(), 1e-4);
+        }
+
+        let expected =
+            quat_from_axis_angle(&vec3_y(), 0.1) * quat_from_axis_angle(&vec3_z(), FRAC_PI_2);
+        assert!(q.angle_to(&expected) < 1e-4);
+    }
 }
@@ -0,0 +1,389 @@
+//! Compact binary encoding for lookup tables.
+//!
+//! A `serde`-free format for embedded targets. Each table starts with a
+//! 4-byte magic tag, followed by `u32` axis lengths, a `u32` flags word for
+//! the table types that have options (periodic axes, Hermite tangents), the
+//! axis values, and the data values. All numbers are little-endian.
+
+use alloc::vec::Vec;
+
+use super::{Lut1D, Lut2D, Lut3D, LutBinaryError};
+
+const LUT1D_MAGIC: [u8; 4] = *b"L1DB";
+const LUT2D_MAGIC: [u8; 4] = *b"L2DB";
+const LUT3D_MAGIC: [u8; 4] = *b"L3DB";
+
+/// Flag bit: lookups wrap around the X axis.
+const FLAG_PERIODIC_X: u32 = 1;
+/// Flag bit: lookups wrap around the Y axis.
+const FLAG_PERIODIC_Y: u32 = 1 << 1;
+/// Flag bit: Hermite tangents follow the data.
+const FLAG_TANGENTS: u32 = 1 << 2;
+
+/// Appends a `u32` length to `out`.
+fn write_len(out: &mut Vec<u8>, len: usize) -> Result<(), LutBinaryError> {
+    let len = u32::try_from(len).map_err(|_| LutBinaryError::AxisTooLong { len })?;
+    out.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+/// Appends `values` to `out`.
+fn write_values(out: &mut Vec<u8>, values: &[f64]) {
+    for v in values {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+}
+
+/// Sequential reader over an encoded LUT.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], LutBinaryError> {
+        if self.bytes.len() < len {
+            return Err(LutBinaryError::Truncated);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn expect_magic(&mut self, magic: [u8; 4]) -> Result<(), LutBinaryError> {
+        if self.take(4)? == magic {
+            Ok(())
+        } else {
+            Err(LutBinaryError::BadMagic)
+        }
+    }
+
+    fn read_u32(&mut self) -> Result<u32, LutBinaryError> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_len(&mut self) -> Result<usize, LutBinaryError> {
+        Ok(self.read_u32()? as usize)
+    }
+
+    /// Reads the flags word, rejecting bits outside `known`.
+    fn read_flags(&mut self, known: u32) -> Result<u32, LutBinaryError> {
+        let flags = self.read_u32()?;
+        if flags & !known == 0 {
+            Ok(flags)
+        } else {
+            Err(LutBinaryError::UnknownFlags { flags })
+        }
+    }
+
+    fn read_values(&mut self, count: usize) -> Result<Vec<f64>, LutBinaryError> {
+        let len = count.checked_mul(8).ok_or(LutBinaryError::Truncated)?;
+        let raw = self.take(len)?;
+        Ok(raw
+            .chunks_exact(8)
+            .map(|chunk| {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(chunk);
+                f64::from_le_bytes(buf)
+            })
+            .collect())
+    }
+}
+
+impl Lut1D {
+    /// Encodes the LUT as `b"L1DB"`, `u32` count, `u32` flags, X axis, data,
+    /// then the Hermite tangents if the table has them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutBinaryError::AxisTooLong` if the table has more than
+    /// `u32::MAX` points.
+    pub fn to_binary(&self) -> Result<Vec<u8>, LutBinaryError> {
+        let tangents = self.tangents().unwrap_or_default();
+        let mut out = Vec::with_capacity(12 + 8 * (2 * self.len() + tangents.len()));
+        out.extend_from_slice(&LUT1D_MAGIC);
+        write_len(&mut out, self.len())?;
+        let mut flags = 0;
+        if self.is_periodic_x() {
+            flags |= FLAG_PERIODIC_X;
+        }
+        if !tangents.is_empty() {
+            flags |= FLAG_TANGENTS;
+        }
+        out.extend_from_slice(&flags.to_le_bytes());
+        write_values(&mut out, self.x_axis());
+        write_values(&mut out, self.data());
+        write_values(&mut out, tangents);
+        Ok(out)
+    }
+
+    /// Decodes a LUT previously encoded with [`Lut1D::to_binary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutBinaryError` if the magic tag is wrong, the input is
+    /// truncated or has unknown flags, or the decoded values fail
+    /// [`Lut1D::new`], [`Lut1D::with_hermite_tangents`] or
+    /// [`Lut1D::with_periodic_x`] validation.
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, LutBinaryError> {
+        let mut reader = Reader { bytes };
+        reader.expect_magic(LUT1D_MAGIC)?;
+        let count = reader.read_len()?;
+        let flags = reader.read_flags(FLAG_PERIODIC_X | FLAG_TANGENTS)?;
+        let x_axis = reader.read_values(count)?;
+        let data = reader.read_values(count)?;
+        let lut = if flags & FLAG_TANGENTS == 0 {
+            Self::new(x_axis, data)?
+        } else {
+            let tangents = reader.read_values(count)?;
+            Self::with_hermite_tangents(x_axis, data, tangents)?
+        };
+        if flags & FLAG_PERIODIC_X == 0 {
+            Ok(lut)
+        } else {
+            Ok(lut.with_periodic_x()?)
+        }
+    }
+}
+
+impl Lut2D {
+    /// Encodes the LUT as `b"L2DB"`, `u32` X and Y lengths, `u32` flags, both
+    /// axes, then data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutBinaryError::AxisTooLong` if an axis has more than
+    /// `u32::MAX` points.
+    pub fn to_binary(&self) -> Result<Vec<u8>, LutBinaryError> {
+        let mut out = Vec::with_capacity(
+            16 + 8 * (self.x_axis().len() + self.y_axis().len() + self.data().len()),
+        );
+        out.extend_from_slice(&LUT2D_MAGIC);
+        write_len(&mut out, self.x_axis().len())?;
+        write_len(&mut out, self.y_axis().len())?;
+        let mut flags = 0;
+        if self.is_periodic_x() {
+            flags |= FLAG_PERIODIC_X;
+        }
+        if self.is_periodic_y() {
+            flags |= FLAG_PERIODIC_Y;
+        }
+        out.extend_from_slice(&flags.to_le_bytes());
+        write_values(&mut out, self.x_axis());
+        write_values(&mut out, self.y_axis());
+        write_values(&mut out, self.data());
+        Ok(out)
+    }
+
+    /// Decodes a LUT previously encoded with [`Lut2D::to_binary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutBinaryError` if the magic tag is wrong, the input is
+    /// truncated or has unknown flags, or the decoded values fail
+    /// [`Lut2D::new`] or periodic axis validation.
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, LutBinaryError> {
+        let mut reader = Reader { bytes };
+        reader.expect_magic(LUT2D_MAGIC)?;
+        let nx = reader.read_len()?;
+        let ny = reader.read_len()?;
+        let flags = reader.read_flags(FLAG_PERIODIC_X | FLAG_PERIODIC_Y)?;
+        let x_axis = reader.read_values(nx)?;
+        let y_axis = reader.read_values(ny)?;
+        let count = nx.checked_mul(ny).ok_or(LutBinaryError::Truncated)?;
+        let data = reader.read_values(count)?;
+        let mut lut = Self::new(x_axis, y_axis, data)?;
+        if flags & FLAG_PERIODIC_X != 0 {
+            lut = lut.with_periodic_x()?;
+        }
+        if flags & FLAG_PERIODIC_Y != 0 {
+            lut = lut.with_periodic_y()?;
+        }
+        Ok(lut)
+    }
+}
+
+impl Lut3D {
+    /// Encodes the LUT as `b"L3DB"`, `u32` X, Y and Z lengths, all three
+    /// axes, then data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutBinaryError::AxisTooLong` if an axis has more than
+    /// `u32::MAX` points.
+    pub fn to_binary(&self) -> Result<Vec<u8>, LutBinaryError> {
+        let mut out = Vec::with_capacity(
+            16 + 8
+                * (self.x_axis().len()
+                    + self.y_axis().len()
+                    + self.z_axis().len()
+                    + self.data().len()),
+        );
+        out.extend_from_slice(&LUT3D_MAGIC);
+        write_len(&mut out, self.x_axis().len())?;
+        write_len(&mut out, self.y_axis().len())?;
+        write_len(&mut out, self.z_axis().len())?;
+        write_values(&mut out, self.x_axis());
+        write_values(&mut out, self.y_axis());
+        write_values(&mut out, self.z_axis());
+        write_values(&mut out, self.data());
+        Ok(out)
+    }
+
+    /// Decodes a LUT previously encoded with [`Lut3D::to_binary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutBinaryError` if the magic tag is wrong, the input is
+    /// truncated, or the decoded values fail [`Lut3D::new`] validation.
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, LutBinaryError> {
+        let mut reader = Reader { bytes };
+        reader.expect_magic(LUT3D_MAGIC)?;
+        let nx = reader.read_len()?;
+        let ny = reader.read_len()?;
+        let nz = reader.read_len()?;
+        let x_axis = reader.read_values(nx)?;
+        let y_axis = reader.read_values(ny)?;
+        let z_axis = reader.read_values(nz)?;
+        let count = nx
+            .checked_mul(ny)
+            .and_then(|n| n.checked_mul(nz))
+            .ok_or(LutBinaryError::Truncated)?;
+        let data = reader.read_values(count)?;
+        Ok(Self::new(x_axis, y_axis, z_axis, data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lut::LutError;
+    use alloc::vec;
+
+    #[test]
+    fn test_lut1d_binary_round_trip() {
+        let lut =
+            Lut1D::new(vec![0.0, 1000.0, 2000.0], vec![0.0, 150.5, -3.25]).expect("valid LUT");
+        let bytes = lut.to_binary().expect("small LUT");
+        assert_eq!(&bytes[..4], b"L1DB");
+        assert_eq!(bytes.len(), 12 + 6 * 8);
+
+        let decoded = Lut1D::from_binary(&bytes).expect("valid encoding");
+        assert_eq!(decoded.x_axis(), lut.x_axis());
+        assert_eq!(decoded.data(), lut.data());
+    }
+
+    #[test]
+    fn test_lut2d_binary_round_trip() {
+        let lut = Lut2D::new(
+            vec![0.0, 5.0, 10.0],
+            vec![0.0, 0.1],
+            vec![0.0, 0.8, 1.0, 0.5, 1.0, 0.9],
+        )
+        .expect("valid LUT");
+        let decoded =
+            Lut2D::from_binary(&lut.to_binary().expect("small LUT")).expect("valid encoding");
+        assert_eq!(decoded.x_axis(), lut.x_axis());
+        assert_eq!(decoded.y_axis(), lut.y_axis());
+        assert_eq!(decoded.data(), lut.data());
+    }
+
+    #[test]
+    fn test_lut3d_binary_round_trip() {
+        let lut = Lut3D::new(
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 1.0, 2.0],
+            (0..12).map(f64::from).collect(),
+        )
+        .expect("valid LUT");
+        let decoded =
+            Lut3D::from_binary(&lut.to_binary().expect("small LUT")).expect("valid encoding");
+        assert_eq!(decoded.x_axis(), lut.x_axis());
+        assert_eq!(decoded.y_axis(), lut.y_axis());
+        assert_eq!(decoded.z_axis(), lut.z_axis());
+        assert_eq!(decoded.data(), lut.data());
+    }
+
+    #[test]
+    fn test_binary_truncated() {
+        let lut1 = Lut1D::new(vec![0.0, 1.0], vec![2.0, 3.0]).expect("valid LUT");
+        let bytes = lut1.to_binary().expect("small LUT");
+        for len in [0, 3, 6, 10, bytes.len() - 1] {
+            assert_eq!(
+                Lut1D::from_binary(&bytes[..len]).err(),
+                Some(LutBinaryError::Truncated)
+            );
+        }
+
+        let lut2 = Lut2D::new(vec![0.0, 1.0], vec![0.0, 1.0], vec![0.0; 4]).expect("valid LUT");
+        let bytes = lut2.to_binary().expect("small LUT");
+        assert_eq!(
+            Lut2D::from_binary(&bytes[..bytes.len() - 8]).err(),
+            Some(LutBinaryError::Truncated)
+        );
+
+        let lut3 = Lut3D::new(vec![0.0], vec![0.0], vec![0.0], vec![1.0]).expect("valid LUT");
+        let bytes = lut3.to_binary().expect("small LUT");
+        assert_eq!(
+            Lut3D::from_binary(&bytes[..bytes.len() - 1]).err(),
+            Some(LutBinaryError::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_binary_bad_magic_and_invalid_data() {
+        let lut = Lut1D::new(vec![0.0, 1.0], vec![2.0, 3.0]).expect("valid LUT");
+        assert_eq!(
+            Lut2D::from_binary(&lut.to_binary().expect("small LUT")).err(),
+            Some(LutBinaryError::BadMagic)
+        );
+
+        // Overwrite the first axis value so the axis is no longer ascending
+        let mut bytes = lut.to_binary().expect("small LUT");
+        bytes[12..20].copy_from_slice(&5.0f64.to_le_bytes());
+        assert_eq!(
+            Lut1D::from_binary(&bytes).err(),
+            Some(LutBinaryError::InvalidData(LutError::UnsortedAxis {
+                axis: "X",
+                index: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn test_binary_round_trip_options() {
+        let hermite = Lut1D::with_hermite_tangents(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 0.0],
+            vec![1.0, 0.0, -1.0],
+        )
+        .expect("valid LUT")
+        .with_periodic_x()
+        .expect("matching ends");
+        let decoded =
+            Lut1D::from_binary(&hermite.to_binary().expect("small LUT")).expect("valid encoding");
+        assert_eq!(decoded.tangents(), hermite.tangents());
+        assert!(decoded.is_periodic_x());
+        for x in [-0.5, 0.25, 1.5, 2.75] {
+            assert!((decoded.lookup(x) - hermite.lookup(x)).abs() < 1e-12);
+        }
+
+        let lut2 = Lut2D::new(vec![0.0, 1.0], vec![0.0, 1.0], vec![1.0, 1.0, 1.0, 1.0])
+            .expect("valid LUT")
+            .with_periodic_y()
+            .expect("matching rows");
+        let decoded =
+            Lut2D::from_binary(&lut2.to_binary().expect("small LUT")).expect("valid encoding");
+        assert!(!decoded.is_periodic_x());
+        assert!(decoded.is_periodic_y());
+
+        let mut bytes = lut2.to_binary().expect("small LUT");
+        bytes[12..16].copy_from_slice(&8u32.to_le_bytes());
+        assert_eq!(
+            Lut2D::from_binary(&bytes).err(),
+            Some(LutBinaryError::UnknownFlags { flags: 8 })
+        );
+    }
+}
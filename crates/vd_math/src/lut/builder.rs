@@ -0,0 +1,264 @@
+//! Fluent builders for lookup tables.
+//!
+//! Builders let axes and data be declared separately; all validation is
+//! deferred to `build()`, which applies the same checks as the LUT
+//! constructors.
+
+use alloc::vec::Vec;
+
+use super::{Lut1D, Lut2D, Lut3D, LutError};
+
+/// Builder for [`Lut1D`].
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::Lut1D;
+///
+/// let lut = Lut1D::builder()
+///     .x_axis(vec![0.0, 1000.0, 2000.0])
+///     .data(vec![0.0, 150.0, 280.0])
+///     .build()
+///     .unwrap();
+/// assert!((lut.lookup(500.0) - 75.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Lut1DBuilder {
+    x_axis: Vec<f64>,
+    data: Vec<f64>,
+}
+
+impl Lut1DBuilder {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the X axis.
+    #[must_use]
+    pub fn x_axis(mut self, axis: Vec<f64>) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    /// Sets the data values.
+    #[must_use]
+    pub fn data(mut self, data: Vec<f64>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Validates the inputs and builds the LUT.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Lut1D::new`].
+    pub fn build(self) -> Result<Lut1D, LutError> {
+        Lut1D::new(self.x_axis, self.data)
+    }
+}
+
+/// Builder for [`Lut2D`].
+///
+/// Data is given in row-major order, as for [`Lut2D::new`].
+#[derive(Debug, Clone, Default)]
+pub struct Lut2DBuilder {
+    x_axis: Vec<f64>,
+    y_axis: Vec<f64>,
+    data: Vec<f64>,
+}
+
+impl Lut2DBuilder {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the X axis (columns).
+    #[must_use]
+    pub fn x_axis(mut self, axis: Vec<f64>) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    /// Sets the Y axis (rows).
+    #[must_use]
+    pub fn y_axis(mut self, axis: Vec<f64>) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    /// Sets the data values in row-major order.
+    #[must_use]
+    pub fn data(mut self, data: Vec<f64>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Validates the inputs and builds the LUT.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Lut2D::new`].
+    pub fn build(self) -> Result<Lut2D, LutError> {
+        Lut2D::new(self.x_axis, self.y_axis, self.data)
+    }
+}
+
+/// Builder for [`Lut3D`].
+///
+/// Data is given in linearized order, as for [`Lut3D::new`].
+#[derive(Debug, Clone, Default)]
+pub struct Lut3DBuilder {
+    x_axis: Vec<f64>,
+    y_axis: Vec<f64>,
+    z_axis: Vec<f64>,
+    data: Vec<f64>,
+}
+
+impl Lut3DBuilder {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the X axis.
+    #[must_use]
+    pub fn x_axis(mut self, axis: Vec<f64>) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    /// Sets the Y axis.
+    #[must_use]
+    pub fn y_axis(mut self, axis: Vec<f64>) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    /// Sets the Z axis.
+    #[must_use]
+    pub fn z_axis(mut self, axis: Vec<f64>) -> Self {
+        self.z_axis = axis;
+        self
+    }
+
+    /// Sets the data values in linearized order.
+    #[must_use]
+    pub fn data(mut self, data: Vec<f64>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Validates the inputs and builds the LUT.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Lut3D::new`].
+    pub fn build(self) -> Result<Lut3D, LutError> {
+        Lut3D::new(self.x_axis, self.y_axis, self.z_axis, self.data)
+    }
+}
+
+impl Lut1D {
+    /// Returns a builder for a 1D LUT.
+    #[must_use]
+    pub fn builder() -> Lut1DBuilder {
+        Lut1DBuilder::new()
+    }
+}
+
+impl Lut2D {
+    /// Returns a builder for a 2D LUT.
+    #[must_use]
+    pub fn builder() -> Lut2DBuilder {
+        Lut2DBuilder::new()
+    }
+}
+
+impl Lut3D {
+    /// Returns a builder for a 3D LUT.
+    #[must_use]
+    pub fn builder() -> Lut3DBuilder {
+        Lut3DBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_builders_match_constructors() {
+        let lut1 = Lut1D::builder()
+            .data(vec![1.0, 2.0])
+            .x_axis(vec![0.0, 1.0])
+            .build()
+            .expect("valid LUT");
+        assert!((lut1.lookup(0.5) - 1.5).abs() < 1e-10);
+
+        let lut2 = Lut2D::builder()
+            .x_axis(vec![0.0, 1.0])
+            .y_axis(vec![0.0, 1.0])
+            .data(vec![0.0, 1.0, 2.0, 3.0])
+            .build()
+            .expect("valid LUT");
+        assert!((lut2.lookup(0.5, 0.5) - 1.5).abs() < 1e-10);
+
+        let lut3 = Lut3D::builder()
+            .x_axis(vec![0.0, 1.0])
+            .y_axis(vec![0.0, 1.0])
+            .z_axis(vec![0.0, 1.0])
+            .data(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0])
+            .build()
+            .expect("valid LUT");
+        assert!((lut3.lookup(0.5, 0.5, 0.5) - 3.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_builder_missing_data() {
+        let result = Lut1D::builder().x_axis(vec![0.0, 1.0, 2.0]).build();
+        assert!(matches!(
+            result,
+            Err(LutError::DimensionMismatch {
+                expected: 3,
+                actual: 0
+            })
+        ));
+
+        let result = Lut2D::builder()
+            .x_axis(vec![0.0, 1.0])
+            .y_axis(vec![0.0, 1.0, 2.0])
+            .build();
+        assert!(matches!(
+            result,
+            Err(LutError::DimensionMismatch {
+                expected: 6,
+                actual: 0
+            })
+        ));
+
+        let result = Lut3D::builder()
+            .x_axis(vec![0.0, 1.0])
+            .y_axis(vec![0.0, 1.0])
+            .z_axis(vec![0.0, 1.0])
+            .build();
+        assert!(matches!(
+            result,
+            Err(LutError::DimensionMismatch {
+                expected: 8,
+                actual: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_builder_missing_axis() {
+        let result = Lut2D::builder().x_axis(vec![0.0]).data(vec![1.0]).build();
+        assert!(matches!(result, Err(LutError::EmptyYAxis)));
+    }
+}
@@ -0,0 +1,357 @@
+//! CSV import and export for lookup tables.
+//!
+//! When parsing, lines starting with `#` or `//` and blank lines are ignored.
+//! Cells may be separated by `,` or `;`; the delimiter is detected from the
+//! first data line. Exported files always use `,`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use super::{Lut1D, Lut2D, ParseError};
+
+/// Returns the non-comment, non-blank lines with their 1-based line numbers.
+fn data_lines(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#') && !line.starts_with("//"))
+}
+
+/// Picks `;` if the line contains one, otherwise `,`.
+fn detect_delimiter(line: &str) -> char {
+    if line.contains(';') {
+        ';'
+    } else {
+        ','
+    }
+}
+
+/// Parses a single trimmed cell as `f64`.
+fn parse_cell(cell: &str, line: usize) -> Result<f64, ParseError> {
+    let cell = cell.trim();
+    cell.parse().map_err(|_| ParseError::InvalidNumber {
+        line,
+        token: String::from(cell),
+    })
+}
+
+/// Returns true if no cell of the line parses as a number (a text header).
+fn is_header(line: &str, delimiter: char) -> bool {
+    line.split(delimiter)
+        .all(|cell| cell.trim().parse::<f64>().is_err())
+}
+
+/// Appends `value` in the shortest form that parses back to the same `f64`.
+fn write_value(out: &mut String, value: f64) {
+    let _ = write!(out, "{value:?}");
+}
+
+impl Lut1D {
+    /// Parses a 1D LUT from two-column CSV text (`x,y` or `x;y` per line).
+    ///
+    /// Comment lines (`#`, `//`) and blank lines are skipped. A leading text
+    /// header such as `rpm,torque` is also skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// let csv = "# torque curve\nrpm;torque\n1000;150\n2000;280\n";
+    /// let lut = Lut1D::from_csv_str(csv).unwrap();
+    /// assert!((lut.lookup(1500.0) - 215.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if a cell is not a number, a line does not have
+    /// exactly two cells, the input has no data, or the resulting axis is
+    /// not a valid LUT axis.
+    pub fn from_csv_str(s: &str) -> Result<Self, ParseError> {
+        let mut lines = data_lines(s).peekable();
+        let delimiter = match lines.peek() {
+            Some((_, first)) => detect_delimiter(first),
+            None => return Err(ParseError::EmptyFile),
+        };
+        if lines
+            .peek()
+            .is_some_and(|(_, first)| is_header(first, delimiter))
+        {
+            lines.next();
+        }
+
+        let mut x_axis = Vec::new();
+        let mut data = Vec::new();
+        for (line_no, line) in lines {
+            let cells: Vec<&str> = line.split(delimiter).collect();
+            if cells.len() != 2 {
+                return Err(ParseError::ColumnCount {
+                    line: line_no,
+                    expected: 2,
+                    found: cells.len(),
+                });
+            }
+            x_axis.push(parse_cell(cells[0], line_no)?);
+            data.push(parse_cell(cells[1], line_no)?);
+        }
+
+        if x_axis.is_empty() {
+            return Err(ParseError::EmptyFile);
+        }
+        Ok(Self::new(x_axis, data)?)
+    }
+
+    /// Serializes the LUT as two-column CSV with an `x,y` header.
+    ///
+    /// Values are written in their shortest round-trip form, so
+    /// [`Lut1D::from_csv_str`] reads back exactly the same table. Hermite
+    /// tangents and the periodic X flag have no CSV representation and are
+    /// not written; the parsed table interpolates linearly and clamps.
+    #[must_use]
+    pub fn to_csv_str(&self) -> String {
+        let mut out = String::from("x,y\n");
+        for (x, y) in self.x_axis().iter().zip(self.data()) {
+            write_value(&mut out, *x);
+            out.push(',');
+            write_value(&mut out, *y);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Lut2D {
+    /// Parses a 2D LUT from matrix-layout CSV text.
+    ///
+    /// The first data line holds the X axis after one leading cell, which is
+    /// ignored (usually empty). Each following line holds a Y axis value and
+    /// then one data cell per X value. Comment lines (`#`, `//`) and blank
+    /// lines are skipped; `,` or `;` delimiters are detected automatically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut2D;
+    ///
+    /// let csv = ",0,10\n0,0.0,1.0\n1,0.5,1.5\n";
+    /// let lut = Lut2D::from_csv_str(csv).unwrap();
+    /// assert!((lut.lookup(5.0, 0.5) - 0.75).abs() < 1e-10);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if a cell is not a number, a row has the wrong
+    /// number of cells, the input has no data rows, or the resulting axes
+    /// are not valid LUT axes.
+    pub fn from_csv_str(s: &str) -> Result<Self, ParseError> {
+        let mut lines = data_lines(s);
+        let Some((header_no, header)) = lines.next() else {
+            return Err(ParseError::EmptyFile);
+        };
+        let delimiter = detect_delimiter(header);
+
+        let x_axis = header
+            .split(delimiter)
+            .skip(1)
+            .map(|cell| parse_cell(cell, header_no))
+            .collect::<Result<Vec<f64>, _>>()?;
+        let columns = x_axis.len() + 1;
+
+        let mut y_axis = Vec::new();
+        let mut data = Vec::new();
+        for (line_no, line) in lines {
+            let cells: Vec<&str> = line.split(delimiter).collect();
+            if cells.len() != columns {
+                return Err(ParseError::ColumnCount {
+                    line: line_no,
+                    expected: columns,
+                    found: cells.len(),
+                });
+            }
+            y_axis.push(parse_cell(cells[0], line_no)?);
+            for cell in &cells[1..] {
+                data.push(parse_cell(cell, line_no)?);
+            }
+        }
+
+        if y_axis.is_empty() {
+            return Err(ParseError::EmptyFile);
+        }
+        Ok(Self::new(x_axis, y_axis, data)?)
+    }
+
+    /// Serializes the LUT as matrix-layout CSV.
+    ///
+    /// The first row holds an empty cell followed by the X axis; each further
+    /// row holds a Y axis value followed by that row's data. Values are
+    /// written in their shortest round-trip form, so [`Lut2D::from_csv_str`]
+    /// reads back exactly the same table. Periodic axis flags have no CSV
+    /// representation and are not written.
+    #[must_use]
+    pub fn to_csv_str(&self) -> String {
+        let mut out = String::new();
+        for x in self.x_axis() {
+            out.push(',');
+            write_value(&mut out, *x);
+        }
+        out.push('\n');
+
+        let rows = self.data().chunks_exact(self.x_axis().len());
+        for (y, row) in self.y_axis().iter().zip(rows) {
+            write_value(&mut out, *y);
+            for v in row {
+                out.push(',');
+                write_value(&mut out, *v);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lut::LutError;
+
+    const TORQUE_CSV: &str = "\
+# Engine torque export
+// generated by ECU tool
+rpm,torque
+
+1000, 150.0
+2000, 280.0
+3000, 320.5
+4000, 300.0
+5000, 250.0
+";
+
+    #[test]
+    fn test_lut1d_from_csv_torque_curve() {
+        let lut = Lut1D::from_csv_str(TORQUE_CSV).expect("valid CSV");
+
+        assert_eq!(lut.x_axis(), &[1000.0, 2000.0, 3000.0, 4000.0, 5000.0]);
+        assert_eq!(lut.data(), &[150.0, 280.0, 320.5, 300.0, 250.0]);
+        assert!((lut.lookup(1500.0) - 215.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_lut1d_from_csv_semicolon() {
+        let lut = Lut1D::from_csv_str("0;1\n1;3\n").expect("valid CSV");
+        assert_eq!(lut.data(), &[1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_lut1d_from_csv_invalid_number() {
+        let result = Lut1D::from_csv_str("0,1\n1,abc\n2,3\n");
+        assert_eq!(
+            result.err(),
+            Some(ParseError::InvalidNumber {
+                line: 2,
+                token: String::from("abc"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_lut1d_from_csv_errors() {
+        assert_eq!(
+            Lut1D::from_csv_str("# only comments\n\n").err(),
+            Some(ParseError::EmptyFile)
+        );
+        assert_eq!(
+            Lut1D::from_csv_str("0,1\n1,2,3\n").err(),
+            Some(ParseError::ColumnCount {
+                line: 2,
+                expected: 2,
+                found: 3,
+            })
+        );
+        assert_eq!(
+            Lut1D::from_csv_str("1,1\n0,2\n").err(),
+            Some(ParseError::LutError(LutError::UnsortedAxis {
+                axis: "X",
+                index: 1,
+            }))
+        );
+    }
+
+    const GRIP_CSV: &str = "\
+# slip angle (deg) across, slip ratio down
+ , 0.0, 5.0, 10.0
+0.0, 0.0, 0.8, 1.0
+0.1, 0.5, 1.0, 0.9
+";
+
+    #[test]
+    fn test_lut2d_from_csv_grip_map() {
+        let lut = Lut2D::from_csv_str(GRIP_CSV).expect("valid CSV");
+
+        assert_eq!(lut.x_axis(), &[0.0, 5.0, 10.0]);
+        assert_eq!(lut.y_axis(), &[0.0, 0.1]);
+        assert_eq!(lut.data(), &[0.0, 0.8, 1.0, 0.5, 1.0, 0.9]);
+
+        assert!((lut.lookup(5.0, 0.0) - 0.8).abs() < 1e-10);
+        assert!((lut.lookup(2.5, 0.0) - 0.4).abs() < 1e-10);
+        assert!((lut.lookup(0.0, 0.05) - 0.25).abs() < 1e-10);
+        // Centre of the first cell: (0.0 + 0.8 + 0.5 + 1.0) / 4
+        assert!((lut.lookup(2.5, 0.05) - 0.575).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_lut2d_from_csv_errors() {
+        assert_eq!(
+            Lut2D::from_csv_str("// nothing\n").err(),
+            Some(ParseError::EmptyFile)
+        );
+        assert_eq!(
+            Lut2D::from_csv_str(";0;1\n").err(),
+            Some(ParseError::EmptyFile)
+        );
+        assert_eq!(
+            Lut2D::from_csv_str(",0,1\n0,1\n").err(),
+            Some(ParseError::ColumnCount {
+                line: 2,
+                expected: 3,
+                found: 2,
+            })
+        );
+        assert_eq!(
+            Lut2D::from_csv_str(",0,1\n0,1,x\n").err(),
+            Some(ParseError::InvalidNumber {
+                line: 2,
+                token: String::from("x"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_lut1d_csv_round_trip() {
+        let lut = Lut1D::new(
+            alloc::vec![-1.5, 0.0, 1000.0, 2.5e12],
+            alloc::vec![0.125, -3.0e-7, 0.1 + 0.2, 1.0 / 3.0],
+        )
+        .expect("valid LUT");
+
+        let csv = lut.to_csv_str();
+        assert!(csv.starts_with("x,y\n"));
+
+        let parsed = Lut1D::from_csv_str(&csv).expect("valid CSV");
+        assert_eq!(parsed.x_axis(), lut.x_axis());
+        assert_eq!(parsed.data(), lut.data());
+    }
+
+    #[test]
+    fn test_lut2d_csv_round_trip() {
+        let lut = Lut2D::from_csv_str(GRIP_CSV).expect("valid CSV");
+
+        let csv = lut.to_csv_str();
+        assert!(csv.starts_with(",0.0,5.0,10.0\n"));
+
+        let parsed = Lut2D::from_csv_str(&csv).expect("valid CSV");
+        assert_eq!(parsed.x_axis(), lut.x_axis());
+        assert_eq!(parsed.y_axis(), lut.y_axis());
+        assert_eq!(parsed.data(), lut.data());
+    }
+}
@@ -0,0 +1,121 @@
+//! Compact `Display` summaries for lookup tables.
+//!
+//! `Debug` prints every value; `Display` prints only the sizes and the range
+//! of each axis and of the data, rounded to 4 significant figures.
+
+use alloc::string::String;
+use core::fmt;
+
+use super::format::write_significant;
+use super::{Lut1D, Lut2D, Lut3D};
+
+/// Significant figures used for ranges in `Display` output.
+const DISPLAY_SIGNIFICANT_FIGURES: i32 = 4;
+
+/// Writes `name={min}..{max}` for the finite range of `values`.
+fn write_range(f: &mut fmt::Formatter<'_>, name: &str, values: &[f64]) -> fmt::Result {
+    let (min, max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| {
+            (libm::fmin(lo, v), libm::fmax(hi, v))
+        });
+
+    let mut out = String::new();
+    write_significant(&mut out, min, DISPLAY_SIGNIFICANT_FIGURES);
+    out.push_str("..");
+    write_significant(&mut out, max, DISPLAY_SIGNIFICANT_FIGURES);
+    write!(f, "{name}={out}")
+}
+
+impl fmt::Display for Lut1D {
+    /// Formats as `Lut1D[n=5, x=1000..5000, y=110..210]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Lut1D[n={}, ", self.len())?;
+        write_range(f, "x", self.x_axis())?;
+        f.write_str(", ")?;
+        write_range(f, "y", self.data())?;
+        f.write_str("]")
+    }
+}
+
+impl fmt::Display for Lut2D {
+    /// Formats as `Lut2D[nx=3, ny=2, x=0..10, y=0..0.1, z=0..1]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lut2D[nx={}, ny={}, ",
+            self.x_axis().len(),
+            self.y_axis().len()
+        )?;
+        write_range(f, "x", self.x_axis())?;
+        f.write_str(", ")?;
+        write_range(f, "y", self.y_axis())?;
+        f.write_str(", ")?;
+        write_range(f, "z", self.data())?;
+        f.write_str("]")
+    }
+}
+
+impl fmt::Display for Lut3D {
+    /// Formats as `Lut3D[nx=2, ny=2, nz=2, x=.., y=.., z=.., w=..]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lut3D[nx={}, ny={}, nz={}, ",
+            self.x_axis().len(),
+            self.y_axis().len(),
+            self.z_axis().len()
+        )?;
+        write_range(f, "x", self.x_axis())?;
+        f.write_str(", ")?;
+        write_range(f, "y", self.y_axis())?;
+        f.write_str(", ")?;
+        write_range(f, "z", self.z_axis())?;
+        f.write_str(", ")?;
+        write_range(f, "w", self.data())?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::vec;
+
+    #[test]
+    fn test_display_lut1d() {
+        let lut = Lut1D::new(
+            vec![1000.0, 2000.0, 3000.0, 4000.0, 5000.0],
+            vec![120.0, 180.0, 210.5678, 190.0, 110.0],
+        )
+        .expect("valid LUT");
+        assert_eq!(format!("{lut}"), "Lut1D[n=5, x=1000..5000, y=110..210.6]");
+    }
+
+    #[test]
+    fn test_display_lut2d_and_lut3d() {
+        let lut2 = Lut2D::new(
+            vec![0.0, 5.0, 10.0],
+            vec![0.0, 0.1],
+            vec![0.0, 0.8, 1.0, 0.5, 1.0, -0.25],
+        )
+        .expect("valid LUT");
+        assert_eq!(
+            format!("{lut2}"),
+            "Lut2D[nx=3, ny=2, x=0..10, y=0..0.1, z=-0.25..1]"
+        );
+
+        let lut3 = Lut3D::new(
+            vec![0.0, 50.0],
+            vec![0.0, 10.0],
+            vec![0.0, 1000.0],
+            vec![0.3, 0.35, 0.4, 0.45, 0.28, 0.33, 0.38, 0.43],
+        )
+        .expect("valid LUT");
+        assert_eq!(
+            format!("{lut3}"),
+            "Lut3D[nx=2, ny=2, nz=2, x=0..50, y=0..10, z=0..1000, w=0.28..0.45]"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for LUT operations.
 
+use alloc::string::String;
 use core::fmt;
 
 /// Error type for LUT creation failures.
@@ -25,6 +26,28 @@ pub enum LutError {
         /// Index where the violation was found.
         index: usize,
     },
+    /// Data does not repeat at both ends of an axis marked as periodic.
+    NonPeriodicData {
+        /// Name of the periodic axis.
+        axis: &'static str,
+    },
+    /// A data value is NaN.
+    NaNInData {
+        /// Index of the first NaN in the data vector.
+        index: usize,
+    },
+    /// A data value is infinite.
+    InfInData {
+        /// Index of the first infinite value in the data vector.
+        index: usize,
+    },
+    /// A value does not fit the narrower type it is being converted to.
+    ValueOutOfRange {
+        /// Name of the axis holding the value, or `"data"`.
+        axis: &'static str,
+        /// Index of the first such value.
+        index: usize,
+    },
 }
 
 impl fmt::Display for LutError {
@@ -39,6 +62,107 @@ impl fmt::Display for LutError {
             Self::UnsortedAxis { axis, index } => {
                 write!(f, "{axis} axis is not strictly ascending at index {index}")
             }
+            Self::NonPeriodicData { axis } => {
+                write!(f, "Data differs at the ends of periodic {axis} axis")
+            }
+            Self::NaNInData { index } => write!(f, "Data is NaN at index {index}"),
+            Self::InfInData { index } => write!(f, "Data is infinite at index {index}"),
+            Self::ValueOutOfRange { axis, index } => {
+                write!(f, "{axis} value at index {index} is out of range")
+            }
+        }
+    }
+}
+
+/// Error type for parsing LUTs from text formats such as CSV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A cell could not be parsed as a number.
+    InvalidNumber {
+        /// 1-based line number of the offending cell.
+        line: usize,
+        /// The text that failed to parse.
+        token: String,
+    },
+    /// A line has the wrong number of cells.
+    ColumnCount {
+        /// 1-based line number of the offending line.
+        line: usize,
+        /// Expected number of cells.
+        expected: usize,
+        /// Actual number of cells found.
+        found: usize,
+    },
+    /// The input contains no data lines.
+    EmptyFile,
+    /// The parsed values do not form a valid LUT.
+    LutError(LutError),
+}
+
+impl From<LutError> for ParseError {
+    fn from(err: LutError) -> Self {
+        Self::LutError(err)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber { line, token } => {
+                write!(f, "Invalid number '{token}' on line {line}")
+            }
+            Self::ColumnCount {
+                line,
+                expected,
+                found,
+            } => {
+                write!(f, "Line {line} has {found} columns, expected {expected}")
+            }
+            Self::EmptyFile => write!(f, "Input contains no data"),
+            Self::LutError(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Error type for the compact binary LUT format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LutBinaryError {
+    /// The input does not start with the expected magic bytes.
+    BadMagic,
+    /// The input ended before all expected values were read.
+    Truncated,
+    /// The decoded values do not form a valid LUT.
+    InvalidData(LutError),
+    /// An axis is too long for its `u32` length field.
+    AxisTooLong {
+        /// Length of the axis.
+        len: usize,
+    },
+    /// The flags word has bits set that this version does not understand.
+    UnknownFlags {
+        /// The encoded flags word.
+        flags: u32,
+    },
+}
+
+impl From<LutError> for LutBinaryError {
+    fn from(err: LutError) -> Self {
+        Self::InvalidData(err)
+    }
+}
+
+impl fmt::Display for LutBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "Invalid LUT binary magic header"),
+            Self::Truncated => write!(f, "LUT binary data is truncated"),
+            Self::InvalidData(err) => write!(f, "{err}"),
+            Self::AxisTooLong { len } => {
+                write!(f, "Axis length {len} does not fit the LUT binary format")
+            }
+            Self::UnknownFlags { flags } => {
+                write!(f, "LUT binary data has unknown flags {flags:#x}")
+            }
         }
     }
 }
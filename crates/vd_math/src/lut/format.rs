@@ -0,0 +1,69 @@
+//! Number formatting shared by the LUT serializers and `Display` impls.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Appends `value` to `out` rounded to `figures` significant figures,
+/// without trailing zeros.
+///
+/// Values with a decimal exponent in `-5..figures` are written in plain
+/// notation, others in scientific notation (e.g. `1.5e-9`).
+pub(super) fn write_significant(out: &mut String, value: f64, figures: i32) {
+    if value == 0.0 || !value.is_finite() {
+        let _ = write!(out, "{value}");
+        return;
+    }
+
+    let exponent = libm::floor(libm::log10(libm::fabs(value))) as i32;
+    let start = out.len();
+    if (-5..figures).contains(&exponent) {
+        let decimals = (figures - 1 - exponent).max(0) as usize;
+        let _ = write!(out, "{value:.decimals$}");
+        if out[start..].contains('.') {
+            let trimmed = out.trim_end_matches('0').trim_end_matches('.').len();
+            out.truncate(trimmed);
+        }
+    } else {
+        let decimals = (figures - 1).max(0) as usize;
+        let formatted = alloc::format!("{value:.decimals$e}");
+        let (mantissa, exp) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+        let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+        let _ = write!(out, "{mantissa}e{exp}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_significant() {
+        let fmt = |v: f64| {
+            let mut out = String::new();
+            write_significant(&mut out, v, 10);
+            out
+        };
+
+        assert_eq!(fmt(0.0), "0");
+        assert_eq!(fmt(1500.0), "1500");
+        assert_eq!(fmt(-0.25), "-0.25");
+        assert_eq!(fmt(core::f64::consts::PI), "3.141592654");
+        assert_eq!(fmt(1.0 / 3.0), "0.3333333333");
+        assert_eq!(fmt(12_345_678_901.0), "1.23456789e10");
+        assert_eq!(fmt(1.5e-9), "1.5e-9");
+    }
+
+    #[test]
+    fn test_write_significant_four_figures() {
+        let fmt = |v: f64| {
+            let mut out = String::new();
+            write_significant(&mut out, v, 4);
+            out
+        };
+
+        assert_eq!(fmt(7000.0), "7000");
+        assert_eq!(fmt(12_346.0), "1.235e4");
+        assert_eq!(fmt(0.123_456), "0.1235");
+        assert_eq!(fmt(-2.5), "-2.5");
+    }
+}
@@ -2,6 +2,26 @@
 
 use super::LutError;
 
+/// Returns true if `next` follows `prev` by a resolvable positive stride.
+///
+/// Strides smaller than `f64::EPSILON * |next|` are rejected: they are at
+/// the limit of floating-point resolution and make the interpolation factor
+/// in [`find_interval`] unreliable or infinite.
+#[inline]
+pub(super) const fn is_ascending_step(prev: f64, next: f64) -> bool {
+    let magnitude = if next < 0.0 { -next } else { next };
+    next > prev && next - prev >= f64::EPSILON * magnitude
+}
+
+/// Returns the smallest gap between adjacent breakpoints.
+///
+/// Returns `f64::INFINITY` for axes with fewer than two points.
+pub(super) fn min_axis_stride(axis: &[f64]) -> f64 {
+    axis.windows(2)
+        .map(|w| w[1] - w[0])
+        .fold(f64::INFINITY, libm::fmin)
+}
+
 /// Validates that an axis is non-empty and strictly ascending.
 pub(super) fn validate_axis(
     axis: &[f64],
@@ -12,7 +32,7 @@ pub(super) fn validate_axis(
         return Err(empty_err);
     }
     for i in 1..axis.len() {
-        if axis[i] <= axis[i - 1] {
+        if !is_ascending_step(axis[i - 1], axis[i]) {
             return Err(LutError::UnsortedAxis {
                 axis: name,
                 index: i,
@@ -22,6 +42,71 @@ pub(super) fn validate_axis(
     Ok(())
 }
 
+/// Validates that every data value is finite.
+///
+/// Reports the first NaN or infinite value, so bad tables are rejected at
+/// construction instead of silently producing NaN lookups.
+pub(super) fn validate_data(data: &[f64]) -> Result<(), LutError> {
+    for (index, &v) in data.iter().enumerate() {
+        if v.is_nan() {
+            return Err(LutError::NaNInData { index });
+        }
+        if v.is_infinite() {
+            return Err(LutError::InfInData { index });
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if `axis` is non-empty and strictly ascending, with every
+/// stride resolvable in floating point.
+///
+/// This is the `const` counterpart of the check performed by the LUT
+/// constructors, usable in `const` items to reject bad tables at compile
+/// time. See [`lut1d_static!`](crate::lut1d_static).
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::validate_axis_const;
+///
+/// const OK: bool = validate_axis_const(&[0.0, 1.0, 2.0]);
+/// const BAD: bool = validate_axis_const(&[0.0, 2.0, 1.0]);
+/// assert!(OK);
+/// assert!(!BAD);
+/// ```
+#[must_use]
+pub const fn validate_axis_const(axis: &[f64]) -> bool {
+    if axis.is_empty() {
+        return false;
+    }
+    let mut i = 1;
+    while i < axis.len() {
+        if !is_ascending_step(axis[i - 1], axis[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns true if every value in `data` is finite.
+///
+/// This is the `const` counterpart of the data check performed by the LUT
+/// constructors. See [`lut1d_static!`](crate::lut1d_static).
+#[must_use]
+pub const fn validate_data_const(data: &[f64]) -> bool {
+    let mut i = 0;
+    while i < data.len() {
+        // False for NaN as well as for both infinities
+        if !(data[i] > f64::NEG_INFINITY && data[i] < f64::INFINITY) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 /// Binary search to find the interval containing a value.
 /// Returns the lower index and interpolation factor t in [0, 1].
 /// Clamps to boundaries if x is outside the axis range.
@@ -30,9 +115,13 @@ pub(super) fn find_interval(axis: &[f64], x: f64) -> (usize, f64) {
     let n = axis.len();
 
     // Handle boundary cases (clamping)
+    #[cfg(test)]
+    tests::count_comparison();
     if x <= axis[0] {
         return (0, 0.0);
     }
+    #[cfg(test)]
+    tests::count_comparison();
     if x >= axis[n - 1] {
         return (n.saturating_sub(2), 1.0);
     }
@@ -43,6 +132,8 @@ pub(super) fn find_interval(axis: &[f64], x: f64) -> (usize, f64) {
 
     while hi - lo > 1 {
         let mid = lo + (hi - lo) / 2;
+        #[cfg(test)]
+        tests::count_comparison();
         if axis[mid] <= x {
             lo = mid;
         } else {
@@ -50,12 +141,214 @@ pub(super) fn find_interval(axis: &[f64], x: f64) -> (usize, f64) {
         }
     }
 
-    // Calculate interpolation factor
-    let x0 = axis[lo];
-    let x1 = axis[hi];
-    let t = (x - x0) / (x1 - x0);
+    (lo, interval_factor(axis, lo, x))
+}
+
+/// Interpolation factor of `x` within interval `i`.
+#[inline]
+fn interval_factor(axis: &[f64], i: usize, x: f64) -> f64 {
+    let x0 = axis[i];
+    let x1 = axis[i + 1];
+    (x - x0) / (x1 - x0)
+}
+
+/// Interval search seeded with the interval `hint` returned by a previous
+/// query.
+///
+/// Tries `hint` and its two neighbours before falling back to
+/// [`find_interval`], so slowly moving sequential queries take O(1).
+/// Always returns exactly what [`find_interval`] would. Out-of-range hints
+/// go straight to the binary search.
+#[inline]
+pub(super) fn find_interval_from_hint(axis: &[f64], x: f64, hint: usize) -> (usize, f64) {
+    let n = axis.len();
+    if n < 2 || hint > n - 2 {
+        return find_interval(axis, x);
+    }
+
+    // Clamped queries resolve to the end intervals, like find_interval
+    #[cfg(test)]
+    tests::count_comparison();
+    if x <= axis[0] {
+        return (0, 0.0);
+    }
+    #[cfg(test)]
+    tests::count_comparison();
+    if x >= axis[n - 1] {
+        return (n - 2, 1.0);
+    }
+
+    for i in [hint, hint + 1, hint.wrapping_sub(1)] {
+        if i > n - 2 {
+            continue;
+        }
+        #[cfg(test)]
+        tests::count_comparison();
+        if axis[i] <= x {
+            #[cfg(test)]
+            tests::count_comparison();
+            if x < axis[i + 1] {
+                return (i, interval_factor(axis, i, x));
+            }
+        }
+    }
+    find_interval(axis, x)
+}
+
+/// Like [`find_interval`], but also returns the upper index and tolerates
+/// single-point axes, for which both indices are 0.
+#[inline]
+pub(super) fn find_bracket(axis: &[f64], x: f64) -> (usize, usize, f64) {
+    if axis.len() == 1 {
+        return (0, 0, 0.0);
+    }
+    let (i, t) = find_interval(axis, x);
+    (i, i + 1, t)
+}
+
+/// Checks that `data` holds exactly `expected` values.
+const fn validate_data_len(data: &[f64], expected: usize) -> Result<(), LutError> {
+    if data.len() == expected {
+        Ok(())
+    } else {
+        Err(LutError::DimensionMismatch {
+            expected,
+            actual: data.len(),
+        })
+    }
+}
+
+/// Interpolates `ys` over `xs` at `x` without building a [`Lut1D`].
+///
+/// Gives the same result as [`Lut1D::lookup`] on a linear table with the
+/// same breakpoints, clamping outside the axis range. The axis is validated
+/// on every call, so prefer a [`Lut1D`] for repeated lookups.
+///
+/// # Errors
+///
+/// Returns the same errors as [`Lut1D::new`].
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::interpolate_1d;
+///
+/// let y = interpolate_1d(&[0.0, 10.0], &[100.0, 200.0], 2.5).unwrap();
+/// assert!((y - 125.0).abs() < 1e-12);
+/// ```
+///
+/// [`Lut1D`]: super::Lut1D
+/// [`Lut1D::lookup`]: super::Lut1D::lookup
+/// [`Lut1D::new`]: super::Lut1D::new
+pub fn interpolate_1d(xs: &[f64], ys: &[f64], x: f64) -> Result<f64, LutError> {
+    validate_axis(xs, "X", LutError::EmptyXAxis)?;
+    validate_data_len(ys, xs.len())?;
+    validate_data(ys)?;
+
+    let (i0, i1, t) = find_bracket(xs, x);
+    Ok(lerp(ys[i0], ys[i1], t))
+}
+
+/// Bilinearly interpolates a row-major grid at `(x, y)` without building a
+/// [`Lut2D`].
+///
+/// `data[yi * xs.len() + xi]` is the value at `(xs[xi], ys[yi])`, as in
+/// [`Lut2D::new`], and the result matches [`Lut2D::lookup`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`Lut2D::new`].
+///
+/// [`Lut2D`]: super::Lut2D
+/// [`Lut2D::new`]: super::Lut2D::new
+/// [`Lut2D::lookup`]: super::Lut2D::lookup
+pub fn interpolate_2d(
+    xs: &[f64],
+    ys: &[f64],
+    data: &[f64],
+    x: f64,
+    y: f64,
+) -> Result<f64, LutError> {
+    validate_axis(xs, "X", LutError::EmptyXAxis)?;
+    validate_axis(ys, "Y", LutError::EmptyYAxis)?;
+    validate_data_len(data, xs.len() * ys.len())?;
+    validate_data(data)?;
+
+    let (x0, x1, tx) = find_bracket(xs, x);
+    let (y0, y1, ty) = find_bracket(ys, y);
+    let nx = xs.len();
 
-    (lo, t)
+    let v0 = lerp(data[y0 * nx + x0], data[y0 * nx + x1], tx);
+    let v1 = lerp(data[y1 * nx + x0], data[y1 * nx + x1], tx);
+    Ok(lerp(v0, v1, ty))
+}
+
+/// Trilinearly interpolates a grid at `(x, y, z)` without building a
+/// [`Lut3D`].
+///
+/// `data[zi * xs.len() * ys.len() + yi * xs.len() + xi]` is the value at
+/// `(xs[xi], ys[yi], zs[zi])`, as in [`Lut3D::new`], and the result matches
+/// [`Lut3D::lookup`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`Lut3D::new`].
+///
+/// [`Lut3D`]: super::Lut3D
+/// [`Lut3D::new`]: super::Lut3D::new
+/// [`Lut3D::lookup`]: super::Lut3D::lookup
+#[allow(clippy::similar_names)]
+pub fn interpolate_3d(
+    xs: &[f64],
+    ys: &[f64],
+    zs: &[f64],
+    data: &[f64],
+    x: f64,
+    y: f64,
+    z: f64,
+) -> Result<f64, LutError> {
+    validate_axis(xs, "X", LutError::EmptyXAxis)?;
+    validate_axis(ys, "Y", LutError::EmptyYAxis)?;
+    validate_axis(zs, "Z", LutError::EmptyZAxis)?;
+    validate_data_len(data, xs.len() * ys.len() * zs.len())?;
+    validate_data(data)?;
+
+    let (x0, x1, tx) = find_bracket(xs, x);
+    let (y0, y1, ty) = find_bracket(ys, y);
+    let (z0, z1, tz) = find_bracket(zs, z);
+    let nx = xs.len();
+    let nxy = nx * ys.len();
+    let idx = |ix: usize, iy: usize, iz: usize| iz * nxy + iy * nx + ix;
+
+    let c00 = lerp(data[idx(x0, y0, z0)], data[idx(x1, y0, z0)], tx);
+    let c10 = lerp(data[idx(x0, y1, z0)], data[idx(x1, y1, z0)], tx);
+    let c01 = lerp(data[idx(x0, y0, z1)], data[idx(x1, y0, z1)], tx);
+    let c11 = lerp(data[idx(x0, y1, z1)], data[idx(x1, y1, z1)], tx);
+
+    let c0 = lerp(c00, c10, ty);
+    let c1 = lerp(c01, c11, ty);
+    Ok(lerp(c0, c1, tz))
+}
+
+/// Interval lookup with hysteresis around the breakpoints.
+///
+/// Starting from the confirmed interval `current`, moves to the interval
+/// [`find_interval`] would pick only as far as `x` has passed each
+/// intervening breakpoint by more than `band / 2`. Returns the new
+/// confirmed interval index.
+#[inline]
+pub(super) fn find_interval_hysteresis(axis: &[f64], x: f64, current: usize, band: f64) -> usize {
+    let margin = 0.5 * band;
+    let mut i = find_interval(axis, x).0;
+    // Moving up: interval i starts at breakpoint i
+    while i > current && x <= axis[i] + margin {
+        i -= 1;
+    }
+    // Moving down: interval i ends at breakpoint i + 1
+    while i < current && x >= axis[i + 1] - margin {
+        i += 1;
+    }
+    i
 }
 
 /// Linear interpolation between two values.
@@ -63,3 +356,206 @@ pub(super) fn find_interval(axis: &[f64], x: f64) -> (usize, f64) {
 pub(super) fn lerp(a: f64, b: f64, t: f64) -> f64 {
     a + t * (b - a)
 }
+
+/// Wraps `x` into `[axis[0], axis[n-1])` for periodic lookups.
+#[inline]
+pub(super) fn wrap_periodic(axis: &[f64], x: f64) -> f64 {
+    let first = axis[0];
+    let period = axis[axis.len() - 1] - first;
+    if period <= 0.0 {
+        return x;
+    }
+    let mut offset = libm::fmod(x - first, period);
+    if offset < 0.0 {
+        offset += period;
+    }
+    first + offset
+}
+
+/// Returns true if two data values are equal within floating-point rounding.
+#[inline]
+pub(super) fn values_match(a: f64, b: f64) -> bool {
+    let scale = libm::fmax(1.0, libm::fmax(libm::fabs(a), libm::fabs(b)));
+    libm::fabs(a - b) <= 1e-12 * scale
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::cell::Cell;
+
+    std::thread_local! {
+        /// Axis comparisons made by the interval searches on this thread.
+        static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Records one comparison against the axis.
+    pub(super) fn count_comparison() {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+    }
+
+    /// Returns the comparisons `search` makes.
+    fn comparisons<R>(search: impl FnOnce() -> R) -> (R, usize) {
+        let before = COMPARISONS.with(Cell::get);
+        let result = search();
+        (result, COMPARISONS.with(Cell::get) - before)
+    }
+
+    #[test]
+    fn test_min_axis_stride() {
+        assert!((min_axis_stride(&[0.0, 1.0, 1.25, 3.0]) - 0.25).abs() < 1e-15);
+        assert!(min_axis_stride(&[5.0]).is_infinite());
+    }
+
+    #[test]
+    fn test_find_interval_hysteresis() {
+        let axis = [0.0, 1.0, 2.0, 3.0];
+        // Inside the band around breakpoint 1 the current interval sticks
+        assert_eq!(find_interval_hysteresis(&axis, 1.1, 0, 0.3), 0);
+        assert_eq!(find_interval_hysteresis(&axis, 0.9, 1, 0.3), 1);
+        // Past the band it switches
+        assert_eq!(find_interval_hysteresis(&axis, 1.2, 0, 0.3), 1);
+        assert_eq!(find_interval_hysteresis(&axis, 0.8, 1, 0.3), 0);
+        // Large jumps stop short of a breakpoint still inside the band
+        assert_eq!(find_interval_hysteresis(&axis, 2.1, 0, 0.3), 1);
+        assert_eq!(find_interval_hysteresis(&axis, 5.0, 0, 0.3), 2);
+        assert_eq!(find_interval_hysteresis(&axis, 0.9, 2, 0.3), 1);
+        assert_eq!(find_interval_hysteresis(&axis, 2.5, 2, 0.3), 2);
+    }
+
+    #[test]
+    fn test_validate_data() {
+        assert_eq!(validate_data(&[]), Ok(()));
+        assert_eq!(validate_data(&[0.0, -1e300, 5.0]), Ok(()));
+        assert_eq!(
+            validate_data(&[0.0, f64::NAN, f64::INFINITY]),
+            Err(LutError::NaNInData { index: 1 })
+        );
+        assert_eq!(
+            validate_data(&[0.0, f64::NEG_INFINITY, f64::NAN]),
+            Err(LutError::InfInData { index: 1 })
+        );
+
+        assert!(validate_data_const(&[0.0, -1e300, 5.0]));
+        assert!(!validate_data_const(&[0.0, f64::NAN]));
+        assert!(!validate_data_const(&[f64::NEG_INFINITY]));
+    }
+
+    #[test]
+    fn test_unresolvable_stride_rejected() {
+        let tiny = [1e-300, 1e-300 + 1e-316];
+        assert!(tiny[1] > tiny[0]);
+        assert_eq!(
+            validate_axis(&tiny, "X", LutError::EmptyXAxis),
+            Err(LutError::UnsortedAxis {
+                axis: "X",
+                index: 1
+            })
+        );
+        assert!(!validate_axis_const(&tiny));
+
+        let two_ulps = [1.0, 1.0 + 2.0 * f64::EPSILON];
+        assert!(validate_axis(&two_ulps, "X", LutError::EmptyXAxis).is_ok());
+        assert!(validate_axis(&[0.0, 0.1, 0.2, 1e6], "X", LutError::EmptyXAxis).is_ok());
+        assert!(validate_axis(&[-2.0, -1.0, 0.0], "X", LutError::EmptyXAxis).is_ok());
+    }
+
+    #[test]
+    fn test_interpolate_free_functions_match_luts() {
+        use super::super::{Lut1D, Lut2D, Lut3D};
+        use alloc::vec::Vec;
+
+        let xs = [0.0, 1.0, 3.0];
+        let ys = [-1.0, 2.0];
+        let zs = [10.0, 20.0];
+        let data_1d = [5.0, -2.0, 4.0];
+        let data_2d: Vec<f64> = (0..6).map(|i| f64::from(i * i) - 3.0).collect();
+        let data_3d: Vec<f64> = (0..12)
+            .map(|i| f64::from(i) * 0.5 - f64::from(i % 3))
+            .collect();
+
+        let lut1 = Lut1D::new(xs.to_vec(), data_1d.to_vec()).unwrap();
+        let lut2 = Lut2D::new(xs.to_vec(), ys.to_vec(), data_2d.clone()).unwrap();
+        let lut3 = Lut3D::new(xs.to_vec(), ys.to_vec(), zs.to_vec(), data_3d.clone()).unwrap();
+
+        for x in [-1.0, 0.0, 0.4, 2.2, 3.0, 5.0] {
+            let free = interpolate_1d(&xs, &data_1d, x).unwrap();
+            assert!((free - lut1.lookup(x)).abs() < 1e-12);
+            for y in [-2.0, 0.5, 2.0] {
+                let free = interpolate_2d(&xs, &ys, &data_2d, x, y).unwrap();
+                assert!((free - lut2.lookup(x, y)).abs() < 1e-12);
+                for z in [0.0, 12.5, 25.0] {
+                    let free = interpolate_3d(&xs, &ys, &zs, &data_3d, x, y, z).unwrap();
+                    assert!((free - lut3.lookup(x, y, z)).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_free_functions_validate() {
+        assert_eq!(interpolate_1d(&[], &[], 0.0), Err(LutError::EmptyXAxis));
+        assert_eq!(
+            interpolate_1d(&[0.0, 1.0], &[1.0], 0.5),
+            Err(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            interpolate_2d(&[0.0, 1.0], &[1.0, 0.0], &[0.0; 4], 0.5, 0.5),
+            Err(LutError::UnsortedAxis {
+                axis: "Y",
+                index: 1
+            })
+        );
+        assert_eq!(
+            interpolate_3d(&[0.0], &[0.0], &[], &[], 0.0, 0.0, 0.0),
+            Err(LutError::EmptyZAxis)
+        );
+        assert_eq!(
+            interpolate_1d(&[0.0, 1.0], &[1.0, f64::NAN], 0.0),
+            Err(LutError::NaNInData { index: 1 })
+        );
+        assert_eq!(
+            interpolate_2d(&[0.0], &[0.0, 1.0], &[f64::INFINITY, 0.0], 0.0, 1.0),
+            Err(LutError::InfInData { index: 0 })
+        );
+        assert_eq!(
+            interpolate_3d(&[0.0], &[0.0], &[0.0], &[f64::NAN], 0.0, 0.0, 0.0),
+            Err(LutError::NaNInData { index: 0 })
+        );
+        assert_eq!(interpolate_1d(&[2.0], &[7.0], 5.0), Ok(7.0));
+    }
+
+    #[test]
+    fn test_find_interval_from_hint() {
+        use alloc::vec::Vec;
+
+        let axis: Vec<f64> = (0..100).map(|i| f64::from(i) * 0.5).collect();
+        let queries = (0..10_000).map(|i| f64::from(i) * 0.005 - 1.0);
+
+        let (mut hinted, mut cold) = (0_usize, 0_usize);
+        let mut hint = 0;
+        for x in queries {
+            let expected = find_interval(&axis, x);
+            let (found, count) = comparisons(|| find_interval_from_hint(&axis, x, hint));
+            hinted += count;
+            assert_eq!(found.0, expected.0);
+            assert!((found.1 - expected.1).abs() < 1e-15);
+            hint = found.0;
+
+            // An out-of-range hint measures the cold binary search
+            cold += comparisons(|| find_interval_from_hint(&axis, x, usize::MAX)).1;
+        }
+        assert!(hinted * 2 < cold, "hinted {hinted} vs cold {cold}");
+
+        // A hit costs the two range checks plus both sides of the bracket
+        assert_eq!(
+            comparisons(|| find_interval_from_hint(&axis, 10.2, 20)).1,
+            4
+        );
+    }
+}
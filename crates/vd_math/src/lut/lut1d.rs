@@ -2,7 +2,147 @@
 
 use alloc::vec::Vec;
 
-use super::{find_interval, lerp, validate_axis, LutError};
+use super::{
+    find_interval, find_interval_from_hint, find_interval_hysteresis, is_ascending_step, lerp,
+    min_axis_stride, validate_axis, validate_data, values_match, wrap_periodic, Lut2D, LutError,
+};
+
+/// Direction of a LUT curve over a run of segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    /// Every segment in the run rises.
+    Increasing,
+    /// Every segment in the run falls.
+    Decreasing,
+    /// Every segment in the run is flat.
+    Constant,
+}
+
+/// A maximal run of breakpoints over which a curve has one direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonotoneSegment {
+    /// Index of the first breakpoint of the run.
+    pub start_index: usize,
+    /// Index of the last breakpoint of the run (inclusive).
+    pub end_index: usize,
+    /// Direction of the curve over the run.
+    pub direction: Monotonicity,
+}
+
+/// State carried between calls to [`Lut1D::lookup_with_hold`].
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::{HoldState, Lut1D};
+///
+/// let gear = Lut1D::new(vec![0.0, 10.0, 20.0], vec![1.0, 1.0, 2.0]).unwrap();
+/// let mut hold = HoldState::new(0.05);
+/// assert_eq!(gear.lookup_with_hold(5.0, &mut hold, 0.01), 1.0);
+/// // Crossing into the next interval holds the previous output
+/// assert_eq!(gear.lookup_with_hold(20.0, &mut hold, 0.01), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HoldState {
+    /// How long the output is held after the input enters a new interval (s).
+    pub hold_duration: f64,
+    /// Time left in the current hold (s); zero when not holding.
+    pub remaining_hold: f64,
+    /// Output returned while holding, and the last output otherwise.
+    pub held_value: f64,
+    /// Interval of the previous input, or `None` before the first lookup.
+    interval: Option<usize>,
+}
+
+impl HoldState {
+    /// Creates a state that holds for `hold_duration` seconds, with no
+    /// previous lookup.
+    #[must_use]
+    pub const fn new(hold_duration: f64) -> Self {
+        Self {
+            hold_duration,
+            remaining_hold: 0.0,
+            held_value: f64::NAN,
+            interval: None,
+        }
+    }
+}
+
+/// State carried between calls to [`Lut1D::lookup_with_hysteresis`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HysteresisState {
+    /// Last confirmed interval, or `None` before the first lookup.
+    interval: Option<usize>,
+}
+
+impl HysteresisState {
+    /// Creates a state with no confirmed interval.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { interval: None }
+    }
+
+    /// Returns the last confirmed interval index, if any.
+    #[must_use]
+    pub const fn interval(&self) -> Option<usize> {
+        self.interval
+    }
+}
+
+/// Builds a [`Lut1D`] from constant arrays, validated at compile time.
+///
+/// Both arguments must be constant expressions of type `[f64; N]`. An empty
+/// or unsorted axis, mismatched lengths, or NaN or infinite data fail the
+/// build instead of returning an error at runtime.
+///
+/// # Example
+///
+/// ```
+/// let lut = vd_math::lut1d_static!([0.0, 1000.0, 2000.0], [0.0, 100.0, 150.0]);
+/// assert!((lut.lookup(500.0) - 50.0).abs() < 1e-10);
+/// ```
+///
+/// A reversed axis does not compile:
+///
+/// ```compile_fail
+/// let lut = vd_math::lut1d_static!([2.0, 1.0, 0.0], [0.0, 1.0, 2.0]);
+/// ```
+///
+/// Neither do mismatched lengths:
+///
+/// ```compile_fail
+/// let lut = vd_math::lut1d_static!([0.0, 1.0, 2.0], [0.0, 1.0]);
+/// ```
+///
+/// Nor does non-finite data:
+///
+/// ```compile_fail
+/// let lut = vd_math::lut1d_static!([0.0, 1.0], [0.0, f64::NAN]);
+/// ```
+#[macro_export]
+macro_rules! lut1d_static {
+    ($x_axis:expr, $data:expr $(,)?) => {{
+        const X_AXIS: &[f64] = &$x_axis;
+        const DATA: &[f64] = &$data;
+        const _: () = assert!(
+            $crate::lut::validate_axis_const(X_AXIS),
+            "lut1d_static!: X axis must be non-empty and strictly ascending"
+        );
+        const _: () = assert!(
+            X_AXIS.len() == DATA.len(),
+            "lut1d_static!: data length must match X axis length"
+        );
+        const _: () = assert!(
+            $crate::lut::validate_data_const(DATA),
+            "lut1d_static!: data must be finite"
+        );
+        $crate::lut::Lut1D::new(
+            ::core::convert::From::from(X_AXIS),
+            ::core::convert::From::from(DATA),
+        )
+        .expect("lut1d_static!: checked at compile time")
+    }};
+}
 
 /// 1D lookup table for y = f(x) interpolation.
 ///
@@ -25,6 +165,11 @@ use super::{find_interval, lerp, validate_axis, LutError};
 pub struct Lut1D {
     x_axis: Vec<f64>,
     data: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    periodic_x: bool,
+    /// Slope at each breakpoint for Hermite interpolation; empty for linear.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tangents: Vec<f64>,
 }
 
 impl Lut1D {
@@ -41,6 +186,7 @@ impl Lut1D {
     /// - `x_axis` is empty
     /// - `x_axis` is not strictly ascending
     /// - `data` length doesn't match `x_axis` length
+    /// - `data` contains NaN or infinite values
     pub fn new(x_axis: Vec<f64>, data: Vec<f64>) -> Result<Self, LutError> {
         validate_axis(&x_axis, "X", LutError::EmptyXAxis)?;
 
@@ -50,157 +196,2434 @@ impl Lut1D {
                 actual: data.len(),
             });
         }
+        validate_data(&data)?;
+
+        Ok(Self {
+            x_axis,
+            data,
+            periodic_x: false,
+            tangents: Vec::new(),
+        })
+    }
+
+    /// Creates a 1D lookup table interpolated with cubic Hermite splines.
+    ///
+    /// `tangents[i]` is the slope `dy/dx` of the curve at `x_axis[i]`, in
+    /// the units of `data / x_axis`. Each segment is the unique cubic that
+    /// matches the values and slopes at both of its breakpoints, so a cubic
+    /// polynomial is reproduced exactly when given its true derivatives.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// // y = x^2 with exact slopes 2x
+    /// let lut = Lut1D::with_hermite_tangents(
+    ///     vec![0.0, 1.0, 2.0],
+    ///     vec![0.0, 1.0, 4.0],
+    ///     vec![0.0, 2.0, 4.0],
+    /// )
+    /// .unwrap();
+    /// assert!((lut.lookup(1.5) - 2.25).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError` for the same reasons as [`Lut1D::new`],
+    /// `LutError::DimensionMismatch` if `tangents` length doesn't match
+    /// `x_axis` length, or `LutError::NaNInData`/`LutError::InfInData`
+    /// (indexing into `tangents`) if a tangent is not finite.
+    pub fn with_hermite_tangents(
+        x_axis: Vec<f64>,
+        data: Vec<f64>,
+        tangents: Vec<f64>,
+    ) -> Result<Self, LutError> {
+        let mut lut = Self::new(x_axis, data)?;
+        if tangents.len() != lut.x_axis.len() {
+            return Err(LutError::DimensionMismatch {
+                expected: lut.x_axis.len(),
+                actual: tangents.len(),
+            });
+        }
+        validate_data(&tangents)?;
+        lut.tangents = tangents;
+        Ok(lut)
+    }
+
+    /// Returns the breakpoint tangents if the table uses Hermite interpolation.
+    #[must_use]
+    pub fn tangents(&self) -> Option<&[f64]> {
+        if self.tangents.is_empty() {
+            None
+        } else {
+            Some(&self.tangents)
+        }
+    }
+
+    /// Marks the X axis as periodic, so lookups wrap around its range.
+    ///
+    /// A query `x` is mapped into `[x0, xn)` before lookup, making the table
+    /// suitable for angular maps where e.g. 360 degrees equals 0 degrees.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NonPeriodicData` if the first and last data values
+    /// differ, since the wrapped function would be discontinuous.
+    pub fn with_periodic_x(mut self) -> Result<Self, LutError> {
+        if !values_match(self.data[0], self.data[self.data.len() - 1]) {
+            return Err(LutError::NonPeriodicData { axis: "X" });
+        }
+        self.periodic_x = true;
+        Ok(self)
+    }
 
-        Ok(Self { x_axis, data })
+    /// Returns true if lookups wrap around the X axis range.
+    #[must_use]
+    pub const fn is_periodic_x(&self) -> bool {
+        self.periodic_x
     }
 
     /// Looks up and interpolates a value at the given x coordinate.
     ///
-    /// Uses linear interpolation between adjacent points.
+    /// Uses linear interpolation between adjacent points, or cubic Hermite
+    /// interpolation if the table was built with tangents.
     /// Values outside the axis range are clamped to boundary values.
     #[inline]
     #[must_use]
     pub fn lookup(&self, x: f64) -> f64 {
+        if self.data.len() == 1 {
+            return self.data[0];
+        }
+        let x = if self.periodic_x {
+            wrap_periodic(&self.x_axis, x)
+        } else {
+            x
+        };
         let (i, t) = find_interval(&self.x_axis, x);
-        lerp(self.data[i], self.data[i + 1], t)
+        self.interpolate(i, t)
     }
 
-    /// Returns the X axis values.
+    /// Looks up `x`, starting the interval search from `*hint`.
+    ///
+    /// For sequential queries such as evaluating a table along a time
+    /// series, the interval rarely moves by more than one, so checking the
+    /// hinted interval and its neighbours first avoids most of the binary
+    /// search. `*hint` is updated to the interval used and can start at 0.
+    /// Returns exactly what [`Lut1D::lookup`] returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// let lut = Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![0.0, 10.0, 15.0, 17.0]).unwrap();
+    /// let mut hint = 0;
+    /// for step in 0..30 {
+    ///     let x = f64::from(step) * 0.1;
+    ///     assert_eq!(lut.lookup_with_hint(x, &mut hint), lut.lookup(x));
+    /// }
+    /// ```
+    #[inline]
     #[must_use]
-    pub fn x_axis(&self) -> &[f64] {
-        &self.x_axis
+    pub fn lookup_with_hint(&self, x: f64, hint: &mut usize) -> f64 {
+        if self.data.len() == 1 {
+            return self.data[0];
+        }
+        let x = if self.periodic_x {
+            wrap_periodic(&self.x_axis, x)
+        } else {
+            x
+        };
+        let (i, t) = find_interval_from_hint(&self.x_axis, x, *hint);
+        *hint = i;
+        self.interpolate(i, t)
     }
 
-    /// Returns the data values.
-    #[must_use]
-    pub fn data(&self) -> &[f64] {
-        &self.data
-    }
+    /// Interpolates within interval `i` at factor `t`.
+    #[inline]
+    fn interpolate(&self, i: usize, t: f64) -> f64 {
+        if self.tangents.is_empty() {
+            return lerp(self.data[i], self.data[i + 1], t);
+        }
 
-    /// Returns the number of data points.
-    #[must_use]
-    pub fn len(&self) -> usize {
-        self.data.len()
+        let h = self.x_axis[i + 1] - self.x_axis[i];
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = 3.0 * t2 - 2.0 * t3;
+        let h11 = t3 - t2;
+        h00 * self.data[i]
+            + h10 * h * self.tangents[i]
+            + h01 * self.data[i + 1]
+            + h11 * h * self.tangents[i + 1]
     }
 
-    /// Returns true if the LUT has no data points.
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+    /// Looks up `x`, holding the previous output for a while after `x`
+    /// moves into a different interval between breakpoints.
+    ///
+    /// Models solenoid debounce on shift maps: when the interval changes,
+    /// the last output is returned for `hold_duration`, counted in steps of
+    /// `dt` and rounded to a whole number of steps, before the new value is
+    /// passed through. Changing interval again during a hold restarts it.
+    /// The first call on a fresh [`HoldState`] never holds.
+    pub fn lookup_with_hold(&self, x: f64, hold_state: &mut HoldState, dt: f64) -> f64 {
+        let x = if self.periodic_x {
+            wrap_periodic(&self.x_axis, x)
+        } else {
+            x
+        };
+        let interval = if self.data.len() == 1 {
+            0
+        } else {
+            find_interval(&self.x_axis, x).0
+        };
+
+        let previous = hold_state.interval.replace(interval);
+        if previous.is_some_and(|i| i != interval) {
+            hold_state.remaining_hold = hold_state.hold_duration;
+        }
+
+        // Half a step of slack absorbs rounding in the accumulated time
+        if hold_state.remaining_hold >= 0.5 * dt {
+            hold_state.remaining_hold -= dt;
+            return hold_state.held_value;
+        }
+
+        hold_state.remaining_hold = 0.0;
+        hold_state.held_value = self.lookup(x);
+        hold_state.held_value
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloc::vec;
+    /// Looks up `x` with hysteresis on the interval selection.
+    ///
+    /// The interval between breakpoints only changes once `x` has passed
+    /// the breakpoint into it by more than `band / 2`, which stops
+    /// threshold maps from chattering when the input hovers near a
+    /// breakpoint. While `x` is inside the band, the result is the value of
+    /// the confirmed interval at its nearest edge. The first call on a
+    /// fresh [`HysteresisState`] takes the interval containing `x`.
+    pub fn lookup_with_hysteresis(&self, x: f64, state: &mut HysteresisState, band: f64) -> f64 {
+        if self.data.len() == 1 {
+            return self.data[0];
+        }
+        let x = if self.periodic_x {
+            wrap_periodic(&self.x_axis, x)
+        } else {
+            x
+        };
 
-    #[test]
-    fn test_exact_match() {
-        let lut =
-            Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![10.0, 20.0, 30.0, 40.0]).expect("valid LUT");
+        let i = state.interval.map_or_else(
+            || find_interval(&self.x_axis, x).0,
+            |current| find_interval_hysteresis(&self.x_axis, x, current, band),
+        );
+        state.interval = Some(i);
 
-        assert!((lut.lookup(0.0) - 10.0).abs() < 1e-10);
-        assert!((lut.lookup(1.0) - 20.0).abs() < 1e-10);
-        assert!((lut.lookup(2.0) - 30.0).abs() < 1e-10);
-        assert!((lut.lookup(3.0) - 40.0).abs() < 1e-10);
+        let (x0, x1) = (self.x_axis[i], self.x_axis[i + 1]);
+        let t = ((x - x0) / (x1 - x0)).clamp(0.0, 1.0);
+        self.interpolate(i, t)
     }
 
-    #[test]
-    fn test_interpolation_midpoint() {
-        let lut = Lut1D::new(vec![0.0, 2.0, 4.0], vec![0.0, 100.0, 200.0]).expect("valid LUT");
+    /// Appends new `(x, y)` points to the end of the table.
+    ///
+    /// All inputs are validated before the table is modified, so on error
+    /// the LUT is left unchanged. On a Hermite table each appended point
+    /// takes the slope of the segment leading to it as its tangent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError` if:
+    /// - `xs` and `ys` have different lengths
+    /// - `xs` does not continue the axis in strictly ascending order
+    /// - `ys` contains NaN or infinite values
+    pub fn extend_axis(&mut self, xs: &[f64], ys: &[f64]) -> Result<(), LutError> {
+        if xs.len() != ys.len() {
+            return Err(LutError::DimensionMismatch {
+                expected: xs.len(),
+                actual: ys.len(),
+            });
+        }
 
-        assert!((lut.lookup(1.0) - 50.0).abs() < 1e-10);
-        assert!((lut.lookup(3.0) - 150.0).abs() < 1e-10);
+        let mut prev = self.x_axis[self.x_axis.len() - 1];
+        for (i, &x) in xs.iter().enumerate() {
+            if !is_ascending_step(prev, x) {
+                return Err(LutError::UnsortedAxis {
+                    axis: "X",
+                    index: self.x_axis.len() + i,
+                });
+            }
+            prev = x;
+        }
+        // Report indices into the extended data, like the axis check above
+        let offset = self.data.len();
+        validate_data(ys).map_err(|err| match err {
+            LutError::NaNInData { index } => LutError::NaNInData {
+                index: offset + index,
+            },
+            LutError::InfInData { index } => LutError::InfInData {
+                index: offset + index,
+            },
+            other => other,
+        })?;
+
+        if !self.tangents.is_empty() {
+            for (&x, &y) in xs.iter().zip(ys) {
+                let last = self.x_axis.len() - 1;
+                self.tangents
+                    .push((y - self.data[last]) / (x - self.x_axis[last]));
+                self.x_axis.push(x);
+                self.data.push(y);
+            }
+            return Ok(());
+        }
+
+        self.x_axis.extend_from_slice(xs);
+        self.data.extend_from_slice(ys);
+        Ok(())
     }
 
-    #[test]
-    fn test_interpolation_quarter() {
-        let lut = Lut1D::new(vec![0.0, 4.0], vec![0.0, 100.0]).expect("valid LUT");
+    /// Joins two tables covering adjacent ranges into one.
+    ///
+    /// The result holds every breakpoint of `low` followed by every
+    /// breakpoint of `high`. If `low` ends where `high` starts (up to
+    /// floating-point rounding), the shared point takes `high`'s value. Hermite tangents and the periodic
+    /// flag are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// let cold = Lut1D::new(vec![0.0, 500.0, 1000.0], vec![0.0, 60.0, 95.0]).unwrap();
+    /// let warm = Lut1D::new(vec![1000.0, 4000.0, 8000.0], vec![100.0, 250.0, 180.0]).unwrap();
+    /// let torque = Lut1D::merge(&cold, &warm).unwrap();
+    /// assert_eq!(torque.x_axis(), &[0.0, 500.0, 1000.0, 4000.0, 8000.0]);
+    /// assert_eq!(torque.data()[2], 100.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::UnsortedAxis` if `low` extends past the start of
+    /// `high`, with `index` pointing at the first out-of-order breakpoint of
+    /// the merged axis.
+    pub fn merge(low: &Self, high: &Self) -> Result<Self, LutError> {
+        let shared = values_match(low.x_axis[low.x_axis.len() - 1], high.x_axis[0]);
+        let low_len = low.x_axis.len() - usize::from(shared);
 
-        assert!((lut.lookup(1.0) - 25.0).abs() < 1e-10);
-        assert!((lut.lookup(2.0) - 50.0).abs() < 1e-10);
-        assert!((lut.lookup(3.0) - 75.0).abs() < 1e-10);
+        let mut x_axis = Vec::with_capacity(low_len + high.x_axis.len());
+        x_axis.extend_from_slice(&low.x_axis[..low_len]);
+        x_axis.extend_from_slice(&high.x_axis);
+        let mut data = Vec::with_capacity(x_axis.len());
+        data.extend_from_slice(&low.data[..low_len]);
+        data.extend_from_slice(&high.data);
+        Self::new(x_axis, data)
     }
 
-    #[test]
-    fn test_out_of_bounds_low() {
-        let lut = Lut1D::new(vec![10.0, 20.0, 30.0], vec![100.0, 200.0, 300.0]).expect("valid LUT");
+    /// Returns a copy with the data denoised by a centred moving average.
+    ///
+    /// Each value is replaced by the mean of the `window` values centred on
+    /// it. Near the ends the window is truncated to the points available on
+    /// each side. The X axis is unchanged; Hermite tangents and the periodic
+    /// flag are dropped, so the result interpolates linearly with clamping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if `window` is even (including
+    /// zero), with `expected` set to the next odd size, or
+    /// `LutError::InfInData` if a window sum overflows.
+    pub fn smooth(&self, window: usize) -> Result<Self, LutError> {
+        if window % 2 == 0 {
+            return Err(LutError::DimensionMismatch {
+                expected: window + 1,
+                actual: window,
+            });
+        }
 
-        assert!((lut.lookup(0.0) - 100.0).abs() < 1e-10);
-        assert!((lut.lookup(-100.0) - 100.0).abs() < 1e-10);
+        let half = window / 2;
+        let n = self.data.len();
+        let data: Vec<f64> = (0..n)
+            .map(|i| {
+                let neighbours = &self.data[i.saturating_sub(half)..(i + half + 1).min(n)];
+                neighbours.iter().sum::<f64>() / neighbours.len() as f64
+            })
+            .collect();
+        validate_data(&data)?;
+
+        Ok(Self {
+            x_axis: self.x_axis.clone(),
+            data,
+            periodic_x: false,
+            tangents: Vec::new(),
+        })
     }
 
-    #[test]
-    fn test_out_of_bounds_high() {
-        let lut = Lut1D::new(vec![10.0, 20.0, 30.0], vec![100.0, 200.0, 300.0]).expect("valid LUT");
+    /// Returns a copy without breakpoints whose data deviates from the mean
+    /// by more than `sigma` population standard deviations.
+    ///
+    /// Statistics are computed once over all data values. If fewer than two
+    /// points would remain, the table is returned unchanged. Hermite
+    /// tangents and the periodic flag are dropped whenever points are removed.
+    #[must_use]
+    pub fn remove_outliers(&self, sigma: f64) -> Self {
+        let n = self.data.len() as f64;
+        let mean = self.data.iter().sum::<f64>() / n;
+        let variance = self
+            .data
+            .iter()
+            .map(|y| (y - mean) * (y - mean))
+            .sum::<f64>()
+            / n;
+        let limit = sigma * libm::sqrt(variance);
 
-        assert!((lut.lookup(40.0) - 300.0).abs() < 1e-10);
-        assert!((lut.lookup(1000.0) - 300.0).abs() < 1e-10);
+        let (x_axis, data): (Vec<f64>, Vec<f64>) = self
+            .x_axis
+            .iter()
+            .zip(&self.data)
+            .filter(|&(_, &y)| libm::fabs(y - mean) <= limit)
+            .map(|(&x, &y)| (x, y))
+            .unzip();
+
+        if x_axis.len() < 2 || x_axis.len() == self.x_axis.len() {
+            return self.clone();
+        }
+        Self::from_validated_parts(&x_axis, &data)
     }
 
-    #[test]
-    fn test_error_empty_axis() {
-        let result = Lut1D::new(vec![], vec![]);
-        assert!(matches!(result, Err(LutError::EmptyXAxis)));
+    /// Returns the sorted union of both tables' X breakpoints, without
+    /// duplicates.
+    #[must_use]
+    pub fn merged_axis(&self, other: &Self) -> Vec<f64> {
+        let mut axis = Vec::with_capacity(self.x_axis.len() + other.x_axis.len());
+        axis.extend_from_slice(&self.x_axis);
+        axis.extend_from_slice(&other.x_axis);
+        axis.sort_by(f64::total_cmp);
+        axis.dedup();
+        axis
     }
 
-    #[test]
-    fn test_error_unsorted_axis() {
-        let result = Lut1D::new(vec![0.0, 2.0, 1.0], vec![0.0, 1.0, 2.0]);
-        assert!(matches!(
-            result,
-            Err(LutError::UnsortedAxis {
-                axis: "X",
-                index: 2
-            })
-        ));
+    /// Resamples both tables onto the union of their X axes.
+    ///
+    /// Each result is its table's [`Lut1D::lookup`] evaluated at every
+    /// breakpoint of [`Lut1D::merged_axis`], so it clamps outside its
+    /// original range and the two results can be combined pointwise.
+    /// Breakpoints too close together to resolve are merged. Hermite
+    /// tangents and the periodic flag are not carried over.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// let a = Lut1D::new(vec![0.0, 2.0], vec![0.0, 4.0]).unwrap();
+    /// let b = Lut1D::new(vec![1.0, 3.0], vec![5.0, 1.0]).unwrap();
+    /// let (a, b) = a.resample_to_match(&b);
+    /// assert_eq!(a.x_axis(), b.x_axis());
+    /// let diff: Vec<f64> = a.data().iter().zip(b.data()).map(|(p, q)| p - q).collect();
+    /// assert_eq!(diff, [-5.0, -3.0, 1.0, 3.0]);
+    /// ```
+    #[must_use]
+    pub fn resample_to_match(&self, other: &Self) -> (Self, Self) {
+        let mut axis = self.merged_axis(other);
+        let mut prev = f64::NEG_INFINITY;
+        axis.retain(|&x| {
+            let keep = is_ascending_step(prev, x);
+            if keep {
+                prev = x;
+            }
+            keep
+        });
+
+        let sample = |lut: &Self| {
+            let data: Vec<f64> = axis.iter().map(|&x| lut.lookup(x)).collect();
+            Self::from_validated_parts(&axis, &data)
+        };
+        (sample(self), sample(other))
     }
 
-    #[test]
-    fn test_error_duplicate_values() {
-        let result = Lut1D::new(vec![0.0, 1.0, 1.0, 2.0], vec![0.0, 1.0, 2.0, 3.0]);
-        assert!(matches!(
-            result,
-            Err(LutError::UnsortedAxis {
-                axis: "X",
-                index: 2
+    /// Returns the integral of `|self(x) - other(x)|` over `[x_lo, x_hi]`.
+    ///
+    /// The difference is sampled at `x_lo`, `x_hi` and every breakpoint of
+    /// [`Lut1D::merged_axis`] in between, and integrated with the
+    /// trapezoidal rule. Intervals where the difference changes sign are
+    /// split at the crossing, so the result is exact for linear tables.
+    /// Returns 0.0 if `x_lo >= x_hi`.
+    #[must_use]
+    pub fn area_between(&self, other: &Self, x_lo: f64, x_hi: f64) -> f64 {
+        if x_lo >= x_hi {
+            return 0.0;
+        }
+
+        let mut xs = Vec::with_capacity(self.x_axis.len() + other.x_axis.len() + 2);
+        xs.push(x_lo);
+        xs.extend(
+            self.merged_axis(other)
+                .into_iter()
+                .filter(|&x| x > x_lo && x < x_hi),
+        );
+        xs.push(x_hi);
+
+        let diff = |x: f64| self.lookup(x) - other.lookup(x);
+        xs.windows(2)
+            .map(|w| {
+                let (d0, d1) = (diff(w[0]), diff(w[1]));
+                let h = w[1] - w[0];
+                if (d0 < 0.0) == (d1 < 0.0) {
+                    0.5 * h * libm::fabs(d0 + d1)
+                } else {
+                    // Two triangles either side of the zero crossing
+                    0.5 * h * (d0 * d0 + d1 * d1) / (libm::fabs(d0) + libm::fabs(d1))
+                }
             })
-        ));
+            .sum()
     }
 
-    #[test]
-    fn test_error_dimension_mismatch() {
-        let result = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0]);
-        assert!(matches!(
-            result,
-            Err(LutError::DimensionMismatch {
-                expected: 3,
-                actual: 2
-            })
-        ));
+    /// Returns true if this curve never exceeds `other` by more than `eps`.
+    ///
+    /// Both tables are compared at every breakpoint of [`Lut1D::merged_axis`].
+    /// For linear tables this covers the whole merged range, since the
+    /// difference of two piecewise-linear curves is extremal at a breakpoint.
+    #[must_use]
+    pub fn is_dominated_by(&self, other: &Self, eps: f64) -> bool {
+        self.merged_axis(other)
+            .into_iter()
+            .all(|x| self.lookup(x) <= other.lookup(x) + eps)
     }
 
-    #[test]
-    fn test_accessors() {
-        let lut = Lut1D::new(vec![1.0, 2.0, 3.0], vec![10.0, 20.0, 30.0]).expect("valid LUT");
+    /// Returns the largest `|self.data[i] - other.data[i]|` over all
+    /// breakpoints.
+    ///
+    /// Returns `None` if the tables do not share the same X axis, up to
+    /// floating-point rounding.
+    #[must_use]
+    pub fn max_absolute_change(&self, other: &Self) -> Option<f64> {
+        self.max_change(other, |_, diff| diff)
+    }
 
-        assert_eq!(lut.x_axis(), &[1.0, 2.0, 3.0]);
-        assert_eq!(lut.data(), &[10.0, 20.0, 30.0]);
-        assert_eq!(lut.len(), 3);
-        assert!(!lut.is_empty());
+    /// Returns the largest change relative to this table's data,
+    /// `|self.data[i] - other.data[i]| / (|self.data[i]| + f64::EPSILON)`.
+    ///
+    /// The epsilon keeps zero-valued breakpoints finite. Returns `None` if
+    /// the tables do not share the same X axis.
+    #[must_use]
+    pub fn max_relative_change(&self, other: &Self) -> Option<f64> {
+        self.max_change(other, |old, diff| diff / (libm::fabs(old) + f64::EPSILON))
     }
 
-    #[test]
-    fn test_many_lookups() {
-        let n = 100;
-        let x_axis: Vec<f64> = (0..n).map(|i| i as f64).collect();
-        let data: Vec<f64> = (0..n).map(|i| (i * i) as f64).collect();
-        let lut = Lut1D::new(x_axis, data).expect("valid LUT");
+    /// Largest `measure(self.data[i], |difference|)` over shared breakpoints.
+    fn max_change(&self, other: &Self, measure: impl Fn(f64, f64) -> f64) -> Option<f64> {
+        let same_axis = self.x_axis.len() == other.x_axis.len()
+            && self
+                .x_axis
+                .iter()
+                .zip(&other.x_axis)
+                .all(|(&a, &b)| values_match(a, b));
+        if !same_axis {
+            return None;
+        }
+        Some(
+            self.data
+                .iter()
+                .zip(&other.data)
+                .map(|(&old, &new)| measure(old, libm::fabs(old - new)))
+                .fold(0.0, libm::fmax),
+        )
+    }
 
-        for i in 0..10000 {
-            let x = (i % 99) as f64 + 0.5;
-            let _ = lut.lookup(x);
+    /// Returns the breakpoint `(x, y)` with the largest data value.
+    ///
+    /// Ties resolve to the first occurrence; NaN values are never selected
+    /// unless every value is NaN.
+    #[must_use]
+    pub fn peak(&self) -> (f64, f64) {
+        self.extreme_point(|candidate, best| candidate > best)
+    }
+
+    /// Returns the breakpoint `(x, y)` with the smallest data value.
+    ///
+    /// Ties resolve to the first occurrence; NaN values are never selected
+    /// unless every value is NaN.
+    #[must_use]
+    pub fn valley(&self) -> (f64, f64) {
+        self.extreme_point(|candidate, best| candidate < best)
+    }
+
+    /// Returns the x coordinate of [`Lut1D::peak`].
+    #[must_use]
+    pub fn peak_x(&self) -> f64 {
+        self.peak().0
+    }
+
+    /// Returns the x coordinate of [`Lut1D::valley`].
+    #[must_use]
+    pub fn valley_x(&self) -> f64 {
+        self.valley().0
+    }
+
+    /// Splits the curve at [`Lut1D::peak`] into its rising and falling arms.
+    ///
+    /// The first arm holds every breakpoint up to and including the peak,
+    /// the second every breakpoint from the peak onward, so both contain the
+    /// peak. If the peak is at either end of the table, that side's arm is a
+    /// single point. Hermite tangents and the periodic flag are not carried
+    /// over.
+    #[must_use]
+    pub fn split_at_peak(&self) -> (Self, Self) {
+        let peak = self.extreme_index(|candidate, best| candidate > best);
+        (
+            Self::from_validated_parts(&self.x_axis[..=peak], &self.data[..=peak]),
+            Self::from_validated_parts(&self.x_axis[peak..], &self.data[peak..]),
+        )
+    }
+
+    /// Scans the data, keeping the first point that `better` never beats.
+    fn extreme_point(&self, better: impl Fn(f64, f64) -> bool) -> (f64, f64) {
+        let best = self.extreme_index(better);
+        (self.x_axis[best], self.data[best])
+    }
+
+    /// Index of the point selected by [`Lut1D::extreme_point`].
+    fn extreme_index(&self, better: impl Fn(f64, f64) -> bool) -> usize {
+        let mut best = 0;
+        for (i, &y) in self.data.iter().enumerate().skip(1) {
+            if better(y, self.data[best]) || self.data[best].is_nan() {
+                best = i;
+            }
         }
+        best
+    }
+
+    /// Broadcasts the curve into a 2D map that is constant along `y_axis`.
+    ///
+    /// Every row of the result equals this table's data, so
+    /// `map.lookup(x, y) == self.lookup(x)` for any `y`. Hermite tangents and
+    /// the periodic flag are not carried over.
+    ///
+    /// The X axis and data are already valid, so only `y_axis` is checked.
+    /// Since it comes from the caller, it can still be empty or unsorted,
+    /// which is why this returns a `Result` rather than a bare `Lut2D`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::EmptyYAxis` or `LutError::UnsortedAxis` if
+    /// `y_axis` is not a valid axis.
+    pub fn to_lut2d_broadcast(&self, y_axis: Vec<f64>) -> Result<Lut2D, LutError> {
+        validate_axis(&y_axis, "Y", LutError::EmptyYAxis)?;
+        let data = self.data.repeat(y_axis.len());
+        Ok(Lut2D::from_validated_parts(
+            self.x_axis.clone(),
+            y_axis,
+            data,
+        ))
+    }
+
+    /// Returns a copy with the data convolved with an FIR `kernel`.
+    ///
+    /// The kernel is centred on each sample and the output has the same
+    /// length as the input; samples beyond either end are treated as zero.
+    /// The X axis is unchanged; Hermite tangents and the periodic flag are
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if `kernel` is empty or has an
+    /// even length, with `expected` set to the next odd length, or
+    /// `LutError::NaNInData`/`LutError::InfInData` if an output sample is
+    /// not finite, e.g. for a non-finite kernel weight.
+    pub fn convolve(&self, kernel: &[f64]) -> Result<Self, LutError> {
+        if kernel.len() % 2 == 0 {
+            return Err(LutError::DimensionMismatch {
+                expected: kernel.len() + 1,
+                actual: kernel.len(),
+            });
+        }
+
+        let half = kernel.len() / 2;
+        let n = self.data.len();
+        let data: Vec<f64> = (0..n)
+            .map(|i| {
+                kernel
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(k, &w)| {
+                        // Sample under kernel tap k; taps run backwards in time
+                        let j = (i + half).checked_sub(k)?;
+                        self.data.get(j).map(|&v| w * v)
+                    })
+                    .sum()
+            })
+            .collect();
+        validate_data(&data)?;
+
+        Ok(Self {
+            x_axis: self.x_axis.clone(),
+            data,
+            periodic_x: false,
+            tangents: Vec::new(),
+        })
+    }
+
+    /// Resamples the curve onto `n` evenly spaced breakpoints.
+    ///
+    /// The new axis runs from the first to the last X breakpoint and its
+    /// values come from [`Lut1D::lookup`], so Hermite and periodic tables
+    /// are sampled as they interpolate. The result is linear and clamped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if `n < 2`, with `expected`
+    /// set to 2, or `LutError::UnsortedAxis` for a single-point table.
+    pub fn to_uniform_grid(&self, n: usize) -> Result<Self, LutError> {
+        if n < 2 {
+            return Err(LutError::DimensionMismatch {
+                expected: 2,
+                actual: n,
+            });
+        }
+
+        let x_min = self.x_axis[0];
+        let x_max = self.x_axis[self.x_axis.len() - 1];
+        let step = (x_max - x_min) / (n - 1) as f64;
+        let x_axis: Vec<f64> = (0..n)
+            .map(|i| {
+                if i == n - 1 {
+                    x_max
+                } else {
+                    x_min + i as f64 * step
+                }
+            })
+            .collect();
+        let data = x_axis.iter().map(|&x| self.lookup(x)).collect();
+        Self::new(x_axis, data)
+    }
+
+    /// Returns a copy whose data is the running sum of this table's data,
+    /// `data[0] + ... + data[i]`.
+    ///
+    /// The X axis is unchanged; Hermite tangents and the periodic flag are
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::InfInData` if the running sum overflows.
+    pub fn cumulative_sum(&self) -> Result<Self, LutError> {
+        let data: Vec<f64> = self
+            .data
+            .iter()
+            .scan(0.0, |sum, &v| {
+                *sum += v;
+                Some(*sum)
+            })
+            .collect();
+        validate_data(&data)?;
+        Ok(Self::from_validated_parts(&self.x_axis, &data))
+    }
+
+    /// Returns a copy whose data is the running integral of the curve from
+    /// the first breakpoint, computed with the trapezoidal rule.
+    ///
+    /// Exact for linear tables, so `result.lookup(x_axis[i])` is the area
+    /// under the curve over `[x_axis[0], x_axis[i]]`. The X axis is
+    /// unchanged; Hermite tangents and the periodic flag are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::InfInData` if the running area overflows.
+    pub fn cumulative_integral(&self) -> Result<Self, LutError> {
+        let mut data = Vec::with_capacity(self.data.len());
+        data.push(0.0);
+        let mut area = 0.0;
+        for (x, y) in self.x_axis.windows(2).zip(self.data.windows(2)) {
+            area += 0.5 * (x[1] - x[0]) * (y[0] + y[1]);
+            data.push(area);
+        }
+        validate_data(&data)?;
+        Ok(Self::from_validated_parts(&self.x_axis, &data))
+    }
+
+    /// Returns a new LUT of the local slopes of this one.
+    ///
+    /// Each output point is the slope of one input segment, placed at the
+    /// segment midpoint. A single-point LUT yields a single point with slope 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::InfInData` if a slope overflows, e.g. for a large
+    /// step over closely spaced breakpoints.
+    pub fn derivative_lut(&self) -> Result<Self, LutError> {
+        if self.x_axis.len() < 2 {
+            return Ok(Self {
+                x_axis: self.x_axis.clone(),
+                data: alloc::vec![0.0; self.x_axis.len()],
+                periodic_x: false,
+                tangents: Vec::new(),
+            });
+        }
+
+        let (x_axis, data): (Vec<f64>, Vec<f64>) = self
+            .x_axis
+            .windows(2)
+            .zip(self.data.windows(2))
+            .map(|(x, y)| ((x[0] + x[1]) * 0.5, (y[1] - y[0]) / (x[1] - x[0])))
+            .unzip();
+
+        validate_data(&data)?;
+
+        // Midpoints of a strictly ascending axis are strictly ascending
+        Ok(Self {
+            x_axis,
+            data,
+            periodic_x: false,
+            tangents: Vec::new(),
+        })
+    }
+
+    /// Fits a least-squares polynomial through the breakpoints.
+    ///
+    /// Returns `degree + 1` coefficients in ascending order, so the fit is
+    /// `c[0] + c[1] * x + c[2] * x^2 + ...`. The degree is clamped to
+    /// `len() - 1`, at which point the polynomial interpolates every
+    /// breakpoint. The normal equations are solved on an X axis scaled to
+    /// `[-1, 1]` to keep them well conditioned for RPM-sized inputs.
+    #[must_use]
+    pub fn fit_polynomial(&self, degree: usize) -> Vec<f64> {
+        let terms = degree.min(self.x_axis.len() - 1) + 1;
+        let scale = self
+            .x_axis
+            .iter()
+            .fold(0.0, |m: f64, &x| m.max(libm::fabs(x)));
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+
+        // Normal equations (A^T A) c = A^T y, augmented with the right-hand side
+        let mut normal = alloc::vec![0.0; terms * (terms + 1)];
+        let mut powers = alloc::vec![0.0; terms];
+        for (&x, &y) in self.x_axis.iter().zip(&self.data) {
+            let t = x / scale;
+            let mut p = 1.0;
+            for power in &mut powers {
+                *power = p;
+                p *= t;
+            }
+            for (r, &pr) in powers.iter().enumerate() {
+                let row = &mut normal[r * (terms + 1)..(r + 1) * (terms + 1)];
+                for (cell, &pc) in row.iter_mut().zip(&powers) {
+                    *cell += pr * pc;
+                }
+                row[terms] += pr * y;
+            }
+        }
+
+        let mut coeffs = solve_augmented(&mut normal, terms);
+        let mut unscale = 1.0;
+        for c in &mut coeffs {
+            *c /= unscale;
+            unscale *= scale;
+        }
+        coeffs
+    }
+
+    /// Returns the change in slope across breakpoint `index`.
+    ///
+    /// This is `slope[index] - slope[index - 1]`, where `slope[i]` is the
+    /// slope of the segment starting at breakpoint `i`: positive where the
+    /// curve bends upwards, negative where it bends downwards. The end
+    /// breakpoints have only one segment and return 0.0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len()`.
+    #[must_use]
+    pub fn curvature_at(&self, index: usize) -> f64 {
+        assert!(index < self.len(), "breakpoint index out of range");
+        if index == 0 || index == self.len() - 1 {
+            return 0.0;
+        }
+        let slope =
+            |i: usize| (self.data[i + 1] - self.data[i]) / (self.x_axis[i + 1] - self.x_axis[i]);
+        slope(index) - slope(index - 1)
+    }
+
+    /// Returns the index of the breakpoint with the sharpest bend.
+    ///
+    /// This is the internal breakpoint maximising `|curvature_at(i)|`, which
+    /// is where extra breakpoints help most when smoothing the curve. Ties
+    /// resolve to the first occurrence. Tables with fewer than three points
+    /// have no internal breakpoint and return 1.
+    #[must_use]
+    pub fn max_curvature_index(&self) -> usize {
+        let mut best = 1;
+        for i in 2..self.len().saturating_sub(1) {
+            if libm::fabs(self.curvature_at(i)) > libm::fabs(self.curvature_at(best)) {
+                best = i;
+            }
+        }
+        best
+    }
+
+    /// Returns the least-squares `(slope, intercept)` of a line through the
+    /// breakpoints.
+    ///
+    /// Uses the closed-form solution from running sums. A single-point
+    /// table yields a zero slope through its value.
+    #[must_use]
+    pub fn linear_regression(&self) -> (f64, f64) {
+        let n = self.x_axis.len() as f64;
+        let (mut sx, mut sy, mut sxx, mut sxy) = (0.0, 0.0, 0.0, 0.0);
+        for (&x, &y) in self.x_axis.iter().zip(&self.data) {
+            sx += x;
+            sy += y;
+            sxx += x * x;
+            sxy += x * y;
+        }
+
+        let sx_squared = sx * sx;
+        let denominator = n * sxx - sx_squared;
+        let slope = if denominator > 0.0 {
+            (n * sxy - sx * sy) / denominator
+        } else {
+            0.0
+        };
+        (slope, (sy - slope * sx) / n)
+    }
+
+    /// Returns the coefficient of determination of
+    /// [`Lut1D::linear_regression`].
+    ///
+    /// 1.0 means the breakpoints lie on a line. Constant data has no
+    /// variance to explain and yields 0.0.
+    #[must_use]
+    pub fn r_squared(&self) -> f64 {
+        let (slope, intercept) = self.linear_regression();
+        let mean = self.data.iter().sum::<f64>() / self.data.len() as f64;
+        let (mut ss_res, mut ss_tot) = (0.0, 0.0);
+        for (&x, &y) in self.x_axis.iter().zip(&self.data) {
+            let residual = y - (slope * x + intercept);
+            ss_res += residual * residual;
+            ss_tot += (y - mean) * (y - mean);
+        }
+
+        if ss_tot > 0.0 {
+            1.0 - ss_res / ss_tot
+        } else {
+            0.0
+        }
+    }
+
+    /// Finds every x within the axis range where `f(x)` equals `target`.
+    ///
+    /// The curve is split into monotone runs of segments and each run that
+    /// brackets `target` is solved by bisection until `|f(x) - target| < eps`.
+    /// Roots are returned in ascending order; a flat run lying on `target`
+    /// contributes only its start point.
+    #[must_use]
+    pub fn find_roots(&self, target: f64, eps: f64) -> Vec<f64> {
+        let mut roots = Vec::new();
+        let n = self.x_axis.len();
+        if n == 1 {
+            if libm::fabs(self.data[0] - target) < eps {
+                roots.push(self.x_axis[0]);
+            }
+            return roots;
+        }
+
+        // End of the last flat run lying on the target, which is not a new root
+        let mut plateau_end = None;
+        for segment in self.monotone_segments() {
+            let (start, end) = (segment.start_index, segment.end_index);
+            if let Some(root) = self.bisect_run(start, end, target, eps) {
+                if roots.last() != Some(&root) && plateau_end != Some(root) {
+                    roots.push(root);
+                }
+                if segment.direction == Monotonicity::Constant {
+                    plateau_end = Some(self.x_axis[end]);
+                }
+            }
+        }
+        roots
+    }
+
+    /// Returns the inverse map, with the data as the X axis and the X axis
+    /// as the data.
+    ///
+    /// Only strictly monotone curves are invertible. A decreasing curve is
+    /// reversed so the new axis ascends. Linear interpolation of the result
+    /// is the exact inverse of linear interpolation of `self` over its data
+    /// range. Hermite tangents and the periodic flag are not carried over.
+    ///
+    /// Returns `None` if any segment is flat or changes direction, or if
+    /// the data steps are too small to form a valid axis (see
+    /// [`Lut1D::new`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// let airflow = Lut1D::new(vec![0.0, 50.0, 100.0], vec![0.0, 80.0, 100.0]).unwrap();
+    /// let throttle = airflow.inverse_piecewise_affine().unwrap();
+    /// assert!((throttle.lookup(90.0) - 75.0).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn inverse_piecewise_affine(&self) -> Option<Self> {
+        let decreasing = self.data.len() > 1 && self.data[1] < self.data[0];
+        let (x_axis, data) = if decreasing {
+            (
+                self.data.iter().rev().copied().collect(),
+                self.x_axis.iter().rev().copied().collect(),
+            )
+        } else {
+            (self.data.clone(), self.x_axis.clone())
+        };
+        // Rejects anything that is not strictly monotone
+        Self::new(x_axis, data).ok()
+    }
+
+    /// Splits the curve into maximal runs of rising, falling or flat segments.
+    ///
+    /// Consecutive runs share their boundary breakpoint. A single-point LUT
+    /// yields no segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::{Lut1D, Monotonicity};
+    ///
+    /// let lut = Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![0.0, 5.0, 5.0, 2.0]).unwrap();
+    /// let dirs: Vec<_> = lut.monotone_segments().map(|s| s.direction).collect();
+    /// assert_eq!(
+    ///     dirs,
+    ///     [Monotonicity::Increasing, Monotonicity::Constant, Monotonicity::Decreasing]
+    /// );
+    /// ```
+    pub fn monotone_segments(&self) -> impl Iterator<Item = MonotoneSegment> + '_ {
+        let last = self.data.len().saturating_sub(1);
+        let direction = |i: usize| {
+            let dy = self.data[i + 1] - self.data[i];
+            if dy > 0.0 {
+                Monotonicity::Increasing
+            } else if dy < 0.0 {
+                Monotonicity::Decreasing
+            } else {
+                Monotonicity::Constant
+            }
+        };
+
+        let mut start = 0;
+        core::iter::from_fn(move || {
+            if start >= last {
+                return None;
+            }
+            let dir = direction(start);
+            let mut end = start + 1;
+            while end < last && direction(end) == dir {
+                end += 1;
+            }
+            let segment = MonotoneSegment {
+                start_index: start,
+                end_index: end,
+                direction: dir,
+            };
+            start = end;
+            Some(segment)
+        })
+    }
+
+    /// Bisects the monotone run between breakpoints `start` and `end`.
+    fn bisect_run(&self, start: usize, end: usize, target: f64, eps: f64) -> Option<f64> {
+        const MAX_ITERATIONS: usize = 200;
+
+        let (mut lo, mut hi) = (self.x_axis[start], self.x_axis[end]);
+        let f_lo = self.data[start] - target;
+        let f_hi = self.data[end] - target;
+
+        if libm::fabs(f_lo) < eps {
+            return Some(lo);
+        }
+        if libm::fabs(f_hi) < eps {
+            return Some(hi);
+        }
+        if (f_lo > 0.0) == (f_hi > 0.0) {
+            return None;
+        }
+
+        let rising = f_hi > f_lo;
+        let mut mid = (lo + hi) * 0.5;
+        for _ in 0..MAX_ITERATIONS {
+            mid = (lo + hi) * 0.5;
+            let f_mid = self.lookup(mid) - target;
+            if libm::fabs(f_mid) < eps {
+                break;
+            }
+            if (f_mid < 0.0) == rising {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(mid)
+    }
+
+    /// Builds a LUT from parts taken from an already validated table, so
+    /// the axis is strictly ascending and the data finite.
+    #[must_use]
+    pub(crate) fn from_validated_parts(x_axis: &[f64], data: &[f64]) -> Self {
+        debug_assert!(super::validate_axis_const(x_axis) && x_axis.len() == data.len());
+        debug_assert!(super::validate_data_const(data));
+        Self {
+            x_axis: x_axis.to_vec(),
+            data: data.to_vec(),
+            periodic_x: false,
+            tangents: Vec::new(),
+        }
+    }
+
+    /// Returns the largest data value, ignoring NaN.
+    #[must_use]
+    pub fn data_max(&self) -> f64 {
+        self.data
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, libm::fmax)
+    }
+
+    /// Returns a copy with the data divided by [`Lut1D::data_max`], together
+    /// with that divisor.
+    ///
+    /// For tables with a positive maximum the peak becomes exactly 1.0;
+    /// [`Lut1D::scale_data`] with the returned factor restores the original.
+    /// If the maximum is zero, or so small that dividing by it overflows,
+    /// the table is returned unchanged with a factor of 1.0.
+    #[must_use]
+    pub fn normalize_data(&self) -> (Self, f64) {
+        let max = self.data_max();
+        if max == 0.0 {
+            return (self.clone(), 1.0);
+        }
+        self.map_linear(|v| v / max)
+            .map_or_else(|_| (self.clone(), 1.0), |normalized| (normalized, max))
+    }
+
+    /// Returns a copy with every data value multiplied by `factor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if a scaled
+    /// value or tangent is not finite, e.g. for a NaN `factor` or on
+    /// overflow.
+    pub fn scale_data(&self, factor: f64) -> Result<Self, LutError> {
+        self.map_linear(|v| v * factor)
+    }
+
+    /// Returns a copy with every data value mapped to `value * gain + offset`.
+    ///
+    /// The X axis and periodic flag are kept; Hermite tangents are scaled by
+    /// `gain`. See [`calibrate_two_point`] for deriving the coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if a mapped
+    /// value or tangent is not finite, e.g. for a non-finite `gain`.
+    pub fn apply_gain_offset(&self, gain: f64, offset: f64) -> Result<Self, LutError> {
+        let data: Vec<f64> = self.data.iter().map(|&v| v * gain + offset).collect();
+        let tangents: Vec<f64> = self.tangents.iter().map(|&v| v * gain).collect();
+        validate_data(&data)?;
+        validate_data(&tangents)?;
+        Ok(Self {
+            data,
+            tangents,
+            ..self.clone()
+        })
+    }
+
+    /// Returns a copy with `f` applied to every data value, e.g. to convert
+    /// a torque curve from lb-ft to N·m.
+    ///
+    /// The X axis and periodic flag are kept. Hermite tangents are dropped,
+    /// since the slopes of a nonlinear `f` cannot be derived from them; use
+    /// [`Lut1D::scale_data`] or [`Lut1D::apply_gain_offset`] to keep them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if `f`
+    /// produces a value that [`Lut1D::new`] would reject.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut1D;
+    ///
+    /// let torque_lbft = Lut1D::new(vec![1000.0, 3000.0], vec![100.0, 150.0]).unwrap();
+    /// let torque_nm = torque_lbft.map_data(|v| v * 1.35582).unwrap();
+    /// assert!((torque_nm.lookup(1000.0) - 135.582).abs() < 1e-9);
+    /// ```
+    pub fn map_data<F: Fn(f64) -> f64>(&self, f: F) -> Result<Self, LutError> {
+        let data: Vec<f64> = self.data.iter().map(|&v| f(v)).collect();
+        validate_data(&data)?;
+        Ok(Self {
+            data,
+            tangents: Vec::new(),
+            ..self.clone()
+        })
+    }
+
+    /// Applies a linear scaling of the data values.
+    fn map_linear(&self, scale: impl Fn(f64) -> f64) -> Result<Self, LutError> {
+        let data: Vec<f64> = self.data.iter().map(|&v| scale(v)).collect();
+        // Tangents are slopes of the data, so they scale the same way
+        let tangents: Vec<f64> = self.tangents.iter().map(|&v| scale(v)).collect();
+        validate_data(&data)?;
+        validate_data(&tangents)?;
+        Ok(Self {
+            data,
+            tangents,
+            ..self.clone()
+        })
+    }
+
+    /// Returns the smallest gap between adjacent X breakpoints.
+    ///
+    /// Returns `f64::INFINITY` for a single-point table.
+    #[must_use]
+    pub fn min_x_stride(&self) -> f64 {
+        min_axis_stride(&self.x_axis)
+    }
+
+    /// Returns the X axis values.
+    #[must_use]
+    pub fn x_axis(&self) -> &[f64] {
+        &self.x_axis
+    }
+
+    /// Returns the data values.
+    #[must_use]
+    pub fn data(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns the number of data points.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the LUT has no data points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// Returns the `(gain, offset)` of the line through two reference points.
+///
+/// Mapping `x1` to `y1` and `x2` to `y2` with `y = x * gain + offset`, as
+/// used by [`Lut1D::apply_gain_offset`]. The result is not finite if
+/// `x1 == x2`.
+#[must_use]
+pub fn calibrate_two_point(x1: f64, y1: f64, x2: f64, y2: f64) -> (f64, f64) {
+    let gain = (y2 - y1) / (x2 - x1);
+    (gain, y1 - gain * x1)
+}
+
+/// Solves an `n x (n + 1)` augmented system by Gaussian elimination with
+/// partial pivoting. Singular pivots yield a zero coefficient.
+fn solve_augmented(m: &mut [f64], n: usize) -> Vec<f64> {
+    let w = n + 1;
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| libm::fabs(m[a * w + col]).total_cmp(&libm::fabs(m[b * w + col])))
+            .unwrap_or(col);
+        if pivot != col {
+            for k in 0..w {
+                m.swap(col * w + k, pivot * w + k);
+            }
+        }
+        let diag = m[col * w + col];
+        if diag == 0.0 {
+            continue;
+        }
+        for row in col + 1..n {
+            let factor = m[row * w + col] / diag;
+            for k in col..w {
+                m[row * w + k] -= factor * m[col * w + k];
+            }
+        }
+    }
+
+    let mut x = alloc::vec![0.0; n];
+    for row in (0..n).rev() {
+        let diag = m[row * w + row];
+        if diag == 0.0 {
+            continue;
+        }
+        let tail: f64 = (row + 1..n).map(|k| m[row * w + k] * x[k]).sum();
+        x[row] = (m[row * w + n] - tail) / diag;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_exact_match() {
+        let lut =
+            Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![10.0, 20.0, 30.0, 40.0]).expect("valid LUT");
+
+        assert!((lut.lookup(0.0) - 10.0).abs() < 1e-10);
+        assert!((lut.lookup(1.0) - 20.0).abs() < 1e-10);
+        assert!((lut.lookup(2.0) - 30.0).abs() < 1e-10);
+        assert!((lut.lookup(3.0) - 40.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_interpolation_midpoint() {
+        let lut = Lut1D::new(vec![0.0, 2.0, 4.0], vec![0.0, 100.0, 200.0]).expect("valid LUT");
+
+        assert!((lut.lookup(1.0) - 50.0).abs() < 1e-10);
+        assert!((lut.lookup(3.0) - 150.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_interpolation_quarter() {
+        let lut = Lut1D::new(vec![0.0, 4.0], vec![0.0, 100.0]).expect("valid LUT");
+
+        assert!((lut.lookup(1.0) - 25.0).abs() < 1e-10);
+        assert!((lut.lookup(2.0) - 50.0).abs() < 1e-10);
+        assert!((lut.lookup(3.0) - 75.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_out_of_bounds_low() {
+        let lut = Lut1D::new(vec![10.0, 20.0, 30.0], vec![100.0, 200.0, 300.0]).expect("valid LUT");
+
+        assert!((lut.lookup(0.0) - 100.0).abs() < 1e-10);
+        assert!((lut.lookup(-100.0) - 100.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_out_of_bounds_high() {
+        let lut = Lut1D::new(vec![10.0, 20.0, 30.0], vec![100.0, 200.0, 300.0]).expect("valid LUT");
+
+        assert!((lut.lookup(40.0) - 300.0).abs() < 1e-10);
+        assert!((lut.lookup(1000.0) - 300.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_error_empty_axis() {
+        let result = Lut1D::new(vec![], vec![]);
+        assert!(matches!(result, Err(LutError::EmptyXAxis)));
+    }
+
+    #[test]
+    fn test_error_unsorted_axis() {
+        let result = Lut1D::new(vec![0.0, 2.0, 1.0], vec![0.0, 1.0, 2.0]);
+        assert!(matches!(
+            result,
+            Err(LutError::UnsortedAxis {
+                axis: "X",
+                index: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_error_duplicate_values() {
+        let result = Lut1D::new(vec![0.0, 1.0, 1.0, 2.0], vec![0.0, 1.0, 2.0, 3.0]);
+        assert!(matches!(
+            result,
+            Err(LutError::UnsortedAxis {
+                axis: "X",
+                index: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_error_dimension_mismatch() {
+        let result = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0]);
+        assert!(matches!(
+            result,
+            Err(LutError::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_extend_axis() {
+        let mut lut = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 10.0, 20.0]).expect("valid LUT");
+
+        lut.extend_axis(&[3.0, 5.0], &[30.0, 10.0])
+            .expect("valid extension");
+        assert_eq!(lut.len(), 5);
+        assert!((lut.lookup(4.0) - 20.0).abs() < 1e-10);
+        assert!((lut.lookup(2.5) - 25.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_extend_axis_errors_leave_lut_unchanged() {
+        let mut lut = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 10.0, 20.0]).expect("valid LUT");
+
+        assert!(matches!(
+            lut.extend_axis(&[2.0, 3.0], &[0.0, 0.0]),
+            Err(LutError::UnsortedAxis {
+                axis: "X",
+                index: 3
+            })
+        ));
+        assert!(matches!(
+            lut.extend_axis(&[3.0, 4.0, 3.5], &[0.0, 0.0, 0.0]),
+            Err(LutError::UnsortedAxis {
+                axis: "X",
+                index: 5
+            })
+        ));
+        assert!(matches!(
+            lut.extend_axis(&[3.0, 4.0], &[0.0]),
+            Err(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert_eq!(
+            lut.extend_axis(&[3.0, 4.0], &[0.0, f64::NAN]),
+            Err(LutError::NaNInData { index: 4 })
+        );
+        assert_eq!(
+            lut.extend_axis(&[3.0], &[f64::INFINITY]),
+            Err(LutError::InfInData { index: 3 })
+        );
+        assert_eq!(lut.len(), 3);
+        assert_eq!(lut.x_axis(), &[0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_derivative_of_line_is_constant() {
+        let lut =
+            Lut1D::new(vec![0.0, 1.0, 3.0, 7.0], vec![0.0, 2.0, 6.0, 14.0]).expect("valid LUT");
+        let slope = lut.derivative_lut().expect("finite slopes");
+
+        assert_eq!(slope.x_axis(), &[0.5, 2.0, 5.0]);
+        for x in [-10.0, 0.0, 1.3, 4.0, 100.0] {
+            assert!((slope.lookup(x) - 2.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_derivative_of_quadratic() {
+        let x_axis: Vec<f64> = (0..10).map(f64::from).collect();
+        let data: Vec<f64> = x_axis.iter().map(|x| x * x).collect();
+        let slope = Lut1D::new(x_axis, data)
+            .expect("valid LUT")
+            .derivative_lut()
+            .expect("finite slopes");
+
+        assert_eq!(slope.len(), 9);
+        for x in [0.5, 2.5, 4.0, 6.25, 8.5] {
+            // Exact at midpoints, within one breakpoint spacing elsewhere
+            assert!((slope.lookup(x) - 2.0 * x).abs() < 1.0);
+        }
+        assert!((slope.lookup(4.5) - 9.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_derivative_single_point() {
+        let lut = Lut1D::new(vec![3.0], vec![42.0]).expect("valid LUT");
+        assert!((lut.lookup(0.0) - 42.0).abs() < 1e-10);
+
+        let slope = lut.derivative_lut().expect("finite slopes");
+        assert_eq!(slope.len(), 1);
+        assert!(slope.lookup(3.0).abs() < 1e-10);
+        assert!(slope.lookup(10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_derivative_overflow() {
+        let lut = Lut1D::new(vec![0.0, 1e-300], vec![0.0, 1e300]).expect("valid LUT");
+        assert_eq!(
+            lut.derivative_lut().err(),
+            Some(LutError::InfInData { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_find_roots_single_peak() {
+        // Rises to 100 at x = 2, then falls
+        let lut = Lut1D::new(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0],
+            vec![0.0, 75.0, 100.0, 75.0, 0.0],
+        )
+        .expect("valid LUT");
+
+        let roots = lut.find_roots(50.0, 1e-9);
+        assert_eq!(roots.len(), 2);
+        for root in &roots {
+            assert!((lut.lookup(*root) - 50.0).abs() < 1e-9);
+        }
+        assert!(roots[0] < 2.0 && roots[1] > 2.0);
+
+        assert!(lut.find_roots(150.0, 1e-9).is_empty());
+
+        // Exactly at the maximum: a single root at the peak
+        assert_eq!(lut.find_roots(100.0, 1e-9), vec![2.0]);
+    }
+
+    #[test]
+    fn test_find_roots_monotone() {
+        let lut =
+            Lut1D::new(vec![0.0, 1.0, 2.0, 5.0], vec![0.0, 1.0, 4.0, 10.0]).expect("valid LUT");
+
+        let roots = lut.find_roots(7.0, 1e-10);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 3.5).abs() < 1e-9);
+
+        // Exactly at an interior breakpoint
+        let roots = lut.find_roots(4.0, 1e-10);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 2.0).abs() < 1e-9);
+
+        // Exactly at the end of the axis
+        assert_eq!(lut.find_roots(10.0, 1e-10), vec![5.0]);
+    }
+
+    #[test]
+    fn test_find_roots_plateau() {
+        let lut =
+            Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![0.0, 5.0, 5.0, 10.0]).expect("valid LUT");
+        assert_eq!(lut.find_roots(5.0, 1e-10), vec![1.0]);
+    }
+
+    #[test]
+    fn test_periodic_angular_map() {
+        let lut = Lut1D::new(
+            vec![0.0, 90.0, 180.0, 270.0, 360.0],
+            vec![0.3, 1.2, 0.5, 1.2, 0.3],
+        )
+        .expect("valid LUT")
+        .with_periodic_x()
+        .expect("periodic data");
+
+        assert!(lut.is_periodic_x());
+        assert!((lut.lookup(0.0) - lut.lookup(360.0)).abs() < 1e-12);
+        assert!((lut.lookup(370.0) - lut.lookup(10.0)).abs() < 1e-12);
+        assert!((lut.lookup(-10.0) - lut.lookup(350.0)).abs() < 1e-12);
+        assert!((lut.lookup(720.0 + 45.0) - 0.75).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_periodic_requires_matching_ends() {
+        let result = Lut1D::new(vec![0.0, 180.0, 360.0], vec![0.0, 1.0, 0.5])
+            .expect("valid LUT")
+            .with_periodic_x();
+        assert!(matches!(
+            result,
+            Err(LutError::NonPeriodicData { axis: "X" })
+        ));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let lut = Lut1D::new(vec![1.0, 2.0, 3.0], vec![10.0, 20.0, 30.0]).expect("valid LUT");
+
+        assert_eq!(lut.x_axis(), &[1.0, 2.0, 3.0]);
+        assert_eq!(lut.data(), &[10.0, 20.0, 30.0]);
+        assert_eq!(lut.len(), 3);
+        assert!(!lut.is_empty());
+    }
+
+    #[test]
+    fn test_many_lookups() {
+        let n = 100;
+        let x_axis: Vec<f64> = (0..n).map(f64::from).collect();
+        let data: Vec<f64> = (0..n).map(|i| f64::from(i * i)).collect();
+        let lut = Lut1D::new(x_axis, data).expect("valid LUT");
+
+        for i in 0..10000 {
+            let x = f64::from(i % 99) + 0.5;
+            let _ = lut.lookup(x);
+        }
+    }
+
+    #[test]
+    fn test_monotone_segments_peak() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 2.0, 3.0, 4.0], vec![0.0, 1.0, 2.0, 1.0, 0.0])
+            .expect("valid LUT");
+        let segments: Vec<_> = lut.monotone_segments().collect();
+
+        // Two rising intervals merge into one run, as do the two falling ones
+        assert_eq!(
+            segments,
+            vec![
+                MonotoneSegment {
+                    start_index: 0,
+                    end_index: 2,
+                    direction: Monotonicity::Increasing,
+                },
+                MonotoneSegment {
+                    start_index: 2,
+                    end_index: 4,
+                    direction: Monotonicity::Decreasing,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monotone_segments_flat_and_strict() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 2.0, 3.0, 4.0], vec![0.0, 3.0, 3.0, 3.0, 1.0])
+            .expect("valid LUT");
+        let dirs: Vec<_> = lut.monotone_segments().map(|s| s.direction).collect();
+        assert_eq!(
+            dirs,
+            vec![
+                Monotonicity::Increasing,
+                Monotonicity::Constant,
+                Monotonicity::Decreasing
+            ]
+        );
+        let flat = lut.monotone_segments().nth(1).expect("flat segment");
+        assert_eq!((flat.start_index, flat.end_index), (1, 3));
+
+        let strict =
+            Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![-1.0, 0.0, 4.0, 9.0]).expect("valid LUT");
+        let segments: Vec<_> = strict.monotone_segments().collect();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].end_index, 3);
+
+        let single = Lut1D::new(vec![1.0], vec![2.0]).expect("valid LUT");
+        assert_eq!(single.monotone_segments().count(), 0);
+    }
+
+    #[test]
+    fn test_static_lut_matches_runtime() {
+        let lut = crate::lut1d_static!([0.0, 1.0, 3.0], [5.0, 7.0, 1.0]);
+        let runtime = Lut1D::new(vec![0.0, 1.0, 3.0], vec![5.0, 7.0, 1.0]).expect("valid LUT");
+
+        assert_eq!(lut.x_axis(), runtime.x_axis());
+        assert_eq!(lut.data(), runtime.data());
+        assert!((lut.lookup(2.0) - runtime.lookup(2.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_validate_axis_const() {
+        assert!(crate::lut::validate_axis_const(&[1.0]));
+        assert!(!crate::lut::validate_axis_const(&[]));
+        assert!(!crate::lut::validate_axis_const(&[0.0, 0.0]));
+        assert!(!crate::lut::validate_axis_const(&[0.0, 2.0, 1.0]));
+    }
+
+    #[test]
+    fn test_hermite_zero_tangent_is_flat_at_entry() {
+        let lut = Lut1D::with_hermite_tangents(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, -2.0],
+        )
+        .expect("valid LUT");
+
+        let eps = 1e-6;
+        let slope = (lut.lookup(eps) - lut.lookup(0.0)) / eps;
+        assert!(slope.abs() < 1e-5, "slope {slope}");
+        // Zero end slopes give the smoothstep profile between 0 and 1
+        assert!((lut.lookup(0.5) - 0.5).abs() < 1e-12);
+        assert!((lut.lookup(0.25) - 0.156_25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hermite_reconstructs_cubic() {
+        let f = |x: f64| x * x * x - 2.0 * x + 1.0;
+        let df = |x: f64| 3.0 * x * x - 2.0;
+        let xs = vec![-2.0, -0.5, 1.0, 3.0];
+        let lut = Lut1D::with_hermite_tangents(
+            xs.clone(),
+            xs.iter().map(|&x| f(x)).collect(),
+            xs.iter().map(|&x| df(x)).collect(),
+        )
+        .expect("valid LUT");
+
+        for i in 0..=100 {
+            let x = -2.0 + 5.0 * f64::from(i) / 100.0;
+            assert!((lut.lookup(x) - f(x)).abs() < 1e-10, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_hermite_tangent_length_mismatch() {
+        let result =
+            Lut1D::with_hermite_tangents(vec![0.0, 1.0], vec![0.0, 1.0], vec![1.0, 1.0, 1.0]);
+        assert_eq!(
+            result.err(),
+            Some(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            Lut1D::with_hermite_tangents(vec![0.0, 1.0], vec![0.0, 1.0], vec![1.0, f64::NAN]).err(),
+            Some(LutError::NaNInData { index: 1 })
+        );
+        let linear = Lut1D::new(vec![0.0, 1.0], vec![0.0, 1.0]).expect("valid LUT");
+        assert!(linear.tangents().is_none());
+    }
+
+    #[test]
+    fn test_min_x_stride() {
+        let lut = Lut1D::new(vec![0.0, 0.5, 2.0, 2.1], vec![0.0; 4]).expect("valid LUT");
+        assert!((lut.min_x_stride() - 0.1).abs() < 1e-12);
+
+        let result = Lut1D::new(vec![1.0, 1.0 + f64::EPSILON / 4.0], vec![0.0, 1.0]);
+        assert!(result.is_err());
+        let mut lut = Lut1D::new(vec![1e-300], vec![0.0]).expect("valid LUT");
+        assert!(lut.extend_axis(&[1e-300 + 1e-316], &[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_map_data() {
+        const LBFT_TO_NM: f64 = 1.35582;
+        let rpm = vec![1000.0, 3000.0, 5000.0, 7000.0];
+        let torque_lbft = vec![120.0, 185.0, 210.0, 175.0];
+        let lut = Lut1D::new(rpm.clone(), torque_lbft.clone()).expect("valid LUT");
+
+        let converted = lut.map_data(|v| v * LBFT_TO_NM).expect("finite");
+        assert_eq!(converted.x_axis(), rpm.as_slice());
+        for (&nm, &lbft) in converted.data().iter().zip(&torque_lbft) {
+            assert!((nm - lbft * LBFT_TO_NM).abs() < 1e-8);
+        }
+        assert!((converted.lookup(2000.0) - 152.5 * LBFT_TO_NM).abs() < 1e-8);
+
+        let identity = lut.map_data(|v| v).expect("finite");
+        assert_eq!(identity.x_axis(), lut.x_axis());
+        assert_eq!(identity.data(), lut.data());
+
+        let single = Lut1D::new(vec![1.0], vec![4.0]).expect("valid LUT");
+        assert_eq!(single.map_data(f64::sqrt).expect("finite").data(), &[2.0]);
+
+        assert_eq!(
+            lut.map_data(|v| v / 0.0).err(),
+            Some(LutError::InfInData { index: 0 })
+        );
+        assert_eq!(
+            lut.map_data(|v| (v - 200.0).sqrt()).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+
+        // Tangents don't survive a general mapping
+        let hermite = Lut1D::with_hermite_tangents(vec![0.0, 1.0], vec![0.0, 1.0], vec![1.0, 1.0])
+            .expect("valid LUT");
+        let squared = hermite.map_data(|v| v * v).expect("finite");
+        assert!(squared.tangents().is_none());
+        assert!(hermite
+            .scale_data(2.0)
+            .expect("finite")
+            .tangents()
+            .is_some());
+    }
+
+    #[test]
+    fn test_normalize_and_scale_data() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 50.0, 100.0]).expect("valid LUT");
+        let (normalized, factor) = lut.normalize_data();
+
+        assert!((factor - 100.0).abs() < 1e-12);
+        assert_eq!(normalized.data(), &[0.0, 0.5, 1.0]);
+        assert_eq!(normalized.x_axis(), lut.x_axis());
+
+        let restored = normalized.scale_data(factor).expect("finite");
+        assert_eq!(restored.data(), lut.data());
+
+        assert_eq!(
+            lut.scale_data(f64::NAN).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+        assert_eq!(
+            lut.scale_data(f64::MAX).err(),
+            Some(LutError::InfInData { index: 1 })
+        );
+
+        let zero = Lut1D::new(vec![0.0, 1.0], vec![0.0, 0.0]).expect("valid LUT");
+        let (same, factor) = zero.normalize_data();
+        assert!((factor - 1.0).abs() < 1e-12);
+        assert_eq!(same.data(), zero.data());
+
+        // Dividing by the tiny maximum would overflow the negative value
+        let lopsided = Lut1D::new(vec![0.0, 1.0], vec![-1e300, 1e-300]).expect("valid LUT");
+        let (same, factor) = lopsided.normalize_data();
+        assert!((factor - 1.0).abs() < 1e-12);
+        assert_eq!(same.data(), lopsided.data());
+
+        let hermite = Lut1D::with_hermite_tangents(vec![0.0, 1.0], vec![0.0, 4.0], vec![2.0, 8.0])
+            .expect("valid LUT");
+        let (normalized, _) = hermite.normalize_data();
+        assert_eq!(normalized.tangents(), Some(&[0.5, 2.0][..]));
+    }
+
+    #[test]
+    fn test_smooth_constant_and_noise() {
+        let x: Vec<f64> = (0..9).map(f64::from).collect();
+        let flat = Lut1D::new(x.clone(), vec![3.0; 9]).expect("valid LUT");
+        for v in flat.smooth(5).expect("odd window").data() {
+            assert!((v - 3.0).abs() < 1e-12);
+        }
+
+        let noisy: Vec<f64> = (0..9).map(|i| if i % 2 == 0 { 3.1 } else { 2.9 }).collect();
+        let smoothed = Lut1D::new(x, noisy)
+            .expect("valid LUT")
+            .smooth(3)
+            .expect("odd window");
+        for v in &smoothed.data()[1..8] {
+            assert!((v - 3.0).abs() < 0.04, "value {v}");
+        }
+    }
+
+    #[test]
+    fn test_smooth_step() {
+        let x: Vec<f64> = (0..10).map(f64::from).collect();
+        let step = vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+        let lut = Lut1D::new(x, step).expect("valid LUT");
+        let smoothed = lut.smooth(5).expect("odd window");
+
+        let expected = [0.0, 0.0, 0.0, 0.2, 0.4, 0.6, 0.8, 1.0, 1.0, 1.0];
+        for (v, e) in smoothed.data().iter().zip(expected) {
+            assert!((v - e).abs() < 1e-12, "{v} != {e}");
+        }
+        assert_eq!(smoothed.x_axis(), lut.x_axis());
+
+        assert_eq!(lut.smooth(1).expect("odd window").data(), lut.data());
+        assert!(lut.smooth(4).is_err());
+        assert!(lut.smooth(0).is_err());
+
+        let huge = Lut1D::new(vec![0.0, 1.0], vec![f64::MAX, f64::MAX]).expect("valid LUT");
+        assert_eq!(huge.smooth(3).err(), Some(LutError::InfInData { index: 0 }));
+    }
+
+    #[test]
+    fn test_merged_axis() {
+        let a = Lut1D::new(vec![0.0, 2.0, 4.0], vec![0.0; 3]).expect("valid LUT");
+        let b = Lut1D::new(vec![1.0, 2.0, 5.0], vec![0.0; 3]).expect("valid LUT");
+        assert_eq!(a.merged_axis(&b), vec![0.0, 1.0, 2.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_is_dominated_by() {
+        let rpm = vec![1000.0, 4000.0, 7000.0];
+        let flat = |v: f64| Lut1D::new(rpm.clone(), vec![v; 3]).expect("valid LUT");
+        let base = flat(100.0);
+
+        assert!(base.is_dominated_by(&flat(200.0), 0.0));
+        assert!(!base.is_dominated_by(&flat(50.0), 0.0));
+        assert!(base.is_dominated_by(&flat(99.5), 1.0));
+
+        // Crosses the flat curve between its own breakpoints
+        let crossing = Lut1D::new(vec![2000.0, 6000.0], vec![150.0, 50.0]).expect("valid LUT");
+        assert!(!base.is_dominated_by(&crossing, 0.0));
+        assert!(!crossing.is_dominated_by(&base, 0.0));
+    }
+
+    #[test]
+    fn test_peak_and_valley() {
+        let lut = Lut1D::new(
+            vec![1000.0, 2000.0, 3000.0, 4000.0, 5000.0],
+            vec![120.0, 180.0, 210.0, 190.0, 110.0],
+        )
+        .expect("valid LUT");
+
+        assert_eq!(lut.peak(), (3000.0, 210.0));
+        assert_eq!(lut.valley(), (5000.0, 110.0));
+        assert!((lut.peak_x() - 3000.0).abs() < 1e-12);
+        assert!((lut.valley_x() - 5000.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_peak_edge_cases() {
+        let flat = Lut1D::new(vec![0.0, 1.0, 2.0], vec![5.0; 3]).expect("valid LUT");
+        assert_eq!(flat.peak(), (0.0, 5.0));
+        assert_eq!(flat.valley(), (0.0, 5.0));
+
+        let single = Lut1D::new(vec![3.0], vec![-1.0]).expect("valid LUT");
+        assert_eq!(single.peak(), (3.0, -1.0));
+        assert_eq!(single.valley(), (3.0, -1.0));
+    }
+
+    #[test]
+    fn test_to_lut2d_broadcast() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 3.0], vec![10.0, 20.0, 5.0]).expect("valid LUT");
+        let map = lut
+            .to_lut2d_broadcast(vec![-40.0, 120.0])
+            .expect("valid axis");
+
+        assert_eq!(map.x_axis(), lut.x_axis());
+        assert_eq!(map.y_axis(), &[-40.0, 120.0]);
+        assert_eq!(map.data().len(), 6);
+        for x in [-1.0, 0.0, 0.5, 2.0, 3.0, 4.0] {
+            for y in [-50.0, -40.0, 0.0, 120.0] {
+                assert!((map.lookup(x, y) - lut.lookup(x)).abs() < 1e-12);
+            }
+        }
+
+        // A single-point Y axis makes the map constant along Y
+        let flat = lut.to_lut2d_broadcast(vec![25.0]).expect("valid axis");
+        for y in [-50.0, 25.0, 120.0] {
+            assert!((flat.lookup(2.0, y) - lut.lookup(2.0)).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            lut.to_lut2d_broadcast(vec![]).err(),
+            Some(LutError::EmptyYAxis)
+        );
+        assert_eq!(
+            lut.to_lut2d_broadcast(vec![1.0, f64::NAN]).err(),
+            Some(LutError::UnsortedAxis {
+                axis: "Y",
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_convolve_identity_and_average() {
+        let lut = Lut1D::new(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0],
+            vec![3.0, -1.0, 4.0, 1.0, 5.0],
+        )
+        .expect("valid LUT");
+
+        assert_eq!(lut.convolve(&[1.0]).expect("odd kernel").data(), lut.data());
+
+        let third = 1.0 / 3.0;
+        let averaged = lut.convolve(&[third; 3]).expect("odd kernel");
+        let expected = [2.0 / 3.0, 2.0, 4.0 / 3.0, 10.0 / 3.0, 2.0];
+        for (v, e) in averaged.data().iter().zip(expected) {
+            assert!((v - e).abs() < 1e-12, "{v} != {e}");
+        }
+
+        // Asymmetric kernel: a one-sample delay
+        let delayed = lut.convolve(&[0.0, 0.0, 1.0]).expect("odd kernel");
+        assert_eq!(delayed.data(), &[0.0, 3.0, -1.0, 4.0, 1.0]);
+
+        assert!(lut.convolve(&[]).is_err());
+        assert!(lut.convolve(&[0.5, 0.5]).is_err());
+        assert_eq!(
+            lut.convolve(&[f64::NAN]).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_convolve_gaussian_step() {
+        let sigma = 3.0;
+        let kernel: Vec<f64> = (-12..=12)
+            .map(|k| libm::exp(-0.5 * libm::pow(f64::from(k) / sigma, 2.0)))
+            .collect();
+        let total: f64 = kernel.iter().sum();
+        let kernel: Vec<f64> = kernel.iter().map(|w| w / total).collect();
+
+        let x: Vec<f64> = (0..100).map(f64::from).collect();
+        let step = (0..100).map(|i| if i < 50 { 0.0 } else { 1.0 }).collect();
+        let lut = Lut1D::new(x, step).expect("valid LUT");
+        let blurred = lut.convolve(&kernel).expect("odd kernel");
+
+        for i in 30..70 {
+            let z = (f64::from(i) - 49.5) / (sigma * core::f64::consts::SQRT_2);
+            let expected = 0.5 * (1.0 + libm::erf(z));
+            let v = blurred.data()[i as usize];
+            assert!((v - expected).abs() < 0.01, "i = {i}: {v} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn test_area_between() {
+        let flat = |v: f64| Lut1D::new(vec![0.0, 5.0, 20.0], vec![v; 3]).expect("valid LUT");
+        let a = flat(100.0);
+        let b = flat(200.0);
+
+        assert!(a.area_between(&a, 0.0, 20.0).abs() < 1e-12);
+        assert!((a.area_between(&b, 2.0, 12.0) - 1000.0).abs() < 1e-9);
+        assert!((b.area_between(&a, 2.0, 12.0) - 1000.0).abs() < 1e-9);
+        assert!(a.area_between(&b, 12.0, 2.0).abs() < 1e-12);
+
+        // Triangle of height 10 over [0, 4] on top of the flat line
+        let peak = Lut1D::new(vec![0.0, 2.0, 4.0], vec![100.0, 110.0, 100.0]).expect("valid LUT");
+        assert!((peak.area_between(&a, 0.0, 4.0) - 20.0).abs() < 1e-9);
+
+        // Crossing lines: two triangles of area 0.5 each
+        let rising = Lut1D::new(vec![0.0, 2.0], vec![-1.0, 1.0]).expect("valid LUT");
+        let zero = Lut1D::new(vec![0.0, 2.0], vec![0.0, 0.0]).expect("valid LUT");
+        assert!((rising.area_between(&zero, 0.0, 2.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fit_polynomial_line() {
+        let lut =
+            Lut1D::new(vec![0.0, 1.0, 3.0, 4.0], vec![1.0, 3.0, 7.0, 9.0]).expect("valid LUT");
+
+        let c = lut.fit_polynomial(1);
+        assert_eq!(c.len(), 2);
+        assert!((c[0] - 1.0).abs() < 1e-12);
+        assert!((c[1] - 2.0).abs() < 1e-12);
+
+        // Degree is clamped to len - 1
+        assert_eq!(lut.fit_polynomial(10).len(), 4);
+        let single = Lut1D::new(vec![2.0], vec![5.0]).expect("valid LUT");
+        assert_eq!(single.fit_polynomial(3), vec![5.0]);
+    }
+
+    #[test]
+    fn test_fit_polynomial_quadratic() {
+        let quad = |x: f64| 0.5 - 0.02 * x + 3.0e-5 * x * x;
+        let x: Vec<f64> = (0..=12).map(|i| f64::from(i) * 500.0).collect();
+        let y = x.iter().map(|&x| quad(x)).collect();
+        let lut = Lut1D::new(x, y).expect("valid LUT");
+
+        let c = lut.fit_polynomial(2);
+        for (got, want) in c.iter().zip([0.5, -0.02, 3.0e-5]) {
+            assert!((got - want).abs() < 1e-8, "{got} != {want}");
+        }
+
+        let eval = |x: f64| crate::polynomial::evaluate(&c, x);
+        for x in [250.0, 1750.0, 3300.0, 5900.0] {
+            // Linear interpolation of a convex curve overshoots slightly
+            assert!((eval(x) - lut.lookup(x)).abs() < 2.0, "x = {x}");
+            assert!((eval(x) - quad(x)).abs() < 1e-8, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_to_uniform_grid() {
+        let lut = Lut1D::new(vec![0.0, 20.0, 99.0], vec![10.0, 250.0, 40.0]).expect("valid LUT");
+        let grid = lut.to_uniform_grid(100).expect("valid size");
+
+        assert_eq!(grid.len(), 100);
+        assert!(grid.x_axis()[0].abs() < 1e-12);
+        assert!((grid.x_axis()[99] - 99.0).abs() < 1e-12);
+        for w in grid.x_axis().windows(2) {
+            assert!((w[1] - w[0] - 1.0).abs() < 1e-12);
+        }
+        for (&x, &y) in lut.x_axis().iter().zip(lut.data()) {
+            assert!((grid.lookup(x) - y).abs() < 1e-10, "x = {x}");
+        }
+        for x in [5.5, 37.25, 80.0] {
+            assert!((grid.lookup(x) - lut.lookup(x)).abs() < 1e-10, "x = {x}");
+        }
+
+        assert_eq!(
+            lut.to_uniform_grid(1).err(),
+            Some(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        let single = Lut1D::new(vec![1.0], vec![1.0]).expect("valid LUT");
+        assert!(single.to_uniform_grid(10).is_err());
+    }
+
+    #[test]
+    fn test_curvature_step() {
+        let lut = Lut1D::new(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![0.0, 0.0, 0.1, 5.0, 5.0, 5.0],
+        )
+        .expect("valid LUT");
+
+        assert_eq!(lut.max_curvature_index(), 3);
+        assert!((lut.curvature_at(3) + 4.9).abs() < 1e-12);
+        assert!((lut.curvature_at(2) - 4.8).abs() < 1e-12);
+        assert!(lut.curvature_at(0).abs() < 1e-12);
+        assert!(lut.curvature_at(5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_curvature_smooth() {
+        // y = x^2 sampled on a non-uniform axis: the slope change grows with spacing
+        let x = vec![0.0, 1.0, 2.0, 4.0, 5.0];
+        let y = x.iter().map(|&x: &f64| x * x).collect();
+        let lut = Lut1D::new(x, y).expect("valid LUT");
+
+        assert!((lut.curvature_at(1) - 2.0).abs() < 1e-12);
+        assert!((lut.curvature_at(2) - 3.0).abs() < 1e-12);
+        assert!((lut.curvature_at(3) - 3.0).abs() < 1e-12);
+        assert_eq!(lut.max_curvature_index(), 2);
+    }
+
+    #[test]
+    fn test_curvature_straight_line() {
+        let line =
+            Lut1D::new(vec![0.0, 1.0, 3.0, 7.0], vec![1.0, 3.0, 7.0, 15.0]).expect("valid LUT");
+        for i in 0..line.len() {
+            assert!(line.curvature_at(i).abs() < 1e-12);
+        }
+        assert_eq!(line.max_curvature_index(), 1);
+
+        let segment = Lut1D::new(vec![0.0, 1.0], vec![0.0, 2.0]).expect("valid LUT");
+        assert_eq!(segment.max_curvature_index(), 1);
+        assert!(segment.curvature_at(1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_split_at_peak() {
+        let lut = Lut1D::new(
+            vec![0.0, 0.05, 0.1, 0.2, 0.5],
+            vec![0.0, 0.9, 1.1, 1.0, 0.8],
+        )
+        .expect("valid LUT");
+        let (rising, falling) = lut.split_at_peak();
+
+        assert_eq!(rising.len(), 3);
+        assert_eq!(falling.len(), 3);
+        assert_eq!(rising.x_axis(), &lut.x_axis()[..3]);
+        assert_eq!(falling.x_axis(), &lut.x_axis()[2..]);
+        for &x in rising.x_axis() {
+            assert!((rising.lookup(x) - lut.lookup(x)).abs() < 1e-12);
+        }
+        for &x in falling.x_axis() {
+            assert!((falling.lookup(x) - lut.lookup(x)).abs() < 1e-12);
+        }
+        assert!((rising.lookup(0.075) - lut.lookup(0.075)).abs() < 1e-12);
+        assert!((falling.lookup(0.3) - lut.lookup(0.3)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_split_at_peak_monotone() {
+        let rising = Lut1D::new(vec![0.0, 1.0, 2.0], vec![1.0, 2.0, 3.0]).expect("valid LUT");
+        let (up, down) = rising.split_at_peak();
+        assert_eq!(up.len(), 3);
+        assert_eq!(down.x_axis(), &[2.0]);
+
+        let falling = Lut1D::new(vec![0.0, 1.0, 2.0], vec![3.0, 2.0, 1.0]).expect("valid LUT");
+        let (up, down) = falling.split_at_peak();
+        assert_eq!(up.x_axis(), &[0.0]);
+        assert_eq!(down.len(), 3);
+    }
+
+    #[test]
+    fn test_cumulative_sum() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 5.0], vec![1.0, 2.0, 3.0]).expect("valid LUT");
+        let sum = lut.cumulative_sum().expect("finite sums");
+        assert_eq!(sum.x_axis(), lut.x_axis());
+        assert_eq!(sum.data(), &[1.0, 3.0, 6.0]);
+
+        let huge = Lut1D::new(vec![0.0, 1.0], vec![f64::MAX, f64::MAX]).expect("valid LUT");
+        assert_eq!(
+            huge.cumulative_sum().err(),
+            Some(LutError::InfInData { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_cumulative_integral() {
+        // y = x integrates to x^2 / 2, even on a non-uniform axis
+        let x = vec![0.0, 0.5, 1.0, 2.5, 4.0];
+        let lut = Lut1D::new(x.clone(), x.clone()).expect("valid LUT");
+        let integral = lut.cumulative_integral().expect("finite area");
+
+        assert_eq!(integral.x_axis(), lut.x_axis());
+        for (&x, &v) in x.iter().zip(integral.data()) {
+            assert!((v - 0.5 * x * x).abs() < 1e-12, "x = {x}");
+        }
+
+        // Starts from the first breakpoint, not from zero
+        let offset = Lut1D::new(vec![10.0, 12.0], vec![3.0, 3.0]).expect("valid LUT");
+        let integral = offset.cumulative_integral().expect("finite area");
+        assert_eq!(integral.data(), &[0.0, 6.0]);
+
+        let single = Lut1D::new(vec![1.0], vec![5.0]).expect("valid LUT");
+        let integral = single.cumulative_integral().expect("finite area");
+        assert_eq!(integral.data(), &[0.0]);
+
+        let huge = Lut1D::new(vec![0.0, 4.0], vec![f64::MAX, f64::MAX]).expect("valid LUT");
+        assert_eq!(
+            huge.cumulative_integral().err(),
+            Some(LutError::InfInData { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_apply_gain_offset() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 100.0, 200.0]).expect("valid LUT");
+        let calibrated = lut.apply_gain_offset(2.0, 10.0).expect("finite");
+        assert_eq!(calibrated.x_axis(), lut.x_axis());
+        assert_eq!(calibrated.data(), &[10.0, 210.0, 410.0]);
+
+        // Two reference points taken from the calibrated table map it back
+        let (gain, offset) = calibrate_two_point(10.0, 0.0, 410.0, 200.0);
+        assert!((gain - 0.5).abs() < 1e-12);
+        assert!((offset + 5.0).abs() < 1e-12);
+        let restored = calibrated.apply_gain_offset(gain, offset).expect("finite");
+        for (a, b) in restored.data().iter().zip(lut.data()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        // 0 * INF is NaN
+        assert_eq!(
+            lut.apply_gain_offset(f64::INFINITY, 0.0).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_lookup_with_hold() {
+        let shift =
+            Lut1D::new(vec![0.0, 1.0, 2.0, 3.0], vec![1.0, 1.0, 2.0, 2.0]).expect("valid LUT");
+        let mut hold = HoldState::new(0.1);
+        let dt = 0.01;
+
+        for _ in 0..5 {
+            assert!((shift.lookup_with_hold(0.5, &mut hold, dt) - 1.0).abs() < 1e-12);
+        }
+
+        // Step into the next interval: the old output holds for 10 steps
+        for step in 0..10 {
+            let v = shift.lookup_with_hold(2.5, &mut hold, dt);
+            assert!((v - 1.0).abs() < 1e-12, "step {step}: {v}");
+        }
+        for _ in 0..3 {
+            assert!((shift.lookup_with_hold(2.5, &mut hold, dt) - 2.0).abs() < 1e-12);
+        }
+
+        // Moving within an interval passes straight through
+        let v = shift.lookup_with_hold(2.75, &mut hold, dt);
+        assert!((v - 2.0).abs() < 1e-12);
+        assert!(hold.remaining_hold.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lookup_with_hold_restarts_on_new_crossing() {
+        let lut = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 10.0, 20.0]).expect("valid LUT");
+        let mut hold = HoldState::new(0.03);
+        let dt = 0.01;
+
+        assert!((lut.lookup_with_hold(0.5, &mut hold, dt) - 5.0).abs() < 1e-12);
+        assert!((lut.lookup_with_hold(1.5, &mut hold, dt) - 5.0).abs() < 1e-12);
+        assert!((lut.lookup_with_hold(1.5, &mut hold, dt) - 5.0).abs() < 1e-12);
+        // Back to the first interval before the hold expires
+        assert!((lut.lookup_with_hold(0.25, &mut hold, dt) - 5.0).abs() < 1e-12);
+        assert!((lut.lookup_with_hold(0.25, &mut hold, dt) - 5.0).abs() < 1e-12);
+        assert!((lut.lookup_with_hold(0.25, &mut hold, dt) - 5.0).abs() < 1e-12);
+        assert!((lut.lookup_with_hold(0.25, &mut hold, dt) - 2.5).abs() < 1e-12);
+
+        let mut no_hold = HoldState::new(0.0);
+        assert!((lut.lookup_with_hold(0.5, &mut no_hold, dt) - 5.0).abs() < 1e-12);
+        assert!((lut.lookup_with_hold(1.5, &mut no_hold, dt) - 15.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lookup_with_hysteresis() {
+        // ABS mode map: mode 0 below 5 m/s, mode 1 from 5 to 10, mode 2 above
+        let modes = Lut1D::new(
+            vec![0.0, 4.999, 5.0, 9.999, 10.0, 20.0],
+            vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0],
+        )
+        .expect("valid LUT");
+        let mut state = HysteresisState::new();
+
+        assert!((modes.lookup_with_hysteresis(5.5, &mut state, 0.3) - 1.0).abs() < 1e-12);
+        let confirmed = state.interval();
+        assert_eq!(confirmed, Some(2));
+
+        for k in 0..20 {
+            let x = if k % 2 == 0 { 4.9 } else { 5.1 };
+            let v = modes.lookup_with_hysteresis(x, &mut state, 0.3);
+            assert!((v - 1.0).abs() < 1e-12, "x = {x}: {v}");
+            assert_eq!(state.interval(), confirmed);
+        }
+
+        // A large step still switches
+        assert!(modes.lookup_with_hysteresis(3.0, &mut state, 0.3).abs() < 1e-12);
+        assert_eq!(state.interval(), Some(0));
+        assert!((modes.lookup_with_hysteresis(15.0, &mut state, 0.3) - 2.0).abs() < 1e-12);
+        assert_eq!(state.interval(), Some(4));
+
+        // Without hysteresis the same input flips every step
+        assert!(modes.lookup(4.9).abs() < 1e-12);
+        assert!((modes.lookup(5.1) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_piecewise_affine() {
+        let xs: Vec<f64> = (0..=10).map(f64::from).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| 2.0 * x).collect();
+        let lut = Lut1D::new(xs, ys).expect("valid LUT");
+
+        let inverse = lut.inverse_piecewise_affine().expect("monotone curve");
+        for y in [0.0, 3.0, 7.5, 20.0] {
+            assert!((inverse.lookup(y) - y / 2.0).abs() < 1e-12);
+        }
+
+        let round_trip = inverse.inverse_piecewise_affine().expect("monotone curve");
+        assert_eq!(round_trip.x_axis(), lut.x_axis());
+        assert_eq!(round_trip.data(), lut.data());
+
+        // Decreasing data is reversed so the new axis ascends
+        let falling = Lut1D::new(vec![0.0, 1.0, 2.0], vec![10.0, 4.0, 1.0]).expect("valid LUT");
+        let inverse = falling.inverse_piecewise_affine().expect("monotone curve");
+        assert_eq!(inverse.x_axis(), &[1.0, 4.0, 10.0]);
+        assert_eq!(inverse.data(), &[2.0, 1.0, 0.0]);
+        assert!((inverse.lookup(7.0) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_piecewise_affine_rejects_non_monotone() {
+        let peak = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.5]).expect("valid LUT");
+        assert!(peak.inverse_piecewise_affine().is_none());
+
+        let flat = Lut1D::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 1.0]).expect("valid LUT");
+        assert!(flat.inverse_piecewise_affine().is_none());
+
+        let valley = Lut1D::new(vec![0.0, 1.0, 2.0], vec![1.0, 0.0, 0.5]).expect("valid LUT");
+        assert!(valley.inverse_piecewise_affine().is_none());
+
+        // Rising, but by less than a new axis can resolve
+        let unresolvable = Lut1D::new(vec![0.0, 1.0], vec![1e16, 1e16 + 2.0]).expect("valid LUT");
+        assert!(unresolvable.inverse_piecewise_affine().is_none());
+
+        let single = Lut1D::new(vec![3.0], vec![7.0]).expect("valid LUT");
+        let inverse = single.inverse_piecewise_affine().expect("monotone curve");
+        assert_eq!(inverse.x_axis(), &[7.0]);
+    }
+
+    #[test]
+    fn test_remove_outliers() {
+        let xs: Vec<f64> = (0..10).map(f64::from).collect();
+        let mut ys: Vec<f64> = xs.iter().map(|x| 1.0 + 0.1 * x).collect();
+        ys[4] = 50.0;
+        let lut = Lut1D::new(xs, ys).expect("valid LUT");
+
+        let filtered = lut.remove_outliers(2.0);
+        assert_eq!(filtered.len(), 9);
+        assert!(!filtered.x_axis().contains(&4.0));
+        for &x in filtered.x_axis() {
+            assert!((filtered.lookup(x) - lut.lookup(x)).abs() < 1e-12);
+        }
+
+        // Nothing within zero deviations of the mean: keep the original
+        let unchanged = lut.remove_outliers(0.0);
+        assert_eq!(unchanged.x_axis(), lut.x_axis());
+        assert_eq!(unchanged.data(), lut.data());
+    }
+
+    #[test]
+    fn test_linear_regression() {
+        let xs: Vec<f64> = (0..8).map(f64::from).collect();
+        let line =
+            Lut1D::new(xs.clone(), xs.iter().map(|x| 3.0 * x + 5.0).collect()).expect("valid LUT");
+        let (slope, intercept) = line.linear_regression();
+        assert!((slope - 3.0).abs() < 1e-12);
+        assert!((intercept - 5.0).abs() < 1e-12);
+        assert!((line.r_squared() - 1.0).abs() < 1e-12);
+
+        let constant = Lut1D::new(xs.clone(), vec![2.0; 8]).expect("valid LUT");
+        assert!(constant.linear_regression().0.abs() < 1e-12);
+        assert!(constant.r_squared().abs() < 1e-12);
+
+        let parabola =
+            Lut1D::new(xs.clone(), xs.iter().map(|x| x * x).collect()).expect("valid LUT");
+        let r2 = parabola.r_squared();
+        assert!(r2 > 0.0 && r2 < 1.0);
+
+        let single = Lut1D::new(vec![1.0], vec![4.0]).expect("valid LUT");
+        let (slope, intercept) = single.linear_regression();
+        assert!(slope.abs() < 1e-12);
+        assert!((intercept - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_max_change() {
+        let old = Lut1D::new(vec![0.0, 1.0, 2.0], vec![100.0, 200.0, 0.0]).expect("valid LUT");
+        assert_eq!(old.max_absolute_change(&old), Some(0.0));
+        assert_eq!(old.max_relative_change(&old), Some(0.0));
+
+        let new = Lut1D::new(vec![0.0, 1.0, 2.0], vec![100.0, 210.0, 0.0]).expect("valid LUT");
+        let absolute = old.max_absolute_change(&new).expect("same axis");
+        assert!((absolute - 10.0).abs() < 1e-12);
+        let relative = old.max_relative_change(&new).expect("same axis");
+        assert!((relative - 0.05).abs() < 1e-12);
+
+        let shifted = Lut1D::new(vec![0.0, 1.5, 2.0], vec![100.0, 200.0, 0.0]).expect("valid LUT");
+        assert_eq!(old.max_absolute_change(&shifted), None);
+        let shorter = Lut1D::new(vec![0.0, 1.0], vec![100.0, 200.0]).expect("valid LUT");
+        assert_eq!(old.max_relative_change(&shorter), None);
+    }
+
+    #[test]
+    fn test_lookup_with_hint_matches_lookup() {
+        let xs: Vec<f64> = (0..100).map(f64::from).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| libm::sin(x * 0.1) * 50.0).collect();
+        let lut = Lut1D::new(xs, ys).expect("valid LUT");
+
+        let mut hint = 0;
+        for i in 0..10_000 {
+            let x = f64::from(i) * 0.0101 - 1.0;
+            assert!((lut.lookup_with_hint(x, &mut hint) - lut.lookup(x)).abs() < 1e-12);
+        }
+        assert_eq!(hint, 98);
+
+        // Jumps fall back to the full search
+        let mut hint = 0;
+        assert!((lut.lookup_with_hint(73.25, &mut hint) - lut.lookup(73.25)).abs() < 1e-12);
+        assert_eq!(hint, 73);
+        assert!((lut.lookup_with_hint(2.5, &mut hint) - lut.lookup(2.5)).abs() < 1e-12);
+        assert_eq!(hint, 2);
+    }
+
+    #[test]
+    fn test_merge() {
+        let low = Lut1D::new(vec![0.0, 1.0, 2.0], vec![1.0, 2.0, 3.0]).expect("valid LUT");
+        let high = Lut1D::new(vec![3.0, 4.0, 5.0], vec![10.0, 20.0, 30.0]).expect("valid LUT");
+        let merged = Lut1D::merge(&low, &high).expect("disjoint ranges");
+        assert_eq!(merged.x_axis(), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(merged.data(), &[1.0, 2.0, 3.0, 10.0, 20.0, 30.0]);
+        assert!((merged.lookup(2.5) - 6.5).abs() < 1e-12);
+
+        // The shared boundary takes the high table's value
+        let touching = Lut1D::new(vec![2.0, 4.0, 5.0], vec![7.0, 20.0, 30.0]).expect("valid LUT");
+        let merged = Lut1D::merge(&low, &touching).expect("disjoint ranges");
+        assert_eq!(merged.x_axis(), &[0.0, 1.0, 2.0, 4.0, 5.0]);
+        assert_eq!(merged.data(), &[1.0, 2.0, 7.0, 20.0, 30.0]);
+
+        let overlapping = Lut1D::new(vec![1.5, 4.0], vec![0.0, 0.0]).expect("valid LUT");
+        assert_eq!(
+            Lut1D::merge(&low, &overlapping).err(),
+            Some(LutError::UnsortedAxis {
+                axis: "X",
+                index: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_resample_to_match() {
+        let first = Lut1D::new(vec![0.0, 2.0], vec![10.0, 20.0]).expect("valid LUT");
+        let second = Lut1D::new(vec![1.0, 3.0], vec![-1.0, -3.0]).expect("valid LUT");
+        let (a, b) = first.resample_to_match(&second);
+
+        assert_eq!(a.x_axis(), &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(b.x_axis(), a.x_axis());
+        // Original values at original breakpoints, clamped beyond them
+        assert_eq!(a.data(), &[10.0, 15.0, 20.0, 20.0]);
+        assert_eq!(b.data(), &[-1.0, -1.0, -2.0, -3.0]);
+
+        // Breakpoints a rounding error apart collapse into one
+        let nearly = Lut1D::new(vec![2.0 + f64::EPSILON, 4.0], vec![0.0, 1.0]).expect("valid LUT");
+        let (a, b) = first.resample_to_match(&nearly);
+        assert_eq!(a.x_axis(), &[0.0, 2.0, 4.0]);
+        assert_eq!(b.len(), 3);
+    }
+
+    #[test]
+    fn test_non_finite_data_rejected() {
+        assert_eq!(
+            Lut1D::new(vec![0.0, 1.0, 2.0], vec![1.0, f64::NAN, 3.0]).err(),
+            Some(LutError::NaNInData { index: 1 })
+        );
+        assert_eq!(
+            Lut1D::new(vec![0.0, 1.0], vec![f64::INFINITY, 0.0]).err(),
+            Some(LutError::InfInData { index: 0 })
+        );
+        assert_eq!(
+            Lut2D::new(vec![0.0, 1.0], vec![0.0], vec![0.0, f64::NAN]).err(),
+            Some(LutError::NaNInData { index: 1 })
+        );
     }
 }
@@ -0,0 +1,463 @@
+//! Single-precision 1D lookup table for memory-constrained targets.
+
+use alloc::vec::Vec;
+
+use super::{lerp, validate_axis, validate_data, Lut1D, LutError};
+
+/// 1D lookup table storing axis and data as `f32`.
+///
+/// Uses half the memory of [`Lut1D`] and performs all interpolation in
+/// `f32` arithmetic. Lookups are always linear and clamp outside the axis
+/// range; periodic wrapping and Hermite tangents are not supported.
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::Lut1Df32;
+///
+/// let lut = Lut1Df32::new(vec![0.0, 1000.0, 2000.0], vec![0.0, 100.0, 200.0]).unwrap();
+/// assert!((lut.lookup(1500.0) - 150.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lut1Df32 {
+    x_axis: Vec<f32>,
+    data: Vec<f32>,
+}
+
+impl Lut1Df32 {
+    /// Creates a new single-precision 1D lookup table.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Lut1D::new`]:
+    /// - `x_axis` is empty
+    /// - `x_axis` is not strictly ascending
+    /// - `data` length doesn't match `x_axis` length
+    /// - `data` contains NaN or infinite values
+    pub fn new(x_axis: Vec<f32>, data: Vec<f32>) -> Result<Self, LutError> {
+        // Widening is exact, so checking in f64 applies the Lut1D rules as is
+        validate_axis(&widen(&x_axis), "X", LutError::EmptyXAxis)?;
+        if data.len() != x_axis.len() {
+            return Err(LutError::DimensionMismatch {
+                expected: x_axis.len(),
+                actual: data.len(),
+            });
+        }
+        validate_data(&widen(&data))?;
+
+        Ok(Self { x_axis, data })
+    }
+
+    /// Looks up and linearly interpolates a value at the given x coordinate.
+    ///
+    /// Values outside the axis range are clamped to boundary values.
+    #[inline]
+    #[must_use]
+    pub fn lookup(&self, x: f32) -> f32 {
+        let n = self.x_axis.len();
+        if n == 1 || x <= self.x_axis[0] {
+            return self.data[0];
+        }
+        if x >= self.x_axis[n - 1] {
+            return self.data[n - 1];
+        }
+
+        let mut lo = 0;
+        let mut hi = n - 1;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if self.x_axis[mid] <= x {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let t = (x - self.x_axis[lo]) / (self.x_axis[hi] - self.x_axis[lo]);
+        self.data[lo] + t * (self.data[hi] - self.data[lo])
+    }
+
+    /// Returns the X axis values.
+    #[must_use]
+    pub fn x_axis(&self) -> &[f32] {
+        &self.x_axis
+    }
+
+    /// Returns the data values.
+    #[must_use]
+    pub fn data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Returns the number of data points.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the LUT has no data points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// 1D lookup table stored as `f32` but evaluated in `f64`.
+///
+/// Halves ROM usage like [`Lut1Df32`], while every lookup widens the stored
+/// values and searches and interpolates in double precision, so the only
+/// error is the rounding of the stored breakpoints. Created with
+/// [`Lut1D::to_f32_compressed`].
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::Lut1D;
+///
+/// let lut = Lut1D::new(vec![1000.0, 4000.0, 7000.0], vec![180.3, 262.7, 214.9]).unwrap();
+/// let rom = lut.to_f32_compressed().unwrap();
+/// assert!((rom.lookup(2500.0) - lut.lookup(2500.0)).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut1DCompressed {
+    x_axis: Vec<f32>,
+    data: Vec<f32>,
+}
+
+impl Lut1DCompressed {
+    /// Looks up and linearly interpolates a value at the given x
+    /// coordinate, in `f64` arithmetic.
+    ///
+    /// Values outside the axis range are clamped to boundary values.
+    #[inline]
+    #[must_use]
+    pub fn lookup(&self, x: f64) -> f64 {
+        let axis = |i: usize| f64::from(self.x_axis[i]);
+        let value = |i: usize| f64::from(self.data[i]);
+
+        let n = self.x_axis.len();
+        if n == 1 || x <= axis(0) {
+            return value(0);
+        }
+        if x >= axis(n - 1) {
+            return value(n - 1);
+        }
+
+        let mut lo = 0;
+        let mut hi = n - 1;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if axis(mid) <= x {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let t = (x - axis(lo)) / (axis(hi) - axis(lo));
+        lerp(value(lo), value(hi), t)
+    }
+
+    /// Returns the stored X axis values.
+    #[must_use]
+    pub fn x_axis(&self) -> &[f32] {
+        &self.x_axis
+    }
+
+    /// Returns the stored data values.
+    #[must_use]
+    pub fn data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Returns the number of data points.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the LUT has no data points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// Widens `f32` values to `f64`. The conversion is exact.
+fn widen(values: &[f32]) -> Vec<f64> {
+    values.iter().copied().map(f64::from).collect()
+}
+
+/// Returns the smallest `f32` greater than `v`, or `v` if there is none.
+fn next_up_f32(v: f32) -> f32 {
+    if v.is_nan() || v == f32::INFINITY {
+        v
+    } else if v == 0.0 {
+        f32::from_bits(1)
+    } else if v > 0.0 {
+        f32::from_bits(v.to_bits() + 1)
+    } else {
+        f32::from_bits(v.to_bits() - 1)
+    }
+}
+
+impl From<Lut1Df32> for Lut1D {
+    /// Widens a single-precision table. The conversion is exact, so the
+    /// result is valid and its breakpoints match the `f32` ones.
+    fn from(lut: Lut1Df32) -> Self {
+        Self::from_validated_parts(&widen(&lut.x_axis), &widen(&lut.data))
+    }
+}
+
+impl Lut1D {
+    /// Looks up a value using single-precision input and output.
+    ///
+    /// The input is widened to `f64`, passed to [`Lut1D::lookup`], and the
+    /// result rounded to the nearest `f32`.
+    #[inline]
+    #[must_use]
+    pub fn lookup_f32(&self, x: f32) -> f32 {
+        self.lookup(f64::from(x)) as f32
+    }
+
+    /// Converts to a single-precision table.
+    ///
+    /// # Precision loss
+    ///
+    /// Every value is rounded to the nearest `f32`, keeping about 7
+    /// significant digits. Breakpoints that would round onto the previous
+    /// one are moved up to the next representable `f32`, so the axis stays
+    /// strictly ascending, but the shape of the curve between them is lost.
+    /// Periodic wrapping and Hermite tangents are dropped, so the result
+    /// always interpolates linearly with clamping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::ValueOutOfRange` if a breakpoint or data value
+    /// would become infinite, i.e. its magnitude exceeds `f32::MAX`.
+    pub fn to_f32(&self) -> Result<Lut1Df32, LutError> {
+        let (x_axis, data) = self.narrow()?;
+        Ok(Lut1Df32 { x_axis, data })
+    }
+
+    /// Converts to a table stored in `f32` but evaluated in `f64`.
+    ///
+    /// # Precision loss
+    ///
+    /// Every value is rounded to the nearest `f32`, a relative error of at
+    /// most about `6e-8`. Breakpoints that would round onto the previous
+    /// one are moved up to the next representable `f32`, so the axis stays
+    /// strictly ascending. Periodic wrapping and Hermite tangents are
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::ValueOutOfRange` if a breakpoint or data value
+    /// would become infinite, i.e. its magnitude exceeds `f32::MAX`.
+    pub fn to_f32_compressed(&self) -> Result<Lut1DCompressed, LutError> {
+        let (x_axis, data) = self.narrow()?;
+        Ok(Lut1DCompressed { x_axis, data })
+    }
+
+    /// Widens a compressed table back to double precision.
+    ///
+    /// The conversion is exact, so the result matches
+    /// [`Lut1DCompressed::lookup`] everywhere.
+    #[must_use]
+    pub fn from_f32_compressed(c: Lut1DCompressed) -> Self {
+        let Lut1DCompressed { x_axis, data } = c;
+        Self::from_validated_parts(&widen(&x_axis), &widen(&data))
+    }
+
+    /// Rounds the axis and data to `f32`, bumping breakpoints that would
+    /// collapse onto the previous one.
+    fn narrow(&self) -> Result<(Vec<f32>, Vec<f32>), LutError> {
+        let mut x_axis: Vec<f32> = Vec::with_capacity(self.len());
+        for (index, &x) in self.x_axis().iter().enumerate() {
+            let narrowed = x as f32;
+            let x = match x_axis.last() {
+                Some(&prev) if narrowed <= prev => next_up_f32(prev),
+                _ => narrowed,
+            };
+            if !x.is_finite() {
+                return Err(LutError::ValueOutOfRange { axis: "X", index });
+            }
+            x_axis.push(x);
+        }
+        let data = self
+            .data()
+            .iter()
+            .enumerate()
+            .map(|(index, &y)| {
+                let narrowed = y as f32;
+                if narrowed.is_finite() {
+                    Ok(narrowed)
+                } else {
+                    Err(LutError::ValueOutOfRange {
+                        axis: "data",
+                        index,
+                    })
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((x_axis, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_f32_lookup_matches_f64() {
+        let lut = Lut1D::new(
+            vec![0.0, 1000.0, 2000.0, 3000.0],
+            vec![0.0, 150.0, 280.0, 250.0],
+        )
+        .expect("valid LUT");
+        let lut32 = lut.to_f32().expect("fits in f32");
+
+        for rpm in [-100.0_f32, 0.0, 500.0, 1750.0, 2999.0, 5000.0] {
+            let wide = lut.lookup(f64::from(rpm));
+            assert!((f64::from(lut32.lookup(rpm)) - wide).abs() < 1e-3);
+            assert!((f64::from(lut.lookup_f32(rpm)) - wide).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_f32_round_trip() {
+        let lut32 = Lut1Df32::new(vec![0.0, 0.5, 2.0], vec![1.0, -1.0, 3.0]).expect("valid LUT");
+        let lut = Lut1D::from(lut32.clone());
+
+        assert_eq!(lut.x_axis(), &[0.0, 0.5, 2.0]);
+        assert_eq!(lut.to_f32().expect("fits in f32").data(), lut32.data());
+    }
+
+    #[test]
+    fn test_f32_validation() {
+        assert_eq!(
+            Lut1Df32::new(vec![], vec![]).err(),
+            Some(LutError::EmptyXAxis)
+        );
+        assert_eq!(
+            Lut1Df32::new(vec![0.0, 0.0], vec![1.0, 2.0]).err(),
+            Some(LutError::UnsortedAxis {
+                axis: "X",
+                index: 1
+            })
+        );
+        assert!(Lut1Df32::new(vec![0.0, 1.0], vec![1.0]).is_err());
+        assert_eq!(
+            Lut1Df32::new(vec![0.0, f32::NAN, 2.0], vec![0.0; 3]).err(),
+            Some(LutError::UnsortedAxis {
+                axis: "X",
+                index: 1
+            })
+        );
+        assert_eq!(
+            Lut1Df32::new(vec![0.0, 1.0], vec![0.0, f32::INFINITY]).err(),
+            Some(LutError::InfInData { index: 1 })
+        );
+        assert_eq!(
+            Lut1Df32::new(vec![0.0, 1.0], vec![f32::NAN, 0.0]).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_f32_keeps_axis_ascending() {
+        // Breakpoints closer than f32 resolution would collapse
+        let lut = Lut1D::new(vec![1.0, 1.0 + 1e-12, 2.0], vec![0.0, 1.0, 2.0]).expect("valid LUT");
+        let lut32 = lut.to_f32().expect("fits in f32");
+        assert!(lut32.x_axis().windows(2).all(|w| w[0] < w[1]));
+        assert!(Lut1D::from(lut32).lookup(1.5).is_finite());
+
+        let huge = Lut1D::new(vec![0.0, 1.0], vec![0.0, 1e300]).expect("valid LUT");
+        assert_eq!(
+            huge.to_f32().err(),
+            Some(LutError::ValueOutOfRange {
+                axis: "data",
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_compressed_torque_curve_error() {
+        // Engine torque in N*m against speed in rpm
+        let rpm = vec![
+            800.0, 1500.0, 2200.0, 3000.0, 3800.0, 4500.0, 5300.0, 6100.0, 6800.0,
+        ];
+        let torque = vec![
+            182.37, 241.91, 288.42, 312.05, 318.66, 309.73, 291.18, 264.52, 231.09,
+        ];
+        let lut = Lut1D::new(rpm, torque).expect("valid LUT");
+        let rom = lut.to_f32_compressed().expect("fits in f32");
+        assert_eq!(rom.len(), lut.len());
+
+        let mut max_relative_error: f64 = 0.0;
+        for i in 0..=7000 {
+            let x = f64::from(i);
+            let exact = lut.lookup(x);
+            let error = ((rom.lookup(x) - exact) / exact).abs();
+            max_relative_error = max_relative_error.max(error);
+        }
+        assert!(max_relative_error < 0.005, "{max_relative_error}");
+        // f32 keeps about 7 significant digits
+        assert!(max_relative_error < 1e-6, "{max_relative_error}");
+
+        let widened = Lut1D::from_f32_compressed(rom.clone());
+        for x in [0.0, 950.0, 3333.3, 6800.0, 9000.0] {
+            assert!((widened.lookup(x) - rom.lookup(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_compressed_keeps_axis_ascending() {
+        // Breakpoints closer than f32 resolution would collapse
+        let lut = Lut1D::new(vec![1.0, 1.0 + 1e-12, 2.0], vec![0.0, 1.0, 2.0]).expect("valid LUT");
+        let rom = lut.to_f32_compressed().expect("fits in f32");
+        assert!(rom.x_axis().windows(2).all(|w| w[0] < w[1]));
+        let widened = Lut1D::from_f32_compressed(rom);
+        assert!(widened.lookup(1.5).is_finite());
+
+        assert_eq!(next_up_f32(-0.0).to_bits(), 1);
+        assert!(next_up_f32(-1.0) > -1.0);
+        assert!(next_up_f32(1.0) > 1.0);
+    }
+
+    #[test]
+    fn test_compressed_rejects_out_of_range() {
+        let huge_data = Lut1D::new(vec![0.0, 1.0], vec![0.0, 1e300]).expect("valid LUT");
+        assert_eq!(
+            huge_data.to_f32_compressed().err(),
+            Some(LutError::ValueOutOfRange {
+                axis: "data",
+                index: 1
+            })
+        );
+
+        let huge_axis = Lut1D::new(vec![0.0, 1e39], vec![0.0, 1.0]).expect("valid LUT");
+        assert_eq!(
+            huge_axis.to_f32_compressed().err(),
+            Some(LutError::ValueOutOfRange {
+                axis: "X",
+                index: 1
+            })
+        );
+
+        // Bumping past the last breakpoint below f32::MAX must not overflow
+        let max = f64::from(f32::MAX);
+        let crowded =
+            Lut1D::new(vec![max * (1.0 - 1e-12), max], vec![0.0, 1.0]).expect("valid LUT");
+        assert_eq!(
+            crowded.to_f32_compressed().err(),
+            Some(LutError::ValueOutOfRange {
+                axis: "X",
+                index: 1
+            })
+        );
+    }
+}
@@ -2,7 +2,10 @@
 
 use alloc::vec::Vec;
 
-use super::{find_interval, lerp, validate_axis, LutError};
+use super::{
+    find_bracket, lerp, min_axis_stride, validate_axis, validate_data, values_match, wrap_periodic,
+    Lut1D, LutError,
+};
 
 /// 2D lookup table for z = f(x, y) interpolation.
 ///
@@ -28,6 +31,10 @@ pub struct Lut2D {
     x_axis: Vec<f64>,
     y_axis: Vec<f64>,
     data: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    periodic_x: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    periodic_y: bool,
 }
 
 impl Lut2D {
@@ -41,7 +48,8 @@ impl Lut2D {
     ///
     /// # Errors
     ///
-    /// Returns `LutError` if any axis is empty, unsorted, or dimensions don't match.
+    /// Returns `LutError` if any axis is empty, unsorted, or dimensions don't match,
+    /// or if `data` contains NaN or infinite values.
     pub fn new(x_axis: Vec<f64>, y_axis: Vec<f64>, data: Vec<f64>) -> Result<Self, LutError> {
         validate_axis(&x_axis, "X", LutError::EmptyXAxis)?;
         validate_axis(&y_axis, "Y", LutError::EmptyYAxis)?;
@@ -53,31 +61,104 @@ impl Lut2D {
                 actual: data.len(),
             });
         }
+        validate_data(&data)?;
 
         Ok(Self {
             x_axis,
             y_axis,
             data,
+            periodic_x: false,
+            periodic_y: false,
         })
     }
 
+    /// Builds a table from axes and data whose validity is already known.
+    pub(super) fn from_validated_parts(x_axis: Vec<f64>, y_axis: Vec<f64>, data: Vec<f64>) -> Self {
+        debug_assert_eq!(data.len(), x_axis.len() * y_axis.len());
+        Self {
+            x_axis,
+            y_axis,
+            data,
+            periodic_x: false,
+            periodic_y: false,
+        }
+    }
+
+    /// Marks the X axis as periodic, so lookups wrap around its range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NonPeriodicData` if any row has different values in
+    /// its first and last columns.
+    pub fn with_periodic_x(mut self) -> Result<Self, LutError> {
+        let nx = self.x_axis.len();
+        let periodic = self
+            .data
+            .chunks_exact(nx)
+            .all(|row| values_match(row[0], row[nx - 1]));
+        if !periodic {
+            return Err(LutError::NonPeriodicData { axis: "X" });
+        }
+        self.periodic_x = true;
+        Ok(self)
+    }
+
+    /// Marks the Y axis as periodic, so lookups wrap around its range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NonPeriodicData` if the first and last rows differ.
+    pub fn with_periodic_y(mut self) -> Result<Self, LutError> {
+        let nx = self.x_axis.len();
+        let last_row = self.data.len() - nx;
+        let periodic = (0..nx).all(|i| values_match(self.data[i], self.data[last_row + i]));
+        if !periodic {
+            return Err(LutError::NonPeriodicData { axis: "Y" });
+        }
+        self.periodic_y = true;
+        Ok(self)
+    }
+
+    /// Returns true if lookups wrap around the X axis range.
+    #[must_use]
+    pub const fn is_periodic_x(&self) -> bool {
+        self.periodic_x
+    }
+
+    /// Returns true if lookups wrap around the Y axis range.
+    #[must_use]
+    pub const fn is_periodic_y(&self) -> bool {
+        self.periodic_y
+    }
+
     /// Looks up and interpolates a value at the given (x, y) coordinates.
     ///
     /// Uses bilinear interpolation between adjacent points.
-    /// Values outside the axis ranges are clamped to boundary values.
+    /// Values outside the axis ranges are clamped to boundary values, and a
+    /// single-point axis makes the table constant along it.
     #[inline]
     #[must_use]
     pub fn lookup(&self, x: f64, y: f64) -> f64 {
-        let (xi, tx) = find_interval(&self.x_axis, x);
-        let (yi, ty) = find_interval(&self.y_axis, y);
+        let x = if self.periodic_x {
+            wrap_periodic(&self.x_axis, x)
+        } else {
+            x
+        };
+        let y = if self.periodic_y {
+            wrap_periodic(&self.y_axis, y)
+        } else {
+            y
+        };
+        let (x0, x1, tx) = find_bracket(&self.x_axis, x);
+        let (y0, y1, ty) = find_bracket(&self.y_axis, y);
 
         let x_len = self.x_axis.len();
 
         // Get the four corner values
-        let v00 = self.data[yi * x_len + xi];
-        let v10 = self.data[yi * x_len + xi + 1];
-        let v01 = self.data[(yi + 1) * x_len + xi];
-        let v11 = self.data[(yi + 1) * x_len + xi + 1];
+        let v00 = self.data[y0 * x_len + x0];
+        let v10 = self.data[y0 * x_len + x1];
+        let v01 = self.data[y1 * x_len + x0];
+        let v11 = self.data[y1 * x_len + x1];
 
         // Bilinear interpolation
         let v0 = lerp(v00, v10, tx);
@@ -85,6 +166,226 @@ impl Lut2D {
         lerp(v0, v1, ty)
     }
 
+    /// Returns the 1D cross-section `y -> f(x, y)` at a fixed `x`.
+    ///
+    /// The result has this table's Y axis as its axis, with each value
+    /// interpolated by [`Lut2D::lookup`]. It is never periodic.
+    #[must_use]
+    pub fn slice_at_x(&self, x: f64) -> Lut1D {
+        let data: Vec<f64> = self.y_axis.iter().map(|&y| self.lookup(x, y)).collect();
+        Lut1D::from_validated_parts(&self.y_axis, &data)
+    }
+
+    /// Returns the 1D cross-section `x -> f(x, y)` at a fixed `y`.
+    ///
+    /// The result has this table's X axis as its axis, with each value
+    /// interpolated by [`Lut2D::lookup`]. It is never periodic.
+    #[must_use]
+    pub fn slice_at_y(&self, y: f64) -> Lut1D {
+        let data: Vec<f64> = self.x_axis.iter().map(|&x| self.lookup(x, y)).collect();
+        Lut1D::from_validated_parts(&self.x_axis, &data)
+    }
+
+    /// Returns row `y_index` of the grid as a 1D table over the X axis.
+    ///
+    /// The values are the stored breakpoints, not interpolated. The result
+    /// is never periodic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if `y_index` is out of range,
+    /// with `expected` set to the number of rows and `actual` to `y_index`.
+    pub fn row(&self, y_index: usize) -> Result<Lut1D, LutError> {
+        let ny = self.y_axis.len();
+        if y_index >= ny {
+            return Err(LutError::DimensionMismatch {
+                expected: ny,
+                actual: y_index,
+            });
+        }
+        let nx = self.x_axis.len();
+        let data = &self.data[y_index * nx..(y_index + 1) * nx];
+        Ok(Lut1D::from_validated_parts(&self.x_axis, data))
+    }
+
+    /// Returns column `x_index` of the grid as a 1D table over the Y axis.
+    ///
+    /// The values are the stored breakpoints, not interpolated. The result
+    /// is never periodic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if `x_index` is out of range,
+    /// with `expected` set to the number of columns and `actual` to
+    /// `x_index`.
+    pub fn col(&self, x_index: usize) -> Result<Lut1D, LutError> {
+        let nx = self.x_axis.len();
+        if x_index >= nx {
+            return Err(LutError::DimensionMismatch {
+                expected: nx,
+                actual: x_index,
+            });
+        }
+        let data: Vec<f64> = self
+            .data
+            .iter()
+            .skip(x_index)
+            .step_by(nx)
+            .copied()
+            .collect();
+        Ok(Lut1D::from_validated_parts(&self.y_axis, &data))
+    }
+
+    /// Returns the bilinear polynomial `[a00, a10, a01, a11]` of the cell
+    /// between X breakpoints `xi`, `xi + 1` and Y breakpoints `yi`, `yi + 1`.
+    ///
+    /// Within the cell, [`Lut2D::lookup`] equals
+    /// `a00 + a10 * tx + a01 * ty + a11 * tx * ty`, where `tx` and `ty` run
+    /// from 0 to 1 across the cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if either index has no cell,
+    /// with `expected` set to the number of cells along that axis and
+    /// `actual` to the index.
+    pub fn cell_coefficients(&self, xi: usize, yi: usize) -> Result<[f64; 4], LutError> {
+        let nx = self.x_axis.len();
+        let ny = self.y_axis.len();
+        for (index, points) in [(xi, nx), (yi, ny)] {
+            let cells = points - 1;
+            if index >= cells {
+                return Err(LutError::DimensionMismatch {
+                    expected: cells,
+                    actual: index,
+                });
+            }
+        }
+
+        let v00 = self.data[yi * nx + xi];
+        let v10 = self.data[yi * nx + xi + 1];
+        let v01 = self.data[(yi + 1) * nx + xi];
+        let v11 = self.data[(yi + 1) * nx + xi + 1];
+        Ok([v00, v10 - v00, v01 - v00, v11 - v10 - v01 + v00])
+    }
+
+    /// Returns the table with its X and Y axes swapped.
+    ///
+    /// The result satisfies `t.lookup(y, x) == self.lookup(x, y)`; its data
+    /// is this table's data in column-major order (`data[xi * ny + yi]`).
+    /// Periodic flags move with their axes. Transposing twice gives back
+    /// the original table.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let nx = self.x_axis.len();
+        let data = (0..nx)
+            .flat_map(|xi| self.data.iter().skip(xi).step_by(nx).copied())
+            .collect();
+        Self {
+            x_axis: self.y_axis.clone(),
+            y_axis: self.x_axis.clone(),
+            data,
+            periodic_x: self.periodic_y,
+            periodic_y: self.periodic_x,
+        }
+    }
+
+    /// Returns the largest data value, ignoring NaN.
+    #[must_use]
+    pub fn data_max(&self) -> f64 {
+        self.data
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, libm::fmax)
+    }
+
+    /// Returns `(x, y, value)` of the grid point with the largest data value.
+    ///
+    /// Only stored breakpoints are searched, not the interpolated surface.
+    /// Ties go to the first point in row-major order and NaN is ignored
+    /// unless every value is NaN.
+    #[must_use]
+    pub fn find_maximum(&self) -> (f64, f64, f64) {
+        self.extreme_point(|candidate, best| candidate > best)
+    }
+
+    /// Returns `(x, y, value)` of the grid point with the smallest data
+    /// value, following the same rules as [`Lut2D::find_maximum`].
+    #[must_use]
+    pub fn find_minimum(&self) -> (f64, f64, f64) {
+        self.extreme_point(|candidate, best| candidate < best)
+    }
+
+    /// Scans the data, keeping the first point that `better` never beats.
+    fn extreme_point(&self, better: impl Fn(f64, f64) -> bool) -> (f64, f64, f64) {
+        let mut best = 0;
+        for (i, &v) in self.data.iter().enumerate().skip(1) {
+            if better(v, self.data[best]) || self.data[best].is_nan() {
+                best = i;
+            }
+        }
+        let nx = self.x_axis.len();
+        (
+            self.x_axis[best % nx],
+            self.y_axis[best / nx],
+            self.data[best],
+        )
+    }
+
+    /// Returns a copy with the data divided by [`Lut2D::data_max`], together
+    /// with that divisor.
+    ///
+    /// For tables with a positive maximum the peak becomes exactly 1.0;
+    /// [`Lut2D::scale_data`] with the returned factor restores the original.
+    /// If the maximum is zero, or so small that dividing by it overflows,
+    /// the table is returned unchanged with a factor of 1.0.
+    #[must_use]
+    pub fn normalize_data(&self) -> (Self, f64) {
+        let max = self.data_max();
+        if max == 0.0 {
+            return (self.clone(), 1.0);
+        }
+        self.map_data(|v| v / max)
+            .map_or_else(|_| (self.clone(), 1.0), |normalized| (normalized, max))
+    }
+
+    /// Returns a copy with every data value multiplied by `factor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if a scaled
+    /// value is not finite, e.g. for a NaN `factor` or on overflow.
+    pub fn scale_data(&self, factor: f64) -> Result<Self, LutError> {
+        self.map_data(|v| v * factor)
+    }
+
+    /// Returns a copy with `f` applied to every data value, e.g. for a unit
+    /// conversion. The axes are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if `f`
+    /// produces a value that [`Lut2D::new`] would reject.
+    pub fn map_data<F: Fn(f64) -> f64>(&self, f: F) -> Result<Self, LutError> {
+        let data: Vec<f64> = self.data.iter().map(|&v| f(v)).collect();
+        validate_data(&data)?;
+        Ok(Self {
+            data,
+            ..self.clone()
+        })
+    }
+
+    /// Returns the smallest gap between adjacent X breakpoints.
+    #[must_use]
+    pub fn min_x_stride(&self) -> f64 {
+        min_axis_stride(&self.x_axis)
+    }
+
+    /// Returns the smallest gap between adjacent Y breakpoints.
+    #[must_use]
+    pub fn min_y_stride(&self) -> f64 {
+        min_axis_stride(&self.y_axis)
+    }
+
     /// Returns the X axis values.
     #[must_use]
     pub fn x_axis(&self) -> &[f64] {
@@ -188,19 +489,267 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_periodic_axes() {
+        // Angle of attack (x) x yaw (y), both wrapping at 360
+        let lut = Lut2D::new(
+            vec![0.0, 180.0, 360.0],
+            vec![0.0, 180.0, 360.0],
+            vec![
+                0.0, 1.0, 0.0, //
+                2.0, 3.0, 2.0, //
+                0.0, 1.0, 0.0,
+            ],
+        )
+        .expect("valid LUT")
+        .with_periodic_x()
+        .expect("periodic x")
+        .with_periodic_y()
+        .expect("periodic y");
+
+        assert!(lut.is_periodic_x() && lut.is_periodic_y());
+        assert!((lut.lookup(370.0, 90.0) - lut.lookup(10.0, 90.0)).abs() < 1e-12);
+        assert!((lut.lookup(90.0, -90.0) - lut.lookup(90.0, 270.0)).abs() < 1e-12);
+        assert!((lut.lookup(0.0, 0.0) - lut.lookup(360.0, 360.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_periodic_requires_matching_edges() {
+        let lut = create_test_lut();
+        assert!(matches!(
+            lut.clone().with_periodic_x(),
+            Err(LutError::NonPeriodicData { axis: "X" })
+        ));
+        assert!(matches!(
+            lut.with_periodic_y(),
+            Err(LutError::NonPeriodicData { axis: "Y" })
+        ));
+    }
+
     #[test]
     fn test_many_lookups() {
         let nx = 50;
         let ny = 50;
-        let x_axis: Vec<f64> = (0..nx).map(|i| i as f64).collect();
-        let y_axis: Vec<f64> = (0..ny).map(|i| i as f64).collect();
-        let data: Vec<f64> = (0..nx * ny).map(|i| i as f64).collect();
+        let x_axis: Vec<f64> = (0..nx).map(f64::from).collect();
+        let y_axis: Vec<f64> = (0..ny).map(f64::from).collect();
+        let data: Vec<f64> = (0..nx * ny).map(f64::from).collect();
         let lut = Lut2D::new(x_axis, y_axis, data).expect("valid LUT");
 
         for i in 0..10000 {
-            let x = (i % 49) as f64 + 0.5;
-            let y = ((i / 49) % 49) as f64 + 0.5;
+            let x = f64::from(i % 49) + 0.5;
+            let y = f64::from((i / 49) % 49) + 0.5;
             let _ = lut.lookup(x, y);
         }
     }
+
+    #[test]
+    fn test_min_axis_strides() {
+        let lut = create_test_lut();
+        assert!((lut.min_x_stride() - 1.0).abs() < 1e-12);
+        assert!((lut.min_y_stride() - 1.0).abs() < 1e-12);
+
+        let result = Lut2D::new(vec![0.0, 1.0], vec![1e-300, 1e-300 + 1e-316], vec![0.0; 4]);
+        assert_eq!(
+            result.err(),
+            Some(LutError::UnsortedAxis {
+                axis: "Y",
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_single_point_axes() {
+        let row = Lut2D::new(vec![0.0, 2.0], vec![5.0], vec![10.0, 30.0]).expect("valid LUT");
+        assert!((row.lookup(1.0, -100.0) - 20.0).abs() < 1e-12);
+        assert!((row.lookup(3.0, 100.0) - 30.0).abs() < 1e-12);
+
+        let point = Lut2D::new(vec![1.0], vec![5.0], vec![7.0]).expect("valid LUT");
+        assert!((point.lookup(0.0, 0.0) - 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalize_and_scale_data() {
+        let lut = create_test_lut();
+        let (normalized, factor) = lut.normalize_data();
+
+        assert!((factor - 120.0).abs() < 1e-12);
+        assert!((normalized.data_max() - 1.0).abs() < 1e-12);
+        assert!((normalized.lookup(0.5, 0.5) * factor - lut.lookup(0.5, 0.5)).abs() < 1e-10);
+
+        let restored = normalized.scale_data(factor).expect("finite");
+        for (a, b) in restored.data().iter().zip(lut.data()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+
+        assert_eq!(
+            lut.scale_data(f64::NAN).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+        assert_eq!(
+            lut.scale_data(f64::MAX).err(),
+            Some(LutError::InfInData { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_map_data() {
+        let lut = create_test_lut();
+        let mapped = lut.map_data(|v| v * 1.35582).expect("finite");
+        assert_eq!(mapped.x_axis(), lut.x_axis());
+        assert_eq!(mapped.y_axis(), lut.y_axis());
+        for (a, b) in mapped.data().iter().zip(lut.data()) {
+            assert!((a - b * 1.35582).abs() < 1e-8);
+        }
+        assert_eq!(lut.map_data(|v| v).expect("finite").data(), lut.data());
+        assert_eq!(
+            lut.map_data(|v| v / 0.0).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+
+        let single = Lut2D::new(vec![0.0], vec![0.0], vec![3.0]).expect("valid LUT");
+        assert_eq!(single.map_data(|v| -v).expect("finite").data(), &[-3.0]);
+    }
+
+    #[test]
+    fn test_slices() {
+        // f(x, y) = x + y
+        let x_axis = vec![0.0, 1.0, 2.5, 4.0];
+        let y_axis = vec![0.0, 2.0, 5.0];
+        let data = y_axis
+            .iter()
+            .flat_map(|&y| x_axis.iter().map(move |&x| x + y))
+            .collect();
+        let lut = Lut2D::new(x_axis, y_axis, data).expect("valid LUT");
+
+        let slice = lut.slice_at_x(2.0);
+        assert_eq!(slice.x_axis(), lut.y_axis());
+        assert!((slice.lookup(3.0) - 5.0).abs() < 1e-12);
+        for &y in lut.y_axis() {
+            assert!((slice.lookup(y) - (2.0 + y)).abs() < 1e-12);
+        }
+
+        let slice = lut.slice_at_y(1.0);
+        assert_eq!(slice.x_axis(), lut.x_axis());
+        assert!((slice.lookup(3.0) - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let lut = create_test_lut();
+        let t = lut.transpose();
+
+        assert_eq!(t.x_axis(), lut.y_axis());
+        assert_eq!(t.y_axis(), lut.x_axis());
+        assert_eq!(t.data(), &[0.0, 100.0, 10.0, 110.0, 20.0, 120.0]);
+
+        let points = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 1.0),
+            (0.5, 0.5),
+            (1.5, 0.25),
+            (0.25, 1.0),
+            (2.0, 0.0),
+            (1.0, 1.0),
+            (0.75, 0.75),
+            (1.9, 0.1),
+        ];
+        for (x, y) in points {
+            assert!((lut.lookup(x, y) - t.lookup(y, x)).abs() < 1e-12);
+        }
+
+        let back = t.transpose();
+        assert_eq!(back.x_axis(), lut.x_axis());
+        assert_eq!(back.y_axis(), lut.y_axis());
+        assert_eq!(back.data(), lut.data());
+    }
+
+    #[test]
+    fn test_row_and_col() {
+        let lut = create_test_lut();
+
+        let row = lut.row(0).expect("in range");
+        assert_eq!(row.x_axis(), lut.x_axis());
+        assert_eq!(row.data(), &lut.data()[..3]);
+        for x in [0.0, 0.5, 1.0, 1.75, 2.0] {
+            assert!((row.lookup(x) - lut.lookup(x, 0.0)).abs() < 1e-12);
+        }
+
+        let col = lut.col(1).expect("in range");
+        assert_eq!(col.x_axis(), lut.y_axis());
+        assert_eq!(col.len(), 2);
+        for y in [0.0, 0.3, 1.0] {
+            assert!((col.lookup(y) - lut.lookup(1.0, y)).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            lut.row(2).err(),
+            Some(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            lut.col(3).err(),
+            Some(LutError::DimensionMismatch {
+                expected: 3,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_extremes() {
+        let peak = Lut2D::new(
+            vec![0.0, 1.0, 2.0],
+            vec![10.0, 20.0, 30.0],
+            vec![1.0, 2.0, 1.0, 2.0, 9.0, 2.0, 1.0, 2.0, 0.5],
+        )
+        .expect("valid LUT");
+        let (x, y, value) = peak.find_maximum();
+        assert_eq!((x, y), (1.0, 20.0));
+        assert!((value - 9.0).abs() < 1e-12);
+        assert!((peak.lookup(x, y) - value).abs() < 1e-12);
+
+        let (x, y, value) = peak.find_minimum();
+        assert_eq!((x, y), (2.0, 30.0));
+        assert!((peak.lookup(x, y) - value).abs() < 1e-12);
+
+        let flat = Lut2D::new(vec![0.0, 1.0], vec![5.0, 6.0], vec![3.0; 4]).expect("valid LUT");
+        assert_eq!(flat.find_maximum(), (0.0, 5.0, 3.0));
+        assert_eq!(flat.find_minimum(), (0.0, 5.0, 3.0));
+    }
+
+    #[test]
+    fn test_cell_coefficients() {
+        let lut = create_test_lut();
+        let eval = |c: [f64; 4], tx: f64, ty: f64| c[0] + c[1] * tx + c[2] * ty + c[3] * tx * ty;
+
+        for xi in 0..2 {
+            let coeffs = lut.cell_coefficients(xi, 0).expect("in range");
+            let (x0, x1) = (lut.x_axis()[xi], lut.x_axis()[xi + 1]);
+            let (y0, y1) = (lut.y_axis()[0], lut.y_axis()[1]);
+            for (tx, x) in [(0.0, x0), (1.0, x1), (0.25, 0.75f64.mul_add(x0, 0.25 * x1))] {
+                for (ty, y) in [(0.0, y0), (1.0, y1), (0.6, 0.4f64.mul_add(y0, 0.6 * y1))] {
+                    assert!((eval(coeffs, tx, ty) - lut.lookup(x, y)).abs() < 1e-12);
+                }
+            }
+        }
+
+        assert_eq!(
+            lut.cell_coefficients(2, 0).err(),
+            Some(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            lut.cell_coefficients(0, 1).err(),
+            Some(LutError::DimensionMismatch {
+                expected: 1,
+                actual: 1
+            })
+        );
+    }
 }
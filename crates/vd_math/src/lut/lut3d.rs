@@ -2,7 +2,7 @@
 
 use alloc::vec::Vec;
 
-use super::{find_interval, lerp, validate_axis, LutError};
+use super::{find_bracket, lerp, min_axis_stride, validate_axis, validate_data, Lut2D, LutError};
 
 /// 3D lookup table for w = f(x, y, z) interpolation.
 ///
@@ -48,7 +48,8 @@ impl Lut3D {
     ///
     /// # Errors
     ///
-    /// Returns `LutError` if any axis is empty, unsorted, or dimensions don't match.
+    /// Returns `LutError` if any axis is empty, unsorted, or dimensions don't match,
+    /// or if `data` contains NaN or infinite values.
     pub fn new(
         x_axis: Vec<f64>,
         y_axis: Vec<f64>,
@@ -66,6 +67,7 @@ impl Lut3D {
                 actual: data.len(),
             });
         }
+        validate_data(&data)?;
 
         Ok(Self {
             x_axis,
@@ -75,17 +77,75 @@ impl Lut3D {
         })
     }
 
+    /// Creates a 3D lookup table from nested data indexed as
+    /// `nested[zi][yi][xi]`.
+    ///
+    /// The nesting is flattened into the linearized order used by
+    /// [`Lut3D::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_math::lut::Lut3D;
+    ///
+    /// let lut = Lut3D::from_nested(
+    ///     vec![0.0, 1.0],
+    ///     vec![0.0, 1.0],
+    ///     vec![0.0, 1.0],
+    ///     vec![
+    ///         vec![vec![0.0, 1.0], vec![10.0, 11.0]],   // z = 0
+    ///         vec![vec![5.0, 6.0], vec![15.0, 16.0]],   // z = 1
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// assert!((lut.lookup(1.0, 1.0, 1.0) - 16.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::DimensionMismatch` if the nesting does not match
+    /// the axis lengths, with `expected` set to the axis length and `actual`
+    /// to the first mismatching length. Otherwise returns the same errors as
+    /// [`Lut3D::new`].
+    pub fn from_nested(
+        x_axis: Vec<f64>,
+        y_axis: Vec<f64>,
+        z_axis: Vec<f64>,
+        nested: Vec<Vec<Vec<f64>>>,
+    ) -> Result<Self, LutError> {
+        let mismatch = |expected: usize, actual: usize| {
+            (expected != actual).then_some(LutError::DimensionMismatch { expected, actual })
+        };
+        if let Some(err) = mismatch(z_axis.len(), nested.len()) {
+            return Err(err);
+        }
+        for plane in &nested {
+            if let Some(err) = mismatch(y_axis.len(), plane.len()) {
+                return Err(err);
+            }
+            for row in plane {
+                if let Some(err) = mismatch(x_axis.len(), row.len()) {
+                    return Err(err);
+                }
+            }
+        }
+
+        let data = nested.into_iter().flatten().flatten().collect();
+        Self::new(x_axis, y_axis, z_axis, data)
+    }
+
     /// Looks up and interpolates a value at the given (x, y, z) coordinates.
     ///
     /// Uses trilinear interpolation between adjacent points (8 corners of a cube).
-    /// Values outside the axis ranges are clamped to boundary values.
+    /// Values outside the axis ranges are clamped to boundary values, and a
+    /// single-point axis makes the table constant along it.
     #[inline]
     #[must_use]
     #[allow(clippy::similar_names)]
     pub fn lookup(&self, x: f64, y: f64, z: f64) -> f64 {
-        let (xi, tx) = find_interval(&self.x_axis, x);
-        let (yi, ty) = find_interval(&self.y_axis, y);
-        let (zi, tz) = find_interval(&self.z_axis, z);
+        let (x0, x1, tx) = find_bracket(&self.x_axis, x);
+        let (y0, y1, ty) = find_bracket(&self.y_axis, y);
+        let (z0, z1, tz) = find_bracket(&self.z_axis, z);
 
         let nx = self.x_axis.len();
         let nxy = nx * self.y_axis.len();
@@ -93,14 +153,14 @@ impl Lut3D {
         // Get the eight corner values of the cube
         let idx = |ix: usize, iy: usize, iz: usize| iz * nxy + iy * nx + ix;
 
-        let c000 = self.data[idx(xi, yi, zi)];
-        let c100 = self.data[idx(xi + 1, yi, zi)];
-        let c010 = self.data[idx(xi, yi + 1, zi)];
-        let c110 = self.data[idx(xi + 1, yi + 1, zi)];
-        let c001 = self.data[idx(xi, yi, zi + 1)];
-        let c101 = self.data[idx(xi + 1, yi, zi + 1)];
-        let c011 = self.data[idx(xi, yi + 1, zi + 1)];
-        let c111 = self.data[idx(xi + 1, yi + 1, zi + 1)];
+        let c000 = self.data[idx(x0, y0, z0)];
+        let c100 = self.data[idx(x1, y0, z0)];
+        let c010 = self.data[idx(x0, y1, z0)];
+        let c110 = self.data[idx(x1, y1, z0)];
+        let c001 = self.data[idx(x0, y0, z1)];
+        let c101 = self.data[idx(x1, y0, z1)];
+        let c011 = self.data[idx(x0, y1, z1)];
+        let c111 = self.data[idx(x1, y1, z1)];
 
         // Trilinear interpolation: X -> Y -> Z
         let c00 = lerp(c000, c100, tx);
@@ -114,6 +174,117 @@ impl Lut3D {
         lerp(c0, c1, tz)
     }
 
+    /// Returns the 2D cross-section `(y, z) -> f(x, y, z)` at a fixed `x`.
+    ///
+    /// The result's X axis is this table's Y axis and its Y axis is this
+    /// table's Z axis.
+    #[must_use]
+    pub fn slice_at_x(&self, x: f64) -> Lut2D {
+        let data = self
+            .z_axis
+            .iter()
+            .flat_map(|&z| self.y_axis.iter().map(move |&y| self.lookup(x, y, z)))
+            .collect();
+        Lut2D::from_validated_parts(self.y_axis.clone(), self.z_axis.clone(), data)
+    }
+
+    /// Returns the 2D cross-section `(x, z) -> f(x, y, z)` at a fixed `y`.
+    ///
+    /// The result's X axis is this table's X axis and its Y axis is this
+    /// table's Z axis.
+    #[must_use]
+    pub fn slice_at_y(&self, y: f64) -> Lut2D {
+        let data = self
+            .z_axis
+            .iter()
+            .flat_map(|&z| self.x_axis.iter().map(move |&x| self.lookup(x, y, z)))
+            .collect();
+        Lut2D::from_validated_parts(self.x_axis.clone(), self.z_axis.clone(), data)
+    }
+
+    /// Returns the 2D cross-section `(x, y) -> f(x, y, z)` at a fixed `z`.
+    ///
+    /// The result keeps this table's X and Y axes.
+    #[must_use]
+    pub fn slice_at_z(&self, z: f64) -> Lut2D {
+        let data = self
+            .y_axis
+            .iter()
+            .flat_map(|&y| self.x_axis.iter().map(move |&x| self.lookup(x, y, z)))
+            .collect();
+        Lut2D::from_validated_parts(self.x_axis.clone(), self.y_axis.clone(), data)
+    }
+
+    /// Returns the largest data value, ignoring NaN.
+    #[must_use]
+    pub fn data_max(&self) -> f64 {
+        self.data
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, libm::fmax)
+    }
+
+    /// Returns a copy with the data divided by [`Lut3D::data_max`], together
+    /// with that divisor.
+    ///
+    /// For tables with a positive maximum the peak becomes exactly 1.0;
+    /// [`Lut3D::scale_data`] with the returned factor restores the original.
+    /// If the maximum is zero, or so small that dividing by it overflows,
+    /// the table is returned unchanged with a factor of 1.0.
+    #[must_use]
+    pub fn normalize_data(&self) -> (Self, f64) {
+        let max = self.data_max();
+        if max == 0.0 {
+            return (self.clone(), 1.0);
+        }
+        self.map_data(|v| v / max)
+            .map_or_else(|_| (self.clone(), 1.0), |normalized| (normalized, max))
+    }
+
+    /// Returns a copy with every data value multiplied by `factor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if a scaled
+    /// value is not finite, e.g. for a NaN `factor` or on overflow.
+    pub fn scale_data(&self, factor: f64) -> Result<Self, LutError> {
+        self.map_data(|v| v * factor)
+    }
+
+    /// Returns a copy with `f` applied to every data value, e.g. for a unit
+    /// conversion. The axes are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LutError::NaNInData` or `LutError::InfInData` if `f`
+    /// produces a value that [`Lut3D::new`] would reject.
+    pub fn map_data<F: Fn(f64) -> f64>(&self, f: F) -> Result<Self, LutError> {
+        let data: Vec<f64> = self.data.iter().map(|&v| f(v)).collect();
+        validate_data(&data)?;
+        Ok(Self {
+            data,
+            ..self.clone()
+        })
+    }
+
+    /// Returns the smallest gap between adjacent X breakpoints.
+    #[must_use]
+    pub fn min_x_stride(&self) -> f64 {
+        min_axis_stride(&self.x_axis)
+    }
+
+    /// Returns the smallest gap between adjacent Y breakpoints.
+    #[must_use]
+    pub fn min_y_stride(&self) -> f64 {
+        min_axis_stride(&self.y_axis)
+    }
+
+    /// Returns the smallest gap between adjacent Z breakpoints.
+    #[must_use]
+    pub fn min_z_stride(&self) -> f64 {
+        min_axis_stride(&self.z_axis)
+    }
+
     /// Returns the X axis values.
     #[must_use]
     pub fn x_axis(&self) -> &[f64] {
@@ -227,15 +398,155 @@ mod tests {
     #[test]
     fn test_many_lookups() {
         let n = 10;
-        let axis: Vec<f64> = (0..n).map(|i| i as f64).collect();
-        let data: Vec<f64> = (0..n * n * n).map(|i| i as f64).collect();
+        let axis: Vec<f64> = (0..n).map(f64::from).collect();
+        let data: Vec<f64> = (0..n * n * n).map(f64::from).collect();
         let lut = Lut3D::new(axis.clone(), axis.clone(), axis, data).expect("valid LUT");
 
         for i in 0..10000 {
-            let x = (i % 9) as f64 + 0.5;
-            let y = ((i / 9) % 9) as f64 + 0.5;
-            let z = ((i / 81) % 9) as f64 + 0.5;
+            let x = f64::from(i % 9) + 0.5;
+            let y = f64::from((i / 9) % 9) + 0.5;
+            let z = f64::from((i / 81) % 9) + 0.5;
             let _ = lut.lookup(x, y, z);
         }
     }
+
+    #[test]
+    fn test_single_point_axes() {
+        let plane = Lut3D::new(
+            vec![0.0, 2.0],
+            vec![0.0, 1.0],
+            vec![5.0],
+            vec![0.0, 20.0, 10.0, 30.0],
+        )
+        .expect("valid LUT");
+        assert!((plane.lookup(1.0, 0.5, -100.0) - 15.0).abs() < 1e-12);
+
+        let point = Lut3D::new(vec![1.0], vec![2.0], vec![3.0], vec![7.0]).expect("valid LUT");
+        assert!((point.lookup(0.0, 0.0, 0.0) - 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalize_and_scale_data() {
+        let lut = create_test_lut();
+        let (normalized, factor) = lut.normalize_data();
+
+        assert!((factor - lut.data_max()).abs() < 1e-12);
+        assert!((normalized.data_max() - 1.0).abs() < 1e-12);
+
+        let restored = normalized.scale_data(factor).expect("finite");
+        for (a, b) in restored.data().iter().zip(lut.data()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+
+        assert_eq!(
+            lut.scale_data(f64::NAN).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+        assert_eq!(
+            lut.scale_data(f64::MAX).err(),
+            Some(LutError::InfInData { index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_map_data() {
+        let lut = create_test_lut();
+        let mapped = lut.map_data(|v| v * 1.35582).expect("finite");
+        assert_eq!(mapped.z_axis(), lut.z_axis());
+        for (a, b) in mapped.data().iter().zip(lut.data()) {
+            assert!((a - b * 1.35582).abs() < 1e-8);
+        }
+        assert_eq!(lut.map_data(|v| v).expect("finite").data(), lut.data());
+        assert_eq!(
+            lut.map_data(|v| v / 0.0).err(),
+            Some(LutError::NaNInData { index: 0 })
+        );
+
+        let single = Lut3D::new(vec![0.0], vec![0.0], vec![0.0], vec![3.0]).expect("valid LUT");
+        assert_eq!(single.map_data(|v| -v).expect("finite").data(), &[-3.0]);
+    }
+
+    #[test]
+    fn test_slices_match_lookup() {
+        let x_axis = vec![0.0, 1.0, 3.0];
+        let y_axis = vec![-1.0, 0.0, 2.0, 4.0];
+        let z_axis = vec![0.0, 1000.0];
+        let mut data = Vec::new();
+        for &z in &z_axis {
+            for &y in &y_axis {
+                for &x in &x_axis {
+                    data.push(x * x + 3.0 * y - z * 0.001 * x * y);
+                }
+            }
+        }
+        let lut = Lut3D::new(x_axis, y_axis, z_axis, data).expect("valid LUT");
+
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut uniform = |lo: f64, hi: f64| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            lo + (hi - lo) * ((state >> 11) as f64 / (1u64 << 53) as f64)
+        };
+
+        for _ in 0..20 {
+            let (x, y, z) = (uniform(0.0, 3.0), uniform(-1.0, 4.0), uniform(0.0, 1000.0));
+            let full = lut.lookup(x, y, z);
+            assert!((lut.slice_at_z(z).lookup(x, y) - full).abs() < 1e-10);
+            assert!((lut.slice_at_y(y).lookup(x, z) - full).abs() < 1e-10);
+            assert!((lut.slice_at_x(x).lookup(y, z) - full).abs() < 1e-10);
+        }
+
+        let slice = lut.slice_at_x(1.0);
+        assert_eq!(slice.x_axis(), lut.y_axis());
+        assert_eq!(slice.y_axis(), lut.z_axis());
+    }
+
+    #[test]
+    fn test_from_nested() {
+        let flat = create_test_lut();
+        let nested = Lut3D::from_nested(
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![
+                vec![vec![0.0, 1.0], vec![10.0, 11.0]],
+                vec![vec![100.0, 101.0], vec![110.0, 111.0]],
+            ],
+        )
+        .expect("valid LUT");
+        assert_eq!(nested.data(), flat.data());
+
+        let corners = [0.0, 1.0];
+        for &x in &corners {
+            for &y in &corners {
+                for &z in &corners {
+                    assert!((nested.lookup(x, y, z) - flat.lookup(x, y, z)).abs() < 1e-12);
+                }
+            }
+        }
+        assert!((nested.lookup(0.5, 0.5, 0.5) - flat.lookup(0.5, 0.5, 0.5)).abs() < 1e-12);
+
+        let short_row = Lut3D::from_nested(
+            vec![0.0, 1.0],
+            vec![0.0],
+            vec![0.0, 1.0],
+            vec![vec![vec![0.0, 1.0]], vec![vec![2.0]]],
+        );
+        assert_eq!(
+            short_row.err(),
+            Some(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        let missing_plane = Lut3D::from_nested(vec![0.0], vec![0.0], vec![0.0, 1.0], vec![]);
+        assert_eq!(
+            missing_plane.err(),
+            Some(LutError::DimensionMismatch {
+                expected: 2,
+                actual: 0
+            })
+        );
+    }
 }
@@ -0,0 +1,151 @@
+//! Literal constructors for lookup tables.
+//!
+//! These macros are shorthand for calling the LUT constructors with array
+//! literals. They panic if the table is invalid, which makes them suited to
+//! tests and hard-coded maps; use the constructors directly to handle
+//! errors, or [`lut1d_static!`](crate::lut1d_static) to validate at compile
+//! time.
+
+/// Builds a [`Lut1D`](crate::lut::Lut1D) from `x => y` pairs.
+///
+/// # Panics
+///
+/// Panics if the X values are not strictly ascending.
+///
+/// # Example
+///
+/// ```
+/// let torque = vd_math::lut1d![0.0 => 0.0, 1000.0 => 150.0, 2000.0 => 280.0];
+/// assert!((torque.lookup(1500.0) - 215.0).abs() < 1e-10);
+/// ```
+#[macro_export]
+macro_rules! lut1d {
+    ($($x:expr => $y:expr),+ $(,)?) => {
+        $crate::lut::Lut1D::new(
+            ::core::convert::From::from([$($x),+]),
+            ::core::convert::From::from([$($y),+]),
+        )
+        .expect("lut1d!: invalid lookup table")
+    };
+}
+
+/// Builds a [`Lut2D`](crate::lut::Lut2D) from axis and row-major data
+/// literals.
+///
+/// # Panics
+///
+/// Panics if an axis is not strictly ascending or the data length is not
+/// `x.len() * y.len()`.
+///
+/// # Example
+///
+/// ```
+/// let grip = vd_math::lut2d! {
+///     x: [0.0, 5.0, 10.0],
+///     y: [0.0, 0.1],
+///     data: [
+///         0.0, 0.8, 1.0,
+///         0.5, 1.0, 0.9,
+///     ],
+/// };
+/// assert!((grip.lookup(5.0, 0.1) - 1.0).abs() < 1e-10);
+/// ```
+#[macro_export]
+macro_rules! lut2d {
+    (x: [$($x:expr),+ $(,)?], y: [$($y:expr),+ $(,)?], data: [$($v:expr),+ $(,)?] $(,)?) => {
+        $crate::lut::Lut2D::new(
+            ::core::convert::From::from([$($x),+]),
+            ::core::convert::From::from([$($y),+]),
+            ::core::convert::From::from([$($v),+]),
+        )
+        .expect("lut2d!: invalid lookup table")
+    };
+}
+
+/// Builds a [`Lut3D`](crate::lut::Lut3D) from axis and linearized data
+/// literals (`data[zi * nx * ny + yi * nx + xi]`).
+///
+/// # Panics
+///
+/// Panics if an axis is not strictly ascending or the data length is not
+/// `x.len() * y.len() * z.len()`.
+///
+/// # Example
+///
+/// ```
+/// let cd = vd_math::lut3d! {
+///     x: [0.0, 50.0],
+///     y: [0.0, 10.0],
+///     z: [0.0, 1000.0],
+///     data: [0.30, 0.35, 0.40, 0.45, 0.28, 0.33, 0.38, 0.43],
+/// };
+/// assert!((cd.lookup(50.0, 10.0, 1000.0) - 0.43).abs() < 1e-10);
+/// ```
+#[macro_export]
+macro_rules! lut3d {
+    (
+        x: [$($x:expr),+ $(,)?],
+        y: [$($y:expr),+ $(,)?],
+        z: [$($z:expr),+ $(,)?],
+        data: [$($v:expr),+ $(,)?] $(,)?
+    ) => {
+        $crate::lut::Lut3D::new(
+            ::core::convert::From::from([$($x),+]),
+            ::core::convert::From::from([$($y),+]),
+            ::core::convert::From::from([$($z),+]),
+            ::core::convert::From::from([$($v),+]),
+        )
+        .expect("lut3d!: invalid lookup table")
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_lut1d_macro() {
+        let lut = crate::lut1d![0.0 => 0.0, 1000.0 => 150.0, 2000.0 => 280.0,];
+        for (x, y) in [(0.0, 0.0), (1000.0, 150.0), (2000.0, 280.0)] {
+            assert!((lut.lookup(x) - y).abs() < 1e-12);
+        }
+        assert_eq!(lut.len(), 3);
+    }
+
+    #[test]
+    fn test_lut2d_macro() {
+        let lut = crate::lut2d! {
+            x: [0.0, 1.0, 2.0],
+            y: [0.0, 1.0],
+            data: [0.0, 10.0, 20.0, 100.0, 110.0, 120.0],
+        };
+        for (yi, y) in [0.0, 1.0].into_iter().enumerate() {
+            for (xi, x) in [0.0, 1.0, 2.0].into_iter().enumerate() {
+                let expected = lut.data()[yi * 3 + xi];
+                assert!((lut.lookup(x, y) - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lut3d_macro() {
+        let lut = crate::lut3d! {
+            x: [0.0, 1.0],
+            y: [0.0, 1.0],
+            z: [0.0, 1.0],
+            data: [0.0, 1.0, 10.0, 11.0, 100.0, 101.0, 110.0, 111.0],
+        };
+        for z in [0.0, 1.0] {
+            for y in [0.0, 1.0] {
+                for x in [0.0, 1.0] {
+                    let expected = x + 10.0 * y + 100.0 * z;
+                    assert!((lut.lookup(x, y, z) - expected).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lut1d!: invalid lookup table")]
+    fn test_lut1d_macro_panics_on_unsorted() {
+        let _ = crate::lut1d![1.0 => 0.0, 0.0 => 1.0];
+    }
+}
@@ -7,15 +7,35 @@
 //! All lookup operations use O(log N) binary search and are designed
 //! for real-time performance with no heap allocations during lookup.
 
+mod binary;
+mod builder;
+mod csv;
+mod display;
 mod error;
+mod format;
 mod interp;
 mod lut1d;
+mod lut1d_f32;
 mod lut2d;
 mod lut3d;
+mod macros;
+mod nd;
 
-pub use error::LutError;
-pub use lut1d::Lut1D;
+pub use builder::{Lut1DBuilder, Lut2DBuilder, Lut3DBuilder};
+pub use error::{LutBinaryError, LutError, ParseError};
+pub use interp::{
+    interpolate_1d, interpolate_2d, interpolate_3d, validate_axis_const, validate_data_const,
+};
+pub use lut1d::{
+    calibrate_two_point, HoldState, HysteresisState, Lut1D, MonotoneSegment, Monotonicity,
+};
+pub use lut1d_f32::{Lut1DCompressed, Lut1Df32};
 pub use lut2d::Lut2D;
 pub use lut3d::Lut3D;
+pub use nd::LutNd;
 
-use interp::{find_interval, lerp, validate_axis};
+use interp::{
+    find_bracket, find_interval, find_interval_from_hint, find_interval_hysteresis,
+    is_ascending_step, lerp, min_axis_stride, validate_axis, validate_data, values_match,
+    wrap_periodic,
+};
@@ -0,0 +1,118 @@
+//! Behaviour shared by lookup tables of every dimensionality.
+
+use super::{Lut1D, Lut2D, Lut3D};
+
+/// Common read-only view of [`Lut1D`], [`Lut2D`] and [`Lut3D`].
+///
+/// Only [`LutNd::data`] must be implemented; the summary statistics are
+/// computed from it. The minimum and maximum ignore NaN like the tables'
+/// own `data_max`, while any NaN makes the mean NaN.
+///
+/// # Example
+///
+/// ```
+/// use vd_math::lut::{Lut1D, Lut2D, LutNd};
+///
+/// fn data_range<L: LutNd>(lut: &L) -> f64 {
+///     lut.data_max() - lut.data_min()
+/// }
+///
+/// let curve = Lut1D::new(vec![0.0, 1.0], vec![2.0, 5.0]).unwrap();
+/// let map = Lut2D::new(vec![0.0, 1.0], vec![0.0, 1.0], vec![1.0, 2.0, 3.0, 9.0]).unwrap();
+/// assert!((data_range(&curve) - 3.0).abs() < 1e-12);
+/// assert!((data_range(&map) - 8.0).abs() < 1e-12);
+/// ```
+pub trait LutNd {
+    /// Returns the stored data values in the table's linearized order.
+    fn data(&self) -> &[f64];
+
+    /// Returns the number of grid points.
+    fn total_points(&self) -> usize {
+        self.data().len()
+    }
+
+    /// Returns the smallest data value, ignoring NaN.
+    fn data_min(&self) -> f64 {
+        self.data().iter().copied().fold(f64::INFINITY, libm::fmin)
+    }
+
+    /// Returns the largest data value, ignoring NaN.
+    fn data_max(&self) -> f64 {
+        self.data()
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, libm::fmax)
+    }
+
+    /// Returns the arithmetic mean of the data values over grid points.
+    fn data_mean(&self) -> f64 {
+        self.data().iter().sum::<f64>() / self.total_points() as f64
+    }
+}
+
+impl LutNd for Lut1D {
+    #[inline]
+    fn data(&self) -> &[f64] {
+        Self::data(self)
+    }
+}
+
+impl LutNd for Lut2D {
+    #[inline]
+    fn data(&self) -> &[f64] {
+        Self::data(self)
+    }
+}
+
+impl LutNd for Lut3D {
+    #[inline]
+    fn data(&self) -> &[f64] {
+        Self::data(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// `(points, min, max, mean)` of any table.
+    fn summarise_lut<L: LutNd>(lut: &L) -> (usize, f64, f64, f64) {
+        (
+            lut.total_points(),
+            lut.data_min(),
+            lut.data_max(),
+            lut.data_mean(),
+        )
+    }
+
+    fn assert_summary(actual: (usize, f64, f64, f64), expected: (usize, f64, f64, f64)) {
+        assert_eq!(actual.0, expected.0);
+        assert!((actual.1 - expected.1).abs() < 1e-12, "{actual:?}");
+        assert!((actual.2 - expected.2).abs() < 1e-12, "{actual:?}");
+        assert!((actual.3 - expected.3).abs() < 1e-12, "{actual:?}");
+    }
+
+    #[test]
+    fn test_summarise_every_dimension() {
+        let curve = Lut1D::new(vec![0.0, 1.0, 2.0], vec![4.0, -2.0, 7.0]).expect("valid LUT");
+        assert_summary(summarise_lut(&curve), (3, -2.0, 7.0, 3.0));
+
+        let map = Lut2D::new(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        )
+        .expect("valid LUT");
+        assert_summary(summarise_lut(&map), (6, 1.0, 6.0, 3.5));
+
+        let cube = Lut3D::new(
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+        )
+        .expect("valid LUT");
+        assert_summary(summarise_lut(&cube), (8, 0.0, 7.0, 3.5));
+    }
+}
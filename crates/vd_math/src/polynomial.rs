@@ -0,0 +1,139 @@
+//! Polynomial evaluation and manipulation.
+//!
+//! Polynomials are coefficient slices in ascending degree order, so
+//! `[c0, c1, c2]` is `c0 + c1 * x + c2 * x^2`. This matches the output of
+//! [`Lut1D::fit_polynomial`](crate::lut::Lut1D::fit_polynomial). An empty
+//! slice is the zero polynomial.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Evaluates a polynomial at `x` using Horner's method.
+///
+/// # Example
+///
+/// ```
+/// use vd_math::polynomial::evaluate;
+///
+/// // 1 + 2x + 3x^2 at x = 2
+/// assert!((evaluate(&[1.0, 2.0, 3.0], 2.0) - 17.0).abs() < 1e-12);
+/// ```
+#[inline]
+#[must_use]
+pub fn evaluate(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// Evaluates the first derivative of a polynomial at `x`.
+///
+/// Uses Horner's method on the differentiated coefficients without
+/// allocating them.
+#[inline]
+#[must_use]
+pub fn evaluate_derivative(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(0.0, |acc, (k, &c)| acc * x + k as f64 * c)
+}
+
+/// Returns the coefficients of the first derivative of a polynomial.
+///
+/// Constant and zero polynomials yield the zero polynomial (an empty vector).
+#[must_use]
+pub fn derivative(coefficients: &[f64]) -> Vec<f64> {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, &c)| k as f64 * c)
+        .collect()
+}
+
+/// Returns the coefficients of the composition `p(q(x))`.
+///
+/// The result has degree `deg(p) * deg(q)`. Built with Horner's method,
+/// multiplying by `q` once per coefficient of `p`.
+#[must_use]
+pub fn compose(p: &[f64], q: &[f64]) -> Vec<f64> {
+    let mut result: Vec<f64> = Vec::new();
+    for &c in p.iter().rev() {
+        result = multiply(&result, q);
+        if result.is_empty() {
+            result.push(c);
+        } else {
+            result[0] += c;
+        }
+    }
+    result
+}
+
+/// Returns the coefficients of the product `a(x) * b(x)`.
+fn multiply(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0.0; a.len() + b.len() - 1];
+    for (i, &ai) in a.iter().enumerate() {
+        for (j, &bj) in b.iter().enumerate() {
+            product[i + j] += ai * bj;
+        }
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_coeffs(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-12, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert!((evaluate(&[1.0, 2.0, 3.0], 2.0) - 17.0).abs() < 1e-12);
+        assert!((evaluate(&[4.0], 100.0) - 4.0).abs() < 1e-12);
+        assert!(evaluate(&[], 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_derivative() {
+        assert_coeffs(&derivative(&[0.0, 0.0, 1.0]), &[0.0, 2.0]);
+        assert_coeffs(&derivative(&[5.0, -1.0, 0.5, 2.0]), &[-1.0, 1.0, 6.0]);
+        assert!(derivative(&[7.0]).is_empty());
+
+        let p = [5.0, -1.0, 0.5, 2.0];
+        for x in [-2.0, 0.0, 0.75, 3.0] {
+            let expected = evaluate(&derivative(&p), x);
+            assert!((evaluate_derivative(&p, x) - expected).abs() < 1e-12);
+        }
+        assert!(evaluate_derivative(&[7.0], 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_compose() {
+        let p = [1.0, -2.0, 0.5, 3.0];
+        let identity = [0.0, 1.0];
+        assert_coeffs(&compose(&p, &identity), &p);
+        assert_coeffs(&compose(&identity, &p), &p);
+
+        // (1 + x)^2 = 1 + 2x + x^2
+        assert_coeffs(&compose(&[0.0, 0.0, 1.0], &[1.0, 1.0]), &[1.0, 2.0, 1.0]);
+
+        let q = [0.5, -1.0, 2.0];
+        let composed = compose(&p, &q);
+        assert_eq!(composed.len(), 7);
+        for x in [-1.5, 0.0, 0.3, 2.0] {
+            let expected = evaluate(&p, evaluate(&q, x));
+            assert!((evaluate(&composed, x) - expected).abs() < 1e-9);
+        }
+
+        assert!(compose(&[], &q).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+//! Signal analysis over sample windows.
+//!
+//! Post-processing helpers for vibration and ride-comfort analysis.
+//! All functions return `f64::NAN` for empty input.
+
+/// Sums `values` using Kahan compensated summation.
+#[inline]
+fn kahan_sum(values: impl Iterator<Item = f64>) -> f64 {
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for v in values {
+        let y = v - compensation;
+        let t = sum + y;
+        compensation = (t - sum) - y;
+        sum = t;
+    }
+    sum
+}
+
+/// Returns the root-mean-square of `data`.
+///
+/// Uses compensated summation so long windows do not accumulate rounding error.
+#[must_use]
+pub fn rms(data: &[f64]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    let sum_sq = kahan_sum(data.iter().map(|v| v * v));
+    libm::sqrt(sum_sq / data.len() as f64)
+}
+
+/// Returns the largest absolute value in `data`.
+#[must_use]
+pub fn peak_hold(data: &[f64]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    data.iter()
+        .fold(0.0, |peak, v| libm::fmax(peak, libm::fabs(*v)))
+}
+
+/// Returns the RMS of `data` in decibels relative to `reference`:
+/// `20 * log10(rms / reference)`.
+#[must_use]
+pub fn rms_db(data: &[f64], reference: f64) -> f64 {
+    20.0 * libm::log10(rms(data) / reference)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_rms_square_wave() {
+        assert!((rms(&[1.0, -1.0, 1.0, -1.0]) - 1.0).abs() < 1e-12);
+        assert!((rms(&[3.0, 4.0]) - libm::sqrt(12.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rms_long_window_precision() {
+        // Naive summation of 1e6 squares drifts; compensated summation does not
+        let data = vec![0.1; 1_000_000];
+        assert!((rms(&data) - 0.1).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_peak_hold() {
+        assert!((peak_hold(&[-3.0, 1.0, 2.0]) - 3.0).abs() < 1e-12);
+        assert!((peak_hold(&[0.5]) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rms_db() {
+        let data = [2.0, -2.0, 2.0, -2.0];
+        let expected = 20.0 * libm::log10(2.0 / 0.5);
+        assert!((rms_db(&data, 0.5) - expected).abs() < 1e-12);
+        assert!(rms_db(&data, 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(rms(&[]).is_nan());
+        assert!(peak_hold(&[]).is_nan());
+        assert!(rms_db(&[], 1.0).is_nan());
+    }
+}
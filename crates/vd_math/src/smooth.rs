@@ -0,0 +1,124 @@
+//! Smooth transition functions.
+//!
+//! Used for blending between two states without discontinuities, e.g.
+//! clutch engagement, tire warm-up, or boost controller ramping.
+
+/// Normalizes `x` to `[0, 1]` between `edge0` and `edge1`.
+#[inline]
+fn normalized(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let span = edge1 - edge0;
+    if span == 0.0 {
+        return if x < edge0 { 0.0 } else { 1.0 };
+    }
+    let t = (x - edge0) / span;
+    libm::fmax(0.0, libm::fmin(t, 1.0))
+}
+
+/// Cubic Hermite step from 0 at `edge0` to 1 at `edge1`.
+///
+/// The first derivative is zero at both edges. Values outside the edges
+/// are clamped.
+#[inline]
+#[must_use]
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = normalized(edge0, edge1, x);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Quintic step (Ken Perlin's smootherstep) from 0 at `edge0` to 1 at `edge1`.
+///
+/// Both the first and second derivatives are zero at the edges. Values
+/// outside the edges are clamped.
+#[inline]
+#[must_use]
+pub fn smootherstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = normalized(edge0, edge1, x);
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Logistic sigmoid `1 / (1 + e^-x)`.
+#[inline]
+#[must_use]
+pub fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + libm::exp(-x))
+}
+
+/// Hyperbolic tangent transition `tanh(x * sharpness)` in `(-1, 1)`.
+#[inline]
+#[must_use]
+pub fn tanh_normalised(x: f64, sharpness: f64) -> f64 {
+    libm::tanh(x * sharpness)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const N: usize = 1000;
+
+    fn grid(lo: f64, hi: f64) -> impl Iterator<Item = f64> {
+        (0..=N).map(move |i| lo + (hi - lo) * i as f64 / N as f64)
+    }
+
+    fn assert_monotonic(f: impl Fn(f64) -> f64, lo: f64, hi: f64) {
+        let mut prev = f64::NEG_INFINITY;
+        for x in grid(lo, hi) {
+            let y = f(x);
+            assert!(y >= prev, "not monotonic at x = {x}");
+            prev = y;
+        }
+    }
+
+    #[test]
+    fn test_step_boundaries() {
+        for f in [smoothstep, smootherstep] {
+            assert!(f(2.0, 4.0, 2.0).abs() < 1e-12);
+            assert!((f(2.0, 4.0, 4.0) - 1.0).abs() < 1e-12);
+            assert!((f(2.0, 4.0, 3.0) - 0.5).abs() < 1e-12);
+            assert!(f(2.0, 4.0, -10.0).abs() < 1e-12);
+            assert!((f(2.0, 4.0, 10.0) - 1.0).abs() < 1e-12);
+            // Degenerate edges behave like a hard step
+            assert!(f(1.0, 1.0, 0.5).abs() < 1e-12);
+            assert!((f(1.0, 1.0, 1.5) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sigmoid_and_tanh_values() {
+        assert!((sigmoid(0.0) - 0.5).abs() < 1e-12);
+        assert!(sigmoid(-50.0) < 1e-12);
+        assert!((sigmoid(50.0) - 1.0).abs() < 1e-12);
+        assert!((sigmoid(2.0) + sigmoid(-2.0) - 1.0).abs() < 1e-12);
+
+        assert!(tanh_normalised(0.0, 5.0).abs() < 1e-12);
+        assert!((tanh_normalised(1.0, 20.0) - 1.0).abs() < 1e-12);
+        assert!((tanh_normalised(-1.0, 20.0) + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_monotonicity() {
+        assert_monotonic(|x| smoothstep(0.0, 1.0, x), -0.5, 1.5);
+        assert_monotonic(|x| smootherstep(0.0, 1.0, x), -0.5, 1.5);
+        assert_monotonic(sigmoid, -10.0, 10.0);
+        assert_monotonic(|x| tanh_normalised(x, 3.0), -2.0, 2.0);
+    }
+
+    #[test]
+    fn test_smootherstep_derivative_continuity() {
+        let h = 1e-6;
+        let derivative =
+            |x: f64| (smootherstep(0.0, 1.0, x + h) - smootherstep(0.0, 1.0, x - h)) / (2.0 * h);
+
+        // Derivative vanishes at both edges, matching the clamped regions
+        assert!(derivative(0.0).abs() < 1e-6);
+        assert!(derivative(1.0).abs() < 1e-6);
+
+        // No jumps in the derivative across the grid
+        let mut prev = derivative(0.0);
+        for x in grid(0.0, 1.0).skip(1) {
+            let d = derivative(x);
+            assert!((d - prev).abs() < 0.01, "derivative jump at x = {x}");
+            prev = d;
+        }
+    }
+}
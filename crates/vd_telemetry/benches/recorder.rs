@@ -0,0 +1,39 @@
+//! Benchmarks for `MemoryRecorder` logging paths.
+
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use vd_telemetry::{ChannelId, MemoryRecorder, TelemetryProvider};
+
+const CHANNELS: usize = 10;
+
+fn setup() -> (MemoryRecorder, Vec<ChannelId>) {
+    let mut recorder = MemoryRecorder::with_defaults();
+    let ids = (0..CHANNELS)
+        .map(|i| recorder.register_channel(&format!("state.{i}"), ""))
+        .collect();
+    (recorder, ids)
+}
+
+fn bench_state_snapshot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("state_snapshot_10_channels");
+
+    let (mut recorder, ids) = setup();
+    group.bench_function("log", |b| {
+        b.iter(|| {
+            for &id in &ids {
+                recorder.log(id, black_box(1.0));
+            }
+        });
+    });
+
+    let (mut recorder, ids) = setup();
+    group.bench_function("batch_log", |b| {
+        b.iter(|| recorder.batch_log(ids.iter().map(|&id| (id, black_box(1.0)))));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_state_snapshot);
+criterion_main!(benches);
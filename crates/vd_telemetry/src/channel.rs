@@ -2,6 +2,8 @@
 //!
 //! This module defines the types used to identify and store telemetry data.
 
+use core::fmt;
+
 use vd_math::Vec3;
 
 #[cfg(feature = "serde")]
@@ -17,6 +19,11 @@ use serde::{Deserialize, Serialize};
 pub struct ChannelId(pub u32);
 
 impl ChannelId {
+    /// Sentinel ID returned when a channel could not be registered.
+    ///
+    /// Logging to this ID is always a no-op.
+    pub const INVALID: Self = Self(u32::MAX);
+
     /// Creates a new channel ID from a raw index.
     #[inline]
     #[must_use]
@@ -30,6 +37,13 @@ impl ChannelId {
     pub const fn index(self) -> u32 {
         self.0
     }
+
+    /// Returns true if this ID is not [`ChannelId::INVALID`].
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        self.0 != u32::MAX
+    }
 }
 
 /// Value types that can be logged to telemetry channels.
@@ -100,6 +114,26 @@ impl ChannelValue {
             Self::Vector3 { x, .. } => *x,
         }
     }
+
+    /// Returns the boolean value, or `None` for non-boolean variants.
+    #[inline]
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the `(x, y, z)` components, or `None` for non-vector variants.
+    #[inline]
+    #[must_use]
+    pub const fn as_vector3(&self) -> Option<(f64, f64, f64)> {
+        match self {
+            Self::Vector3 { x, y, z } => Some((*x, *y, *z)),
+            _ => None,
+        }
+    }
 }
 
 impl From<f64> for ChannelValue {
@@ -127,6 +161,275 @@ impl From<Vec3> for ChannelValue {
     }
 }
 
+/// Full description of a channel, passed at registration time.
+///
+/// # Example
+///
+/// ```
+/// use vd_telemetry::channel::ChannelDescriptor;
+///
+/// let desc = ChannelDescriptor::new("tire.fl.slip_ratio", "")
+///     .with_description("Longitudinal slip of the front-left tire")
+///     .with_expected_min(-1.0)
+///     .with_expected_max(1.0);
+/// assert_eq!(desc.unit, "");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct ChannelDescriptor<'a> {
+    /// Human-readable name (e.g., `vehicle.speed`).
+    pub name: &'a str,
+    /// Physical unit (e.g., "m/s", "rad", "N").
+    pub unit: &'a str,
+    /// Free-form description of what the channel measures.
+    pub description: &'a str,
+    /// Lowest value expected during normal operation, if known.
+    pub expected_min: Option<f64>,
+    /// Highest value expected during normal operation, if known.
+    pub expected_max: Option<f64>,
+    /// Key-value tags attached to the channel (e.g., `wheel=FL`).
+    pub tags: ChannelTags<'a>,
+}
+
+impl<'a> ChannelDescriptor<'a> {
+    /// Creates a descriptor with an empty description and no expected range.
+    #[inline]
+    #[must_use]
+    pub const fn new(name: &'a str, unit: &'a str) -> Self {
+        Self {
+            name,
+            unit,
+            description: "",
+            expected_min: None,
+            expected_max: None,
+            tags: ChannelTags::new(),
+        }
+    }
+
+    /// Returns the descriptor with the given description.
+    #[inline]
+    #[must_use]
+    pub const fn with_description(mut self, description: &'a str) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Returns the descriptor with the given lowest expected value.
+    #[inline]
+    #[must_use]
+    pub const fn with_expected_min(mut self, min: f64) -> Self {
+        self.expected_min = Some(min);
+        self
+    }
+
+    /// Returns the descriptor with the given highest expected value.
+    #[inline]
+    #[must_use]
+    pub const fn with_expected_max(mut self, max: f64) -> Self {
+        self.expected_max = Some(max);
+        self
+    }
+
+    /// Returns the descriptor with an additional `key=value` tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooManyTags` if the descriptor already holds
+    /// [`MAX_CHANNEL_TAGS`] tags.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_telemetry::channel::ChannelDescriptor;
+    ///
+    /// let desc = ChannelDescriptor::new("tire.fl.temp", "K").with_tag("wheel", "FL")?;
+    /// assert_eq!(desc.tags.as_slice(), &[("wheel", "FL")]);
+    /// # Ok::<(), vd_telemetry::channel::TooManyTags>(())
+    /// ```
+    #[inline]
+    pub fn with_tag(mut self, key: &'a str, value: &'a str) -> Result<Self, TooManyTags> {
+        self.tags.push(key, value)?;
+        Ok(self)
+    }
+}
+
+/// Maximum number of tags a [`ChannelDescriptor`] can carry.
+pub const MAX_CHANNEL_TAGS: usize = 8;
+
+/// Inline list of `(key, value)` tags for a [`ChannelDescriptor`].
+///
+/// Stored without allocation so descriptors stay `Copy` and usable in
+/// `no_std` builds without `alloc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelTags<'a> {
+    /// Tag slots; only the first `len` are populated.
+    entries: [(&'a str, &'a str); MAX_CHANNEL_TAGS],
+    /// Number of populated slots.
+    len: usize,
+}
+
+impl<'a> ChannelTags<'a> {
+    /// Creates an empty tag list.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: [("", ""); MAX_CHANNEL_TAGS],
+            len: 0,
+        }
+    }
+
+    /// Appends a `key=value` tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooManyTags`, leaving the list unchanged, if it already
+    /// holds [`MAX_CHANNEL_TAGS`] tags.
+    #[inline]
+    pub fn push(&mut self, key: &'a str, value: &'a str) -> Result<(), TooManyTags> {
+        let slot = self.entries.get_mut(self.len).ok_or(TooManyTags)?;
+        *slot = (key, value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the tags in insertion order.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[(&'a str, &'a str)] {
+        &self.entries[..self.len]
+    }
+
+    /// Returns the number of tags.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no tags.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Default for ChannelTags<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned when adding a tag beyond [`MAX_CHANNEL_TAGS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyTags;
+
+impl fmt::Display for TooManyTags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "At most {MAX_CHANNEL_TAGS} tags per channel")
+    }
+}
+
+/// A set of related channels registered and logged as a unit.
+///
+/// Useful for per-corner quantities such as the four tire temperatures,
+/// which are always logged together.
+///
+/// # Example
+///
+/// ```
+/// use vd_telemetry::{ChannelGroup, NoOpTelemetry};
+///
+/// let mut telemetry = NoOpTelemetry;
+/// let temps = ChannelGroup::register(
+///     &mut telemetry,
+///     &[("tire.fl.temp", "K"), ("tire.fr.temp", "K")],
+/// );
+/// temps.log_all(&mut telemetry, &[350.0, 352.0]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelGroup {
+    /// Channel IDs in registration order.
+    ids: alloc::vec::Vec<ChannelId>,
+    /// Channel names, parallel to `ids`.
+    names: alloc::vec::Vec<alloc::string::String>,
+}
+
+#[cfg(feature = "alloc")]
+impl ChannelGroup {
+    /// Registers one channel per `(name, unit)` pair, in order.
+    ///
+    /// Channels the provider cannot register keep [`ChannelId::INVALID`],
+    /// so logging to them is a no-op.
+    #[must_use]
+    pub fn register<T: crate::TelemetryProvider>(provider: &mut T, names: &[(&str, &str)]) -> Self {
+        Self {
+            ids: names
+                .iter()
+                .map(|&(name, unit)| provider.register_channel(name, unit))
+                .collect(),
+            names: names
+                .iter()
+                .map(|&(name, _)| alloc::string::String::from(name))
+                .collect(),
+        }
+    }
+
+    /// Logs one value per channel, in registration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.len() != self.len()`.
+    #[inline]
+    pub fn log_all<T: crate::TelemetryProvider>(&self, provider: &mut T, values: &[f64]) {
+        assert_eq!(
+            values.len(),
+            self.ids.len(),
+            "ChannelGroup::log_all: one value per channel required"
+        );
+        self.log_all_zip(provider, values.iter().copied());
+    }
+
+    /// Logs values from an iterator, pairing them with the channels in
+    /// registration order and stopping at whichever runs out first.
+    #[inline]
+    pub fn log_all_zip<T, I>(&self, provider: &mut T, values: I)
+    where
+        T: crate::TelemetryProvider,
+        I: Iterator<Item = f64>,
+    {
+        for (&id, value) in self.ids.iter().zip(values) {
+            provider.log(id, value);
+        }
+    }
+
+    /// Returns the channel IDs in registration order.
+    #[must_use]
+    pub fn ids(&self) -> &[ChannelId] {
+        &self.ids
+    }
+
+    /// Returns the channel names in registration order.
+    #[must_use]
+    pub fn names(&self) -> &[alloc::string::String] {
+        &self.names
+    }
+
+    /// Returns the number of channels in the group.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns true if the group has no channels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
 /// Metadata for a telemetry channel.
 #[cfg(feature = "enable_telemetry")]
 #[derive(Debug, Clone)]
@@ -136,6 +439,18 @@ pub struct ChannelMetadata {
     pub name: alloc::string::String,
     /// Physical unit of the channel (e.g., "m/s", "rad", "N").
     pub unit: alloc::string::String,
+    /// Free-form description of the channel.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: alloc::string::String,
+    /// Lowest value expected during normal operation, if known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expected_min: Option<f64>,
+    /// Highest value expected during normal operation, if known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expected_max: Option<f64>,
+    /// Key-value tags, exported as `InfluxDB` tags.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tags: alloc::vec::Vec<(alloc::string::String, alloc::string::String)>,
 }
 
 #[cfg(feature = "enable_telemetry")]
@@ -146,9 +461,29 @@ impl ChannelMetadata {
     /// Creates new channel metadata.
     #[must_use]
     pub fn new(name: &str, unit: &str) -> Self {
+        Self::from_descriptor(&ChannelDescriptor::new(name, unit))
+    }
+
+    /// Creates channel metadata from a full descriptor.
+    #[must_use]
+    pub fn from_descriptor(desc: &ChannelDescriptor<'_>) -> Self {
         Self {
-            name: alloc::string::String::from(name),
-            unit: alloc::string::String::from(unit),
+            name: alloc::string::String::from(desc.name),
+            unit: alloc::string::String::from(desc.unit),
+            description: alloc::string::String::from(desc.description),
+            expected_min: desc.expected_min,
+            expected_max: desc.expected_max,
+            tags: desc
+                .tags
+                .as_slice()
+                .iter()
+                .map(|&(key, value)| {
+                    (
+                        alloc::string::String::from(key),
+                        alloc::string::String::from(value),
+                    )
+                })
+                .collect(),
         }
     }
 }
@@ -164,6 +499,27 @@ mod tests {
     }
 
     #[test]
+    fn test_channel_tags_limit() {
+        let mut desc = ChannelDescriptor::new("tire.fl.temp", "K");
+        for _ in 0..MAX_CHANNEL_TAGS {
+            desc = desc.with_tag("wheel", "FL").expect("below the limit");
+        }
+        assert_eq!(desc.with_tag("axle", "front"), Err(TooManyTags));
+
+        let mut tags = desc.tags;
+        assert_eq!(tags.push("axle", "front"), Err(TooManyTags));
+        assert_eq!(tags.len(), MAX_CHANNEL_TAGS);
+    }
+
+    #[test]
+    fn test_channel_id_invalid() {
+        assert!(!ChannelId::INVALID.is_valid());
+        assert!(ChannelId::new(0).is_valid());
+        assert_eq!(ChannelId::INVALID.index(), u32::MAX);
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn test_channel_value_float() {
         let v = ChannelValue::float(3.14);
         assert!((v.as_float() - 3.14).abs() < 1e-10);
@@ -178,8 +534,129 @@ mod tests {
     }
 
     #[test]
+    fn test_channel_value_typed_accessors() {
+        let float = ChannelValue::float(1.5);
+        assert_eq!(float.as_bool(), None);
+        assert_eq!(float.as_vector3(), None);
+
+        let flag = ChannelValue::bool(true);
+        assert_eq!(flag.as_bool(), Some(true));
+        assert_eq!(flag.as_vector3(), None);
+
+        let vector = ChannelValue::from_vec3(&Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(vector.as_vector3(), Some((1.0, 2.0, 3.0)));
+        assert_eq!(vector.as_bool(), None);
+    }
+
+    #[cfg(feature = "enable_telemetry")]
+    #[test]
+    fn test_channel_group_logs_tire_temperatures() {
+        use crate::{MemoryRecorder, TelemetryProvider};
+
+        let mut recorder = MemoryRecorder::with_defaults();
+        let speed = recorder.register_channel("vehicle.speed", "m/s");
+        let temps = ChannelGroup::register(
+            &mut recorder,
+            &[
+                ("tire.fl.temp", "K"),
+                ("tire.fr.temp", "K"),
+                ("tire.rl.temp", "K"),
+                ("tire.rr.temp", "K"),
+            ],
+        );
+        assert_eq!(temps.len(), 4);
+        assert_eq!(temps.names()[2], "tire.rl.temp");
+
+        temps.log_all(&mut recorder, &[350.0, 351.0, 340.0, 341.0]);
+        temps.log_all_zip(&mut recorder, [352.0, 353.0].into_iter());
+
+        assert_eq!(recorder.sample_count(speed), 0);
+        let expected: [&[f64]; 4] = [&[350.0, 352.0], &[351.0, 353.0], &[340.0], &[341.0]];
+        for (&id, want) in temps.ids().iter().zip(expected) {
+            let meta = recorder.channel_metadata(id).expect("registered");
+            assert!(meta.name.starts_with("tire."));
+            assert_eq!(recorder.get_channel_data(id).as_deref(), Some(want));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "one value per channel required")]
+    fn test_channel_group_log_all_length_mismatch() {
+        let mut telemetry = crate::NoOpTelemetry;
+        let group = ChannelGroup::register(&mut telemetry, &[("a", ""), ("b", "")]);
+        group.log_all(&mut telemetry, &[1.0]);
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn test_channel_value_from_f64() {
         let v: ChannelValue = 2.718.into();
         assert!((v.as_float() - 2.718).abs() < 1e-10);
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_channel_id_round_trip() {
+        let id = ChannelId(42);
+        let json = serde_json::to_string(&id).expect("serialize");
+        assert_eq!(json, "42");
+        let back: ChannelId = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(back, id);
+    }
+
+    #[test]
+    fn test_channel_value_round_trip() {
+        let values = [
+            ChannelValue::float(-1.25),
+            ChannelValue::bool(true),
+            ChannelValue::bool(false),
+            ChannelValue::vector3(1.0, -2.0, 3.5),
+        ];
+        for value in values {
+            let json = serde_json::to_string(&value).expect("serialize");
+            let back: ChannelValue = serde_json::from_str(&json).expect("deserialize");
+            assert_eq!(back, value, "round trip of {json}");
+        }
+    }
+
+    #[test]
+    fn test_channel_value_unknown_variant() {
+        let result = serde_json::from_str::<ChannelValue>(r#"{"Quaternion":[1.0,0.0,0.0,0.0]}"#);
+        let err = result
+            .expect_err("unknown variant must be rejected")
+            .to_string();
+        assert!(err.contains("unknown variant"), "unexpected error: {err}");
+        assert!(err.contains("Quaternion"), "unexpected error: {err}");
+    }
+
+    #[cfg(feature = "enable_telemetry")]
+    #[test]
+    fn test_channel_metadata_round_trip() {
+        let meta = ChannelMetadata::from_descriptor(
+            &ChannelDescriptor::new("tire.fl.slip_ratio", "")
+                .with_description("front-left slip")
+                .with_expected_min(-1.0),
+        );
+        let json = serde_json::to_string(&meta).expect("serialize");
+        let back: ChannelMetadata = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(back.name, meta.name);
+        assert_eq!(back.unit, meta.unit);
+        assert_eq!(back.description, meta.description);
+        assert_eq!(back.expected_min, Some(-1.0));
+        assert_eq!(back.expected_max, None);
+
+        // Metadata written before the range fields existed still loads
+        let legacy: ChannelMetadata =
+            serde_json::from_str(r#"{"name":"speed","unit":"m/s"}"#).expect("deserialize");
+        assert!(legacy.description.is_empty());
+        assert_eq!(legacy.expected_min, None);
+    }
+}
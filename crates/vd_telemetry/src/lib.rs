@@ -47,16 +47,30 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod channel;
 pub mod recorder;
+pub mod ring_buffer;
+#[cfg(feature = "alloc")]
+pub mod stats;
 
-pub use channel::{ChannelId, ChannelValue};
+#[cfg(feature = "alloc")]
+pub use channel::ChannelGroup;
+pub use channel::{
+    ChannelDescriptor, ChannelId, ChannelTags, ChannelValue, TooManyTags, MAX_CHANNEL_TAGS,
+};
 
+#[cfg(feature = "std")]
+pub use recorder::FileRecorder;
+pub use recorder::StaticMemoryRecorder;
 #[cfg(feature = "enable_telemetry")]
-pub use recorder::{MemoryRecorder, RingBufferConfig};
+pub use recorder::{
+    ChannelSnapshot, MemoryRecorder, RecorderDecodeError, RecorderError, RingBufferConfig,
+};
 
-use vd_math::Vec3;
+use vd_math::{Mat3, Quat, Vec3};
 
 /// Trait for telemetry providers.
 ///
@@ -72,16 +86,30 @@ use vd_math::Vec3;
 /// - Use simple array indexing
 /// - Be inlineable for optimization
 pub trait TelemetryProvider {
-    /// Registers a new telemetry channel.
+    /// Registers a new telemetry channel with full metadata.
     ///
     /// Returns a `ChannelId` that can be used for fast logging.
     /// The ID should be stored and reused - do not call this in the hot loop.
     ///
+    /// Implementations with bounded storage may return [`ChannelId::INVALID`]
+    /// when no more channels can be registered. Logging to an invalid ID is a
+    /// no-op, but callers should check [`ChannelId::is_valid`] if they need to
+    /// know whether the channel is actually recorded.
+    fn register_channel_full(&mut self, meta: ChannelDescriptor<'_>) -> ChannelId;
+
+    /// Registers a new telemetry channel with only a name and unit.
+    ///
+    /// Equivalent to [`TelemetryProvider::register_channel_full`] with no
+    /// description or expected range.
+    ///
     /// # Arguments
     ///
     /// * `name` - Human-readable name (e.g., `vehicle.speed`, `tire.fl.slip_ratio`)
     /// * `unit` - Physical unit (e.g., "m/s", "rad", "N")
-    fn register_channel(&mut self, name: &str, unit: &str) -> ChannelId;
+    #[inline]
+    fn register_channel(&mut self, name: &str, unit: &str) -> ChannelId {
+        self.register_channel_full(ChannelDescriptor::new(name, unit))
+    }
 
     /// Logs a scalar value to a channel.
     ///
@@ -89,16 +117,80 @@ pub trait TelemetryProvider {
     /// this compiles to nothing.
     fn log(&mut self, id: ChannelId, value: f64);
 
+    /// Logs a scalar value only if it differs from the last value logged
+    /// through this method by at least `threshold`.
+    ///
+    /// The default implementation ignores the threshold and always logs.
+    #[inline]
+    fn log_if_changed(&mut self, id: ChannelId, value: f64, threshold: f64) {
+        let _ = threshold;
+        self.log(id, value);
+    }
+
+    /// Logs a batch of `(channel, value)` pairs, e.g. a full state snapshot.
+    ///
+    /// The default implementation calls [`TelemetryProvider::log`] for each
+    /// pair in order.
+    #[inline]
+    fn batch_log<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = (ChannelId, f64)>,
+        Self: Sized,
+    {
+        for (id, value) in values {
+            self.log(id, value);
+        }
+    }
+
     /// Logs a 3D vector to three channels (x, y, z components).
     ///
     /// Requires pre-registered channel IDs for each component.
     fn log_vector(&mut self, id_x: ChannelId, id_y: ChannelId, id_z: ChannelId, vec: &Vec3);
 
+    /// Logs a [`ChannelValue`], dispatching on its variant.
+    ///
+    /// Floats are logged as-is and booleans as 0.0 or 1.0. A `Vector3` is
+    /// logged through [`TelemetryProvider::log_vector`] to `id`, `id + 1`
+    /// and `id + 2`, the consecutive IDs [`VectorChannelIds::register`]
+    /// gets from providers that assign IDs in registration order.
+    #[inline]
+    fn log_value(&mut self, id: ChannelId, value: ChannelValue) {
+        match value {
+            ChannelValue::Float(v) => self.log(id, v),
+            ChannelValue::Bool(b) => self.log_bool(id, b),
+            ChannelValue::Vector3 { x, y, z } => {
+                // Past the last index the components fall on the no-op ID
+                let offset = |n| {
+                    id.index()
+                        .checked_add(n)
+                        .map_or(ChannelId::INVALID, ChannelId::new)
+                };
+                self.log_vector(id, offset(1), offset(2), &Vec3::new(x, y, z));
+            }
+        }
+    }
+
     /// Logs a boolean value to a channel (stored as 0.0 or 1.0).
     #[inline]
     fn log_bool(&mut self, id: ChannelId, value: bool) {
         self.log(id, if value { 1.0 } else { 0.0 });
     }
+
+    /// Flushes any buffered data to the underlying sink.
+    ///
+    /// The default implementation does nothing and returns `Ok(())`, which
+    /// is correct for providers that keep everything in memory. Providers
+    /// that buffer writes (e.g. to a file) should override this to push the
+    /// buffered data out and report failure as `Err(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if buffered data could not be written.
+    #[allow(clippy::result_unit_err)]
+    #[inline]
+    fn flush(&mut self) -> Result<(), ()> {
+        Ok(())
+    }
 }
 
 /// No-op telemetry provider for zero-cost disabled telemetry.
@@ -108,9 +200,22 @@ pub trait TelemetryProvider {
 #[derive(Debug, Clone, Copy, Default)]
 pub struct NoOpTelemetry;
 
+impl NoOpTelemetry {
+    /// Mirrors `MemoryRecorder::log_ranged`; never logs or reports anything.
+    #[inline]
+    pub fn log_ranged(
+        &mut self,
+        _id: ChannelId,
+        _value: f64,
+        _on_violation: &mut dyn FnMut(ChannelId, f64, f64, f64),
+    ) {
+        // Intentionally empty - compiles to nothing
+    }
+}
+
 impl TelemetryProvider for NoOpTelemetry {
     #[inline]
-    fn register_channel(&mut self, _name: &str, _unit: &str) -> ChannelId {
+    fn register_channel_full(&mut self, _meta: ChannelDescriptor<'_>) -> ChannelId {
         ChannelId::new(0)
     }
 
@@ -119,10 +224,20 @@ impl TelemetryProvider for NoOpTelemetry {
         // Intentionally empty - compiles to nothing
     }
 
+    #[inline]
+    fn log_if_changed(&mut self, _id: ChannelId, _value: f64, _threshold: f64) {
+        // Intentionally empty - compiles to nothing
+    }
+
     #[inline]
     fn log_vector(&mut self, _id_x: ChannelId, _id_y: ChannelId, _id_z: ChannelId, _vec: &Vec3) {
         // Intentionally empty - compiles to nothing
     }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ()> {
+        Ok(())
+    }
 }
 
 /// Helper struct for registering vector channels (x, y, z components).
@@ -167,6 +282,128 @@ impl VectorChannelIds {
     }
 }
 
+/// Helper struct for registering quaternion channels (w, x, y, z components).
+#[derive(Debug, Clone, Copy)]
+pub struct QuaternionChannelIds {
+    /// Channel ID for the scalar (W) component.
+    pub w: ChannelId,
+    /// Channel ID for X component.
+    pub x: ChannelId,
+    /// Channel ID for Y component.
+    pub y: ChannelId,
+    /// Channel ID for Z component.
+    pub z: ChannelId,
+}
+
+impl QuaternionChannelIds {
+    /// Registers four unitless channels for a quaternion (`{base_name}.w`,
+    /// `.x`, `.y`, `.z`).
+    #[must_use]
+    pub fn register<T: TelemetryProvider>(telemetry: &mut T, base_name: &str) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            use alloc::format;
+            Self {
+                w: telemetry.register_channel(&format!("{base_name}.w"), ""),
+                x: telemetry.register_channel(&format!("{base_name}.x"), ""),
+                y: telemetry.register_channel(&format!("{base_name}.y"), ""),
+                z: telemetry.register_channel(&format!("{base_name}.z"), ""),
+            }
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = base_name;
+            Self {
+                w: telemetry.register_channel("", ""),
+                x: telemetry.register_channel("", ""),
+                y: telemetry.register_channel("", ""),
+                z: telemetry.register_channel("", ""),
+            }
+        }
+    }
+
+    /// Logs a quaternion to the registered channels.
+    #[inline]
+    pub fn log<T: TelemetryProvider>(&self, telemetry: &mut T, quat: &Quat) {
+        telemetry.log(self.w, quat.w);
+        telemetry.log(self.x, quat.i);
+        telemetry.log(self.y, quat.j);
+        telemetry.log(self.z, quat.k);
+    }
+}
+
+/// Helper struct for registering 3x3 matrix channels, one per element.
+///
+/// Field `mRC` holds the element in row `R`, column `C`.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixChannelIds3x3 {
+    /// Channel ID for row 0, column 0.
+    pub m00: ChannelId,
+    /// Channel ID for row 0, column 1.
+    pub m01: ChannelId,
+    /// Channel ID for row 0, column 2.
+    pub m02: ChannelId,
+    /// Channel ID for row 1, column 0.
+    pub m10: ChannelId,
+    /// Channel ID for row 1, column 1.
+    pub m11: ChannelId,
+    /// Channel ID for row 1, column 2.
+    pub m12: ChannelId,
+    /// Channel ID for row 2, column 0.
+    pub m20: ChannelId,
+    /// Channel ID for row 2, column 1.
+    pub m21: ChannelId,
+    /// Channel ID for row 2, column 2.
+    pub m22: ChannelId,
+}
+
+impl MatrixChannelIds3x3 {
+    /// Registers nine unitless channels for a matrix (`{base}.m00` through
+    /// `{base}.m22`), in row-major order.
+    #[must_use]
+    pub fn register<T: TelemetryProvider>(provider: &mut T, base: &str) -> Self {
+        let mut ids = [ChannelId::INVALID; 9];
+        for (i, id) in ids.iter_mut().enumerate() {
+            #[cfg(feature = "alloc")]
+            {
+                let name = alloc::format!("{base}.m{}{}", i / 3, i % 3);
+                *id = provider.register_channel(&name, "");
+            }
+            #[cfg(not(feature = "alloc"))]
+            {
+                let _ = (base, i);
+                *id = provider.register_channel("", "");
+            }
+        }
+        let [m00, m01, m02, m10, m11, m12, m20, m21, m22] = ids;
+        Self {
+            m00,
+            m01,
+            m02,
+            m10,
+            m11,
+            m12,
+            m20,
+            m21,
+            m22,
+        }
+    }
+
+    /// Logs every element of a matrix to the registered channels.
+    #[inline]
+    pub fn log<T: TelemetryProvider>(&self, provider: &mut T, mat: &Mat3) {
+        provider.log(self.m00, mat[(0, 0)]);
+        provider.log(self.m01, mat[(0, 1)]);
+        provider.log(self.m02, mat[(0, 2)]);
+        provider.log(self.m10, mat[(1, 0)]);
+        provider.log(self.m11, mat[(1, 1)]);
+        provider.log(self.m12, mat[(1, 2)]);
+        provider.log(self.m20, mat[(2, 0)]);
+        provider.log(self.m21, mat[(2, 1)]);
+        provider.log(self.m22, mat[(2, 2)]);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,12 +413,48 @@ mod tests {
         assert_eq!(core::mem::size_of::<NoOpTelemetry>(), 0);
     }
 
+    #[test]
+    fn test_flush_dispatch() {
+        #[derive(Default)]
+        struct FlushCounter {
+            flushes: usize,
+        }
+
+        impl TelemetryProvider for FlushCounter {
+            fn register_channel_full(&mut self, _meta: ChannelDescriptor<'_>) -> ChannelId {
+                ChannelId::new(0)
+            }
+
+            fn log(&mut self, _id: ChannelId, _value: f64) {}
+
+            fn log_vector(&mut self, _: ChannelId, _: ChannelId, _: ChannelId, _: &Vec3) {}
+
+            fn flush(&mut self) -> Result<(), ()> {
+                self.flushes += 1;
+                Ok(())
+            }
+        }
+
+        fn finish<T: TelemetryProvider>(telemetry: &mut T) -> Result<(), ()> {
+            telemetry.flush()
+        }
+
+        let mut counter = FlushCounter::default();
+        assert_eq!(finish(&mut counter), Ok(()));
+        assert_eq!(finish(&mut counter), Ok(()));
+        assert_eq!(counter.flushes, 2);
+
+        assert_eq!(finish(&mut NoOpTelemetry), Ok(()));
+    }
+
     #[test]
     fn test_noop_telemetry_works() {
         let mut telemetry = NoOpTelemetry;
         let id = telemetry.register_channel("test", "unit");
         telemetry.log(id, 42.0);
         telemetry.log_bool(id, true);
+        telemetry.log_if_changed(id, 1.0, 0.5);
+        telemetry.log_ranged(id, 1e9, &mut |_, _, _, _| unreachable!());
         // Should compile and run without issues
     }
 
@@ -190,6 +463,91 @@ mod tests {
         assert_eq!(core::mem::size_of::<ChannelId>(), 4);
     }
 
+    #[cfg(feature = "enable_telemetry")]
+    #[test]
+    fn test_log_value_dispatch() {
+        let mut recorder = MemoryRecorder::with_defaults();
+        let scalar = recorder.register_channel("scalar", "");
+        let flag = recorder.register_channel("flag", "");
+        let ids = VectorChannelIds::register(&mut recorder, "force", "N");
+
+        recorder.log_value(scalar, ChannelValue::float(2.5));
+        recorder.log_value(flag, ChannelValue::bool(true));
+        recorder.log_value(ids.x, ChannelValue::vector3(1.0, 2.0, 3.0));
+        recorder.log_value(ChannelId::INVALID, ChannelValue::vector3(4.0, 5.0, 6.0));
+
+        let last = |id| {
+            recorder
+                .get_channel_data(id)
+                .and_then(|d| d.last().copied())
+        };
+        assert_eq!(last(scalar), Some(2.5));
+        assert_eq!(last(flag), Some(1.0));
+        assert_eq!(last(ids.x), Some(1.0));
+        assert_eq!(last(ids.y), Some(2.0));
+        assert_eq!(last(ids.z), Some(3.0));
+    }
+
+    #[cfg(feature = "enable_telemetry")]
+    #[test]
+    fn test_quaternion_channel_ids() {
+        let mut recorder = MemoryRecorder::with_defaults();
+        let ids = QuaternionChannelIds::register(&mut recorder, "body.attitude");
+        let quat = vd_math::linear::quat_from_euler(0.1, -0.2, 0.3);
+        ids.log(&mut recorder, &quat);
+
+        let expected = [
+            (ids.w, "body.attitude.w", quat.w),
+            (ids.x, "body.attitude.x", quat.i),
+            (ids.y, "body.attitude.y", quat.j),
+            (ids.z, "body.attitude.z", quat.k),
+        ];
+        for (id, name, value) in expected {
+            let meta = recorder.channel_metadata(id).expect("metadata");
+            assert_eq!(meta.name, name);
+            let data = recorder.get_channel_data(id).expect("data");
+            assert_eq!(data, [value]);
+        }
+        assert_eq!(ids.x.index(), ids.w.index() + 1);
+    }
+
+    #[test]
+    fn test_quaternion_channel_ids_with_noop() {
+        let mut telemetry = NoOpTelemetry;
+        let ids = QuaternionChannelIds::register(&mut telemetry, "attitude");
+        ids.log(&mut telemetry, &Quat::identity());
+        // Should compile and run without issues
+    }
+
+    #[cfg(feature = "enable_telemetry")]
+    #[test]
+    fn test_matrix_channel_ids_3x3() {
+        let mut recorder = MemoryRecorder::with_defaults();
+        let ids = MatrixChannelIds3x3::register(&mut recorder, "body.rotation");
+        // 90 degree yaw: x -> y, y -> -x
+        let mat = vd_math::linear::mat3_from_axis_angle(&Vec3::z(), core::f64::consts::FRAC_PI_2);
+        ids.log(&mut recorder, &mat);
+
+        let expected = [
+            (ids.m00, "body.rotation.m00", 0.0),
+            (ids.m01, "body.rotation.m01", -1.0),
+            (ids.m02, "body.rotation.m02", 0.0),
+            (ids.m10, "body.rotation.m10", 1.0),
+            (ids.m11, "body.rotation.m11", 0.0),
+            (ids.m12, "body.rotation.m12", 0.0),
+            (ids.m20, "body.rotation.m20", 0.0),
+            (ids.m21, "body.rotation.m21", 0.0),
+            (ids.m22, "body.rotation.m22", 1.0),
+        ];
+        for (id, name, value) in expected {
+            let meta = recorder.channel_metadata(id).expect("metadata");
+            assert_eq!(meta.name, name);
+            let data = recorder.get_channel_data(id).expect("data");
+            assert_eq!(data.len(), 1);
+            assert!((data[0] - value).abs() < 1e-12, "{name}: {}", data[0]);
+        }
+    }
+
     #[test]
     fn test_vector_channel_ids_with_noop() {
         let mut telemetry = NoOpTelemetry;
@@ -1,14 +1,19 @@
 //! Telemetry recorder implementations.
 //!
 //! This module provides concrete implementations of the `TelemetryProvider` trait,
-//! including a ring buffer-based memory recorder for storing telemetry history.
+//! including a ring buffer-based memory recorder for storing telemetry history,
+//! a fixed-capacity variant for targets without an allocator, and a text file
+//! recorder for `std` targets.
 
 #[cfg(feature = "enable_telemetry")]
 mod enabled {
-    use crate::channel::{ChannelId, ChannelMetadata};
+    use crate::channel::{ChannelDescriptor, ChannelId, ChannelMetadata};
+    use crate::ring_buffer::DynRingBuffer;
     use crate::TelemetryProvider;
-    use alloc::vec;
+    use alloc::string::String;
     use alloc::vec::Vec;
+    use core::fmt;
+    use core::fmt::Write;
     use vd_math::Vec3;
 
     extern crate alloc;
@@ -43,6 +48,136 @@ mod enabled {
         }
     }
 
+    /// Magic header identifying the `MemoryRecorder` binary format.
+    const RECORDER_MAGIC: [u8; 8] = *b"VDREC\x00\x00\x04";
+
+    /// Smallest encoded size of one channel's metadata (three empty strings,
+    /// an empty tag list, two range bounds, capacity, write position, sample
+    /// count and liveness flag).
+    const MIN_CHANNEL_BYTES: usize = 3 * 4 + 4 + 2 * 8 + 4 * 4;
+
+    /// Error type for decoding a `MemoryRecorder` from bytes.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum RecorderDecodeError {
+        /// The input ended before all expected fields were read.
+        Truncated,
+        /// The input does not start with the recorder magic header.
+        BadMagic,
+        /// A channel name or unit is not valid UTF-8.
+        InvalidMetadata {
+            /// Index of the channel with invalid metadata.
+            channel: usize,
+        },
+    }
+
+    impl fmt::Display for RecorderDecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Truncated => write!(f, "Recorder data is truncated"),
+                Self::BadMagic => write!(f, "Recorder data has an invalid magic header"),
+                Self::InvalidMetadata { channel } => {
+                    write!(f, "Channel {channel} metadata is not valid UTF-8")
+                }
+            }
+        }
+    }
+
+    /// Error type for operations on a live `MemoryRecorder`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum RecorderError {
+        /// The channel ID does not refer to a registered channel.
+        ChannelNotFound(ChannelId),
+    }
+
+    impl fmt::Display for RecorderError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::ChannelNotFound(id) => {
+                    write!(f, "Channel {} is not registered", id.index())
+                }
+            }
+        }
+    }
+
+    /// Sequential reader over an encoded recorder.
+    struct ByteReader<'a> {
+        bytes: &'a [u8],
+    }
+
+    impl<'a> ByteReader<'a> {
+        fn take(&mut self, len: usize) -> Result<&'a [u8], RecorderDecodeError> {
+            if self.bytes.len() < len {
+                return Err(RecorderDecodeError::Truncated);
+            }
+            let (head, tail) = self.bytes.split_at(len);
+            self.bytes = tail;
+            Ok(head)
+        }
+
+        fn read_u32(&mut self) -> Result<u32, RecorderDecodeError> {
+            let mut buf = [0u8; 4];
+            buf.copy_from_slice(self.take(4)?);
+            Ok(u32::from_ne_bytes(buf))
+        }
+
+        fn read_f64(&mut self) -> Result<f64, RecorderDecodeError> {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(self.take(8)?);
+            Ok(f64::from_ne_bytes(buf))
+        }
+
+        fn read_str(&mut self, channel: usize) -> Result<String, RecorderDecodeError> {
+            let len = self.read_u32()? as usize;
+            let raw = self.take(len)?;
+            core::str::from_utf8(raw)
+                .map(String::from)
+                .map_err(|_| RecorderDecodeError::InvalidMetadata { channel })
+        }
+    }
+
+    /// Appends `text` to `out`, escaping it as an `InfluxDB` tag value.
+    ///
+    /// Spaces become underscores; commas and equals signs are backslash-escaped.
+    fn push_influx_tag(out: &mut String, text: &str) {
+        for c in text.chars() {
+            match c {
+                ' ' => out.push('_'),
+                ',' | '=' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                _ => out.push(c),
+            }
+        }
+    }
+
+    /// Most recent value of every channel at a point in time.
+    ///
+    /// Indexed by [`ChannelId::index`]. Channels that have not been logged
+    /// to yet hold `NaN`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChannelSnapshot(pub Vec<f64>);
+
+    impl ChannelSnapshot {
+        /// Returns the value captured for a channel.
+        #[must_use]
+        pub fn get(&self, id: ChannelId) -> Option<f64> {
+            self.0.get(id.index() as usize).copied()
+        }
+
+        /// Returns the number of channels in the snapshot.
+        #[must_use]
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        /// Returns true if the snapshot contains no channels.
+        #[must_use]
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
     /// In-memory telemetry recorder using ring buffers.
     ///
     /// Pre-allocates storage to avoid allocations during the simulation loop.
@@ -50,12 +185,13 @@ mod enabled {
     pub struct MemoryRecorder {
         /// Channel metadata.
         metadata: Vec<ChannelMetadata>,
-        /// Data storage: flat buffer organized as `[ch0_samples, ch1_samples, ...]`.
-        data: Vec<f64>,
-        /// Write position for each channel (index into the channel's slice).
-        write_positions: Vec<usize>,
-        /// Number of samples written to each channel (saturates at buffer size).
-        sample_counts: Vec<usize>,
+        /// Sample history of each channel.
+        buffers: Vec<DynRingBuffer<f64>>,
+        /// Last value recorded by `log_if_changed` for each channel (NaN if none).
+        last_logged: Vec<f64>,
+        /// Whether each slot holds a registered channel; false once
+        /// deregistered, until `register_channel` reuses the slot.
+        live: Vec<bool>,
         /// Configuration.
         config: RingBufferConfig,
     }
@@ -66,9 +202,9 @@ mod enabled {
         pub fn new(config: RingBufferConfig) -> Self {
             Self {
                 metadata: Vec::with_capacity(config.max_channels),
-                data: Vec::new(),
-                write_positions: Vec::with_capacity(config.max_channels),
-                sample_counts: Vec::with_capacity(config.max_channels),
+                buffers: Vec::with_capacity(config.max_channels),
+                last_logged: Vec::with_capacity(config.max_channels),
+                live: Vec::with_capacity(config.max_channels),
                 config,
             }
         }
@@ -79,31 +215,150 @@ mod enabled {
             Self::new(RingBufferConfig::default())
         }
 
-        /// Returns the number of registered channels.
+        /// Returns the number of registered channels, not counting
+        /// deregistered ones.
         #[must_use]
         pub fn channel_count(&self) -> usize {
-            self.metadata.len()
+            self.live.iter().filter(|&&live| live).count()
         }
 
         /// Returns the metadata for a channel.
         #[must_use]
         pub fn channel_metadata(&self, id: ChannelId) -> Option<&ChannelMetadata> {
+            if !self.channel_exists(id) {
+                return None;
+            }
             self.metadata.get(id.index() as usize)
         }
 
-        /// Returns all channel metadata.
-        #[must_use]
-        pub fn all_metadata(&self) -> &[ChannelMetadata] {
-            &self.metadata
+        /// Returns the metadata of every registered channel, in ID order.
+        ///
+        /// Deregistered channels are skipped.
+        pub fn all_metadata(&self) -> impl Iterator<Item = &ChannelMetadata> + '_ {
+            self.metadata
+                .iter()
+                .zip(&self.live)
+                .filter_map(|(meta, &live)| live.then_some(meta))
         }
 
         /// Returns the number of samples stored for a channel.
         #[must_use]
         pub fn sample_count(&self, id: ChannelId) -> usize {
-            self.sample_counts
+            self.buffers
                 .get(id.index() as usize)
-                .copied()
-                .unwrap_or(0)
+                .map_or(0, DynRingBuffer::len)
+        }
+
+        /// Changes how many samples a channel keeps.
+        ///
+        /// Stored samples are kept in chronological order. When shrinking,
+        /// only the most recent `new_sample_count` samples survive. Other
+        /// channels keep the configured size.
+        ///
+        /// # Errors
+        ///
+        /// Returns `RecorderError::ChannelNotFound` if `id` is not a
+        /// registered channel.
+        pub fn resize_channel(
+            &mut self,
+            id: ChannelId,
+            new_sample_count: usize,
+        ) -> Result<(), RecorderError> {
+            if !self.channel_exists(id) {
+                return Err(RecorderError::ChannelNotFound(id));
+            }
+            let buffer = &mut self.buffers[id.index() as usize];
+            let mut resized = DynRingBuffer::with_capacity(new_sample_count);
+            for &value in buffer.iter_chronological() {
+                resized.push(value);
+            }
+            *buffer = resized;
+            Ok(())
+        }
+
+        /// Drops every sample logged outside `[t_start, t_end]`.
+        ///
+        /// Samples carry no timestamps of their own, so times are read from
+        /// `time`, a channel the caller logs simulation time to on every
+        /// step. Only channels holding as many samples as `time` (including
+        /// `time` itself) are treated as logged alongside it and trimmed;
+        /// other channels are left untouched. Samples whose timestamp is
+        /// `NaN` are dropped. Channel capacities are kept.
+        ///
+        /// Returns the total number of samples removed, or 0 if `time` is not
+        /// a registered channel.
+        pub fn trim_to_time_range(&mut self, time: ChannelId, t_start: f64, t_end: f64) -> usize {
+            let Some(times) = self.get_channel_data(time) else {
+                return 0;
+            };
+            let keep: Vec<bool> = times
+                .iter()
+                .map(|t| (t_start..=t_end).contains(t))
+                .collect();
+
+            let mut removed = 0;
+            for (buffer, _) in self
+                .buffers
+                .iter_mut()
+                .zip(&self.live)
+                .filter(|(buffer, &live)| live && buffer.len() == keep.len())
+            {
+                let mut trimmed = DynRingBuffer::with_capacity(buffer.capacity());
+                for (&value, &keep) in buffer.iter_chronological().zip(&keep) {
+                    if keep {
+                        trimmed.push(value);
+                    } else {
+                        removed += 1;
+                    }
+                }
+                *buffer = trimmed;
+            }
+            removed
+        }
+
+        /// Removes a channel and discards its samples.
+        ///
+        /// The slot is reused by the next `register_channel` call, which
+        /// lets long sessions re-register channels without exhausting
+        /// `max_channels`. Until then, `id` is treated like an unknown
+        /// channel and logging to it is a no-op. IDs are plain slot indices,
+        /// so once the slot is reused `id` refers to the new channel. Until
+        /// then, exports skip the slot and `to_bytes` records it as free.
+        ///
+        /// # Errors
+        ///
+        /// Returns `RecorderError::ChannelNotFound` if `id` is not a
+        /// registered channel.
+        pub fn deregister_channel(&mut self, id: ChannelId) -> Result<(), RecorderError> {
+            if !self.channel_exists(id) {
+                return Err(RecorderError::ChannelNotFound(id));
+            }
+            let idx = id.index() as usize;
+            self.live[idx] = false;
+            self.metadata[idx] = ChannelMetadata::new("", "");
+            // A zero-capacity ring frees the samples and drops later logs
+            self.buffers[idx] = DynRingBuffer::with_capacity(0);
+            self.last_logged[idx] = f64::NAN;
+            Ok(())
+        }
+
+        /// Returns true if `id` refers to a registered channel.
+        #[must_use]
+        pub fn channel_exists(&self, id: ChannelId) -> bool {
+            self.live.get(id.index() as usize).copied().unwrap_or(false)
+        }
+
+        /// Returns true if `id` is registered and has at least one sample.
+        #[must_use]
+        pub fn channel_is_active(&self, id: ChannelId) -> bool {
+            self.sample_count(id) > 0
+        }
+
+        /// Returns true if `id` is registered and its buffer is full, so the
+        /// next sample overwrites the oldest one.
+        #[must_use]
+        pub fn channel_is_overflowed(&self, id: ChannelId) -> bool {
+            self.channel_exists(id) && self.buffers[id.index() as usize].is_full()
         }
 
         /// Returns the data for a channel as a slice.
@@ -111,83 +366,454 @@ mod enabled {
         /// The data is returned in chronological order (oldest first).
         #[must_use]
         pub fn get_channel_data(&self, id: ChannelId) -> Option<Vec<f64>> {
-            let idx = id.index() as usize;
-            if idx >= self.metadata.len() {
+            if !self.channel_exists(id) {
+                return None;
+            }
+            let buffer = &self.buffers[id.index() as usize];
+            Some(buffer.iter_chronological().copied().collect())
+        }
+
+        /// Returns a channel's chronological data as `(time, value)` pairs,
+        /// with times read from the `time` channel.
+        ///
+        /// Returns `None` if either channel is not registered, or if they hold
+        /// different sample counts and so were not logged alongside each
+        /// other.
+        #[must_use]
+        pub fn get_channel_data_with_timestamps(
+            &self,
+            id: ChannelId,
+            time: ChannelId,
+        ) -> Option<Vec<(f64, f64)>> {
+            if !self.channel_exists(id) || !self.channel_exists(time) {
+                return None;
+            }
+            let buffer = &self.buffers[id.index() as usize];
+            let times = &self.buffers[time.index() as usize];
+            if buffer.len() != times.len() {
                 return None;
             }
+            Some(
+                times
+                    .iter_chronological()
+                    .copied()
+                    .zip(buffer.iter_chronological().copied())
+                    .collect(),
+            )
+        }
 
-            let samples = self.config.samples_per_channel;
-            let base = idx * samples;
-            let count = self.sample_counts[idx];
-            let write_pos = self.write_positions[idx];
+        /// Replays every stored sample as `(channel, value)` pairs.
+        ///
+        /// Samples carry no timestamps, so channels are interleaved
+        /// round-robin: the oldest sample of each channel in ID order, then
+        /// the second oldest, and so on. For channels logged once per
+        /// simulation step in registration order this reproduces the
+        /// original logging order. The iterator borrows the ring buffers and
+        /// does not allocate.
+        pub fn replay_iter(&self) -> impl Iterator<Item = (ChannelId, f64)> + '_ {
+            let steps = self
+                .buffers
+                .iter()
+                .map(DynRingBuffer::len)
+                .max()
+                .unwrap_or(0);
+            (0..steps).flat_map(move |step| {
+                self.buffers
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(idx, buffer)| {
+                        let value = *buffer.get(step)?;
+                        Some((ChannelId::new(idx as u32), value))
+                    })
+            })
+        }
 
-            if count < samples {
-                // Buffer not full yet - data is in order from start
-                Some(self.data[base..base + count].to_vec())
-            } else {
-                // Buffer wrapped - need to reorder
-                let mut result = Vec::with_capacity(samples);
-                // Oldest data starts at write_pos
-                result.extend_from_slice(&self.data[base + write_pos..base + samples]);
-                result.extend_from_slice(&self.data[base..base + write_pos]);
-                Some(result)
+        /// Returns the Pearson correlation coefficient between two channels.
+        ///
+        /// Samples are paired in chronological order. Returns `None` if
+        /// either channel is unknown, the sample counts differ, fewer than
+        /// two samples are stored, or either channel is constant.
+        #[must_use]
+        pub fn correlate(&self, id_a: ChannelId, id_b: ChannelId) -> Option<f64> {
+            let a = self.buffers.get(id_a.index() as usize)?;
+            let b = self.buffers.get(id_b.index() as usize)?;
+            let count = a.len();
+            if count != b.len() || count < 2 {
+                return None;
+            }
+
+            let pairs = a.iter_chronological().zip(b.iter_chronological());
+
+            let (mut sx, mut sy, mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for (&x, &y) in pairs {
+                sx += x;
+                sy += y;
+                sxx += x * x;
+                syy += y * y;
+                sxy += x * y;
+            }
+
+            let n = count as f64;
+            let cov = n * sxy - sx * sy;
+            let var_x = n * sxx - libm::pow(sx, 2.0);
+            let var_y = n * syy - libm::pow(sy, 2.0);
+            if var_x <= 0.0 || var_y <= 0.0 {
+                return None;
+            }
+            Some((cov / libm::sqrt(var_x * var_y)).clamp(-1.0, 1.0))
+        }
+
+        /// Returns the most recently logged value of every channel.
+        ///
+        /// Runs in O(C) for C channels and does not copy any history.
+        #[must_use]
+        pub fn snapshot(&self) -> ChannelSnapshot {
+            let values = self
+                .buffers
+                .iter()
+                .map(|buffer| buffer.latest().copied().unwrap_or(f64::NAN))
+                .collect();
+            ChannelSnapshot(values)
+        }
+
+        /// Encodes the recorder into a compact binary format.
+        ///
+        /// Layout (integers and floats in native endianness):
+        /// - 8-byte magic header
+        /// - `u32` channel count, `u32` configured samples per channel
+        /// - per channel: length-prefixed UTF-8 name, unit and description,
+        ///   `u32` tag count followed by length-prefixed keys and values,
+        ///   `f64` expected min and max (`NaN` if unset), then `u32`
+        ///   capacity (which differs from the configured size after
+        ///   [`MemoryRecorder::resize_channel`]), `u32` write position,
+        ///   `u32` sample count and `u32` 1 if the channel is registered or 0
+        ///   if its slot was freed by [`MemoryRecorder::deregister_channel`]
+        /// - `capacity` `f64` values for each channel in turn, with the ring
+        ///   starting at index 0 (unused slots are zero)
+        ///
+        /// The format is intended for same-machine storage; it is not portable
+        /// across platforms with different endianness.
+        #[must_use]
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let metadata_len: usize = self
+                .metadata
+                .iter()
+                .map(|m| {
+                    let tags_len: usize = m.tags.iter().map(|(k, v)| 8 + k.len() + v.len()).sum();
+                    MIN_CHANNEL_BYTES + m.name.len() + m.unit.len() + m.description.len() + tags_len
+                })
+                .sum();
+            let data_len: usize = self.buffers.iter().map(DynRingBuffer::capacity).sum();
+            let mut out = Vec::with_capacity(16 + metadata_len + data_len * 8);
+
+            out.extend_from_slice(&RECORDER_MAGIC);
+            out.extend_from_slice(&(self.metadata.len() as u32).to_ne_bytes());
+            out.extend_from_slice(&(self.config.samples_per_channel as u32).to_ne_bytes());
+
+            for ((meta, buffer), &live) in self.metadata.iter().zip(&self.buffers).zip(&self.live) {
+                for text in [&meta.name, &meta.unit, &meta.description] {
+                    out.extend_from_slice(&(text.len() as u32).to_ne_bytes());
+                    out.extend_from_slice(text.as_bytes());
+                }
+                out.extend_from_slice(&(meta.tags.len() as u32).to_ne_bytes());
+                for (key, value) in &meta.tags {
+                    for text in [key, value] {
+                        out.extend_from_slice(&(text.len() as u32).to_ne_bytes());
+                        out.extend_from_slice(text.as_bytes());
+                    }
+                }
+                for bound in [meta.expected_min, meta.expected_max] {
+                    out.extend_from_slice(&bound.unwrap_or(f64::NAN).to_ne_bytes());
+                }
+                let capacity = buffer.capacity();
+                let write_pos = if capacity == 0 {
+                    0
+                } else {
+                    buffer.len() % capacity
+                };
+                out.extend_from_slice(&(capacity as u32).to_ne_bytes());
+                out.extend_from_slice(&(write_pos as u32).to_ne_bytes());
+                out.extend_from_slice(&(buffer.len() as u32).to_ne_bytes());
+                out.extend_from_slice(&u32::from(live).to_ne_bytes());
+            }
+
+            for buffer in &self.buffers {
+                let padding = buffer.capacity() - buffer.len();
+                let values = buffer
+                    .iter_chronological()
+                    .chain(core::iter::repeat_n(&0.0, padding));
+                for value in values {
+                    out.extend_from_slice(&value.to_ne_bytes());
+                }
+            }
+            out
+        }
+
+        /// Decodes a recorder previously encoded with [`MemoryRecorder::to_bytes`].
+        ///
+        /// The decoded recorder's `max_channels` equals its channel count.
+        ///
+        /// # Errors
+        ///
+        /// Returns `RecorderDecodeError` if the input is truncated, does not
+        /// start with the expected magic header, or contains invalid metadata.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, RecorderDecodeError> {
+            let mut reader = ByteReader { bytes };
+            if reader.take(RECORDER_MAGIC.len())? != RECORDER_MAGIC {
+                return Err(RecorderDecodeError::BadMagic);
+            }
+
+            let channels = reader.read_u32()? as usize;
+            let samples = reader.read_u32()? as usize;
+            let config = RingBufferConfig {
+                samples_per_channel: samples,
+                max_channels: channels,
+            };
+
+            // Reject impossible channel counts early so a corrupt header
+            // cannot trigger a huge allocation.
+            if reader.bytes.len() / MIN_CHANNEL_BYTES < channels {
+                return Err(RecorderDecodeError::Truncated);
+            }
+
+            let mut recorder = Self::new(config);
+            let mut positions = Vec::with_capacity(channels);
+            for channel in 0..channels {
+                let name = reader.read_str(channel)?;
+                let unit = reader.read_str(channel)?;
+                let description = reader.read_str(channel)?;
+                let tag_count = reader.read_u32()? as usize;
+                // Each tag needs at least its two length prefixes
+                if reader.bytes.len() / 8 < tag_count {
+                    return Err(RecorderDecodeError::Truncated);
+                }
+                let mut tags = Vec::with_capacity(tag_count);
+                for _ in 0..tag_count {
+                    let key = reader.read_str(channel)?;
+                    let value = reader.read_str(channel)?;
+                    tags.push((key, value));
+                }
+                let bound = |v: f64| if v.is_nan() { None } else { Some(v) };
+                let expected_min = bound(reader.read_f64()?);
+                let expected_max = bound(reader.read_f64()?);
+                let capacity = reader.read_u32()? as usize;
+                let write_pos = reader.read_u32()? as usize;
+                let count = reader.read_u32()? as usize;
+                let live = reader.read_u32()? != 0;
+                recorder.metadata.push(if live {
+                    ChannelMetadata {
+                        name,
+                        unit,
+                        description,
+                        expected_min,
+                        expected_max,
+                        tags,
+                    }
+                } else {
+                    ChannelMetadata::new("", "")
+                });
+                positions.push((
+                    capacity,
+                    write_pos.min(capacity.saturating_sub(1)),
+                    count.min(capacity),
+                    live,
+                ));
+                recorder.last_logged.push(f64::NAN);
+                recorder.live.push(live);
             }
+
+            // Every ring is stored in full, so the capacities are bounded by
+            // the input length before any of them sizes an allocation
+            let len = positions
+                .iter()
+                .try_fold(0usize, |sum, &(capacity, ..)| sum.checked_add(capacity))
+                .ok_or(RecorderDecodeError::Truncated)?;
+            if reader.bytes.len() / 8 < len {
+                return Err(RecorderDecodeError::Truncated);
+            }
+            let mut raw = Vec::new();
+            for (capacity, write_pos, count, live) in positions {
+                raw.clear();
+                for _ in 0..capacity {
+                    raw.push(reader.read_f64()?);
+                }
+                if !live {
+                    // Like `deregister_channel`, a free slot drops its logs
+                    recorder.buffers.push(DynRingBuffer::with_capacity(0));
+                    continue;
+                }
+                // A full ring starts at its write position; a partial one at 0
+                let start = if count == capacity { write_pos } else { 0 };
+                let mut buffer = DynRingBuffer::with_capacity(capacity);
+                for &value in raw[start..].iter().chain(&raw[..start]).take(count) {
+                    buffer.push(value);
+                }
+                recorder.buffers.push(buffer);
+            }
+
+            Ok(recorder)
+        }
+
+        /// Exports every registered channel as a `(metadata, data)` column,
+        /// in ID order.
+        ///
+        /// Data is chronological and all columns are padded at the end with
+        /// `NaN` to the largest sample count, giving a rectangular table
+        /// suitable for `NumPy` or MATLAB.
+        #[must_use]
+        pub fn export_columns(&self) -> Vec<(ChannelMetadata, Vec<f64>)> {
+            let length = self
+                .buffers
+                .iter()
+                .map(DynRingBuffer::len)
+                .max()
+                .unwrap_or(0);
+            self.all_metadata()
+                .cloned()
+                .zip(self.export_columns_aligned(length))
+                .collect()
+        }
+
+        /// Exports every registered channel's chronological data, in ID
+        /// order, truncated or `NaN`-padded at the end to exactly
+        /// `expected_length` samples.
+        #[must_use]
+        pub fn export_columns_aligned(&self, expected_length: usize) -> Vec<Vec<f64>> {
+            (0..self.metadata.len())
+                .filter_map(|idx| self.get_channel_data(ChannelId::new(idx as u32)))
+                .map(|mut column| {
+                    column.resize(expected_length, f64::NAN);
+                    column
+                })
+                .collect()
+        }
+
+        /// Exports all samples in `InfluxDB` line protocol.
+        ///
+        /// Emits one line per sample, grouped by channel in chronological order:
+        /// `{measurement},channel={name}[,{key}={value}...] value={value} {timestamp}`.
+        /// Channel tags follow the `channel` tag in registration order. The sample
+        /// index within the channel is used as a synthetic nanosecond timestamp.
+        /// Non-finite samples are skipped, since line protocol cannot encode them.
+        #[must_use]
+        pub fn export_influxdb_line_protocol(&self, measurement: &str) -> String {
+            let mut out = String::new();
+            for (idx, meta) in self.metadata.iter().enumerate() {
+                let Some(data) = self.get_channel_data(ChannelId::new(idx as u32)) else {
+                    continue;
+                };
+                for (timestamp, value) in data.iter().enumerate() {
+                    if !value.is_finite() {
+                        continue;
+                    }
+                    for c in measurement.chars() {
+                        if c == ',' || c == ' ' {
+                            out.push('\\');
+                        }
+                        out.push(c);
+                    }
+                    out.push_str(",channel=");
+                    push_influx_tag(&mut out, &meta.name);
+                    for (key, tag) in &meta.tags {
+                        out.push(',');
+                        push_influx_tag(&mut out, key);
+                        out.push('=');
+                        push_influx_tag(&mut out, tag);
+                    }
+                    let _ = writeln!(out, " value={value} {timestamp}");
+                }
+            }
+            out
         }
 
         /// Clears all recorded data but keeps channel registrations.
         pub fn clear(&mut self) {
-            for pos in &mut self.write_positions {
-                *pos = 0;
+            for buffer in &mut self.buffers {
+                buffer.clear();
             }
-            for count in &mut self.sample_counts {
-                *count = 0;
+            for last in &mut self.last_logged {
+                *last = f64::NAN;
             }
-            // Reset data to zeros
-            for val in &mut self.data {
-                *val = 0.0;
+        }
+
+        /// Logs `value` and reports it if it falls outside the channel's
+        /// expected range.
+        ///
+        /// `on_violation` receives `(id, value, expected_min, expected_max)`.
+        /// It is only called for channels registered with at least one bound;
+        /// a missing bound is passed as the corresponding infinity.
+        pub fn log_ranged(
+            &mut self,
+            id: ChannelId,
+            value: f64,
+            on_violation: &mut dyn FnMut(ChannelId, f64, f64, f64),
+        ) {
+            self.log(id, value);
+
+            let Some(meta) = self.channel_metadata(id) else {
+                return;
+            };
+            if meta.expected_min.is_none() && meta.expected_max.is_none() {
+                return;
+            }
+
+            let min = meta.expected_min.unwrap_or(f64::NEG_INFINITY);
+            let max = meta.expected_max.unwrap_or(f64::INFINITY);
+            if !(min..=max).contains(&value) {
+                on_violation(id, value, min, max);
             }
         }
     }
 
     impl TelemetryProvider for MemoryRecorder {
-        fn register_channel(&mut self, name: &str, unit: &str) -> ChannelId {
-            let id = ChannelId::new(self.metadata.len() as u32);
+        /// Reuses the first deregistered slot, if any, before growing.
+        fn register_channel_full(&mut self, meta: ChannelDescriptor<'_>) -> ChannelId {
+            let metadata = ChannelMetadata::from_descriptor(&meta);
+            let buffer = DynRingBuffer::with_capacity(self.config.samples_per_channel);
+
+            if let Some(idx) = self.live.iter().position(|&live| !live) {
+                self.metadata[idx] = metadata;
+                self.buffers[idx] = buffer;
+                self.last_logged[idx] = f64::NAN;
+                self.live[idx] = true;
+                return ChannelId::new(idx as u32);
+            }
 
             if self.metadata.len() >= self.config.max_channels {
-                // Return a dummy ID that will be ignored on log
-                return ChannelId::new(u32::MAX);
+                return ChannelId::INVALID;
             }
 
-            self.metadata.push(ChannelMetadata::new(name, unit));
-            self.write_positions.push(0);
-            self.sample_counts.push(0);
+            let id = ChannelId::new(self.metadata.len() as u32);
 
-            // Extend the data buffer for this channel
-            self.data.extend(vec![0.0; self.config.samples_per_channel]);
+            self.metadata.push(metadata);
+            self.buffers.push(buffer);
+            self.last_logged.push(f64::NAN);
+            self.live.push(true);
 
             id
         }
 
         #[inline]
         fn log(&mut self, id: ChannelId, value: f64) {
-            let idx = id.index() as usize;
-            if idx >= self.metadata.len() {
-                return;
+            // Also skips ChannelId::INVALID, whose index is never in range
+            if let Some(buffer) = self.buffers.get_mut(id.index() as usize) {
+                buffer.push(value);
             }
+        }
 
-            let samples = self.config.samples_per_channel;
-            let base = idx * samples;
-            let write_pos = self.write_positions[idx];
-
-            // Direct write - no bounds check needed due to modular arithmetic
-            self.data[base + write_pos] = value;
-
-            // Advance write position (ring buffer wrap)
-            self.write_positions[idx] = (write_pos + 1) % samples;
+        /// Logs `value` only when it has moved by at least `threshold` since the
+        /// last value recorded through this method. The first call on a channel
+        /// always logs.
+        #[inline]
+        fn log_if_changed(&mut self, id: ChannelId, value: f64, threshold: f64) {
+            let idx = id.index() as usize;
+            let Some(&last) = self.last_logged.get(idx) else {
+                return;
+            };
 
-            // Update sample count (saturates at buffer size)
-            if self.sample_counts[idx] < samples {
-                self.sample_counts[idx] += 1;
+            if last.is_nan() || libm::fabs(value - last) >= threshold {
+                self.last_logged[idx] = value;
+                self.log(id, value);
             }
         }
 
@@ -201,6 +827,7 @@ mod enabled {
     #[cfg(test)]
     mod tests {
         use super::*;
+        use alloc::vec;
 
         #[test]
         fn test_register_channel() {
@@ -216,6 +843,155 @@ mod enabled {
             assert_eq!(meta.unit, "m/s");
         }
 
+        #[test]
+        fn test_register_channel_full() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let id = recorder.register_channel_full(
+                ChannelDescriptor::new("tire.fl.slip_ratio", "")
+                    .with_description("Longitudinal slip ratio")
+                    .with_expected_min(-1.0)
+                    .with_expected_max(1.0),
+            );
+            let plain = recorder.register_channel("speed", "m/s");
+
+            let meta = recorder.channel_metadata(id).expect("metadata");
+            assert_eq!(meta.name, "tire.fl.slip_ratio");
+            assert_eq!(meta.unit, "");
+            assert_eq!(meta.description, "Longitudinal slip ratio");
+            assert_eq!(meta.expected_min, Some(-1.0));
+            assert_eq!(meta.expected_max, Some(1.0));
+
+            let meta = recorder.channel_metadata(plain).expect("metadata");
+            assert!(meta.description.is_empty());
+            assert_eq!(meta.expected_min, None);
+            assert_eq!(meta.expected_max, None);
+        }
+
+        #[test]
+        fn test_batch_log() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let ids: Vec<ChannelId> = (0..10)
+                .map(|i| recorder.register_channel(&alloc::format!("state.{i}"), ""))
+                .collect();
+            let values = (0..10u32).map(|i| f64::from(i) * 1.5);
+
+            recorder.batch_log(
+                ids.iter()
+                    .copied()
+                    .zip(values.clone())
+                    .chain([(ChannelId::INVALID, 99.0)]),
+            );
+
+            for (&id, expected) in ids.iter().zip(values) {
+                assert_eq!(recorder.sample_count(id), 1);
+                let data = recorder.get_channel_data(id).expect("channel data");
+                assert!((data[0] - expected).abs() < 1e-12);
+            }
+        }
+
+        #[test]
+        fn test_replay_iter() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 3,
+                max_channels: 4,
+            });
+            let a = recorder.register_channel("a", "");
+            let b = recorder.register_channel("b", "");
+            recorder.register_channel("idle", "");
+
+            recorder.log(a, 1.0);
+            recorder.log(b, 10.0);
+            recorder.log(a, 2.0);
+
+            let replayed: Vec<_> = recorder.replay_iter().collect();
+            assert_eq!(replayed, [(a, 1.0), (b, 10.0), (a, 2.0)]);
+
+            // Wrapped buffers replay from their oldest retained sample
+            for i in 3..6 {
+                recorder.log(a, f64::from(i));
+                recorder.log(b, f64::from(i) * 10.0);
+            }
+            let values: Vec<f64> = recorder.replay_iter().map(|(_, v)| v).collect();
+            assert_eq!(values, [3.0, 30.0, 4.0, 40.0, 5.0, 50.0]);
+
+            assert_eq!(MemoryRecorder::with_defaults().replay_iter().count(), 0);
+        }
+
+        #[test]
+        fn test_correlate() {
+            let config = RingBufferConfig {
+                samples_per_channel: 64,
+                max_channels: 8,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            let a = recorder.register_channel("a", "");
+            let same = recorder.register_channel("same", "");
+            let anti = recorder.register_channel("anti", "");
+            let sin = recorder.register_channel("sin", "");
+            let cos = recorder.register_channel("cos", "");
+            let short = recorder.register_channel("short", "");
+
+            // Wrap the buffers so chronological reordering is exercised
+            for i in 0..100 {
+                let x = f64::from(i);
+                recorder.log(a, x * 0.5 + 3.0);
+                recorder.log(same, x);
+                recorder.log(anti, -2.0 * x);
+                let phase = core::f64::consts::TAU * x / 64.0;
+                recorder.log(sin, libm::sin(phase));
+                recorder.log(cos, libm::cos(phase));
+            }
+            recorder.log(short, 1.0);
+            recorder.log(short, 2.0);
+
+            let r = recorder.correlate(a, same).expect("equal lengths");
+            assert!((r - 1.0).abs() < 1e-12);
+            let r = recorder.correlate(a, anti).expect("equal lengths");
+            assert!((r + 1.0).abs() < 1e-12);
+            let r = recorder.correlate(sin, cos).expect("equal lengths");
+            assert!(r.abs() < 1e-10, "r = {r}");
+
+            assert_eq!(recorder.correlate(a, short), None);
+            assert_eq!(recorder.correlate(a, ChannelId::INVALID), None);
+        }
+
+        #[test]
+        fn test_log_ranged() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let slip = recorder.register_channel_full(
+                ChannelDescriptor::new("tire.fl.slip_ratio", "")
+                    .with_expected_min(-1.0)
+                    .with_expected_max(1.0),
+            );
+            let temp = recorder.register_channel_full(
+                ChannelDescriptor::new("engine.temp", "K").with_expected_max(390.0),
+            );
+            let speed = recorder.register_channel("vehicle.speed", "m/s");
+
+            let mut violations = Vec::new();
+            let mut record = |id: ChannelId, v: f64, min: f64, max: f64| {
+                violations.push((id, v, min, max));
+            };
+
+            recorder.log_ranged(slip, 0.5, &mut record);
+            recorder.log_ranged(slip, 1.0, &mut record);
+            recorder.log_ranged(slip, 1.5, &mut record);
+            recorder.log_ranged(temp, 400.0, &mut record);
+            recorder.log_ranged(temp, -10.0, &mut record);
+            recorder.log_ranged(speed, 1e9, &mut record);
+
+            assert_eq!(
+                violations,
+                vec![
+                    (slip, 1.5, -1.0, 1.0),
+                    (temp, 400.0, f64::NEG_INFINITY, 390.0),
+                ]
+            );
+            // Every value is logged regardless of range
+            assert_eq!(recorder.sample_count(slip), 3);
+            assert_eq!(recorder.sample_count(speed), 1);
+        }
+
         #[test]
         fn test_log_and_retrieve() {
             let config = RingBufferConfig {
@@ -226,7 +1002,7 @@ mod enabled {
             let id = recorder.register_channel("test", "unit");
 
             for i in 0..5 {
-                recorder.log(id, i as f64);
+                recorder.log(id, f64::from(i));
             }
 
             let data = recorder.get_channel_data(id).expect("data should exist");
@@ -247,7 +1023,7 @@ mod enabled {
 
             // Write 8 values into a buffer of size 5
             for i in 0..8 {
-                recorder.log(id, i as f64);
+                recorder.log(id, f64::from(i));
             }
 
             // Should have values 3, 4, 5, 6, 7 in chronological order
@@ -273,7 +1049,7 @@ mod enabled {
 
             // Record a sine wave
             for i in 0..100 {
-                let t = i as f64 / 100.0 * 2.0 * PI;
+                let t = f64::from(i) / 100.0 * 2.0 * PI;
                 recorder.log(id, libm::sin(t));
             }
 
@@ -286,10 +1062,7 @@ mod enabled {
                 let expected = libm::sin(t);
                 assert!(
                     (v - expected).abs() < 1e-10,
-                    "mismatch at {}: {} != {}",
-                    i,
-                    v,
-                    expected
+                    "mismatch at {i}: {v} != {expected}"
                 );
             }
         }
@@ -318,22 +1091,1087 @@ mod enabled {
         }
 
         #[test]
-        fn test_clear() {
+        fn test_register_beyond_max_channels() {
             let config = RingBufferConfig {
-                samples_per_channel: 10,
+                samples_per_channel: 4,
+                max_channels: 3,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            for i in 0..3 {
+                let id = recorder.register_channel("ch", "");
+                assert!(id.is_valid());
+                assert_eq!(id.index(), i);
+            }
+
+            let overflow = recorder.register_channel("overflow", "");
+            assert_eq!(overflow, ChannelId::INVALID);
+            assert!(!overflow.is_valid());
+            assert_eq!(recorder.channel_count(), 3);
+        }
+
+        #[test]
+        fn test_log_to_invalid_is_noop() {
+            let config = RingBufferConfig {
+                samples_per_channel: 4,
+                max_channels: 1,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            let id = recorder.register_channel("ch", "");
+            let invalid = recorder.register_channel("overflow", "");
+
+            recorder.log(invalid, 1.0);
+            assert_eq!(recorder.sample_count(id), 0);
+            assert_eq!(recorder.sample_count(invalid), 0);
+            assert!(recorder.get_channel_data(invalid).is_none());
+        }
+
+        #[test]
+        fn test_log_if_changed_threshold() {
+            let config = RingBufferConfig {
+                samples_per_channel: 100,
                 max_channels: 4,
             };
             let mut recorder = MemoryRecorder::new(config);
-            let id = recorder.register_channel("test", "unit");
+            let id = recorder.register_channel("ramp", "");
 
-            recorder.log(id, 42.0);
+            // Ramp 0.0, 0.1, ..., 2.0 with a 0.5 threshold
+            for i in 0..=20 {
+                recorder.log_if_changed(id, f64::from(i) * 0.1, 0.5);
+            }
+
+            let data = recorder.get_channel_data(id).expect("data should exist");
+            let expected = [0.0, 0.5, 1.0, 1.5, 2.0];
+            assert_eq!(data.len(), expected.len());
+            for (v, e) in data.iter().zip(expected) {
+                assert!((v - e).abs() < 1e-9, "{v} != {e}");
+            }
+        }
+
+        #[test]
+        fn test_log_if_changed_first_call_logs() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let id = recorder.register_channel("test", "");
+
+            recorder.log_if_changed(id, 5.0, f64::INFINITY);
+            recorder.log_if_changed(id, 6.0, f64::INFINITY);
             assert_eq!(recorder.sample_count(id), 1);
 
             recorder.clear();
-            assert_eq!(recorder.sample_count(id), 0);
+            recorder.log_if_changed(id, 6.0, f64::INFINITY);
+            assert_eq!(recorder.sample_count(id), 1);
         }
-    }
-}
 
-#[cfg(feature = "enable_telemetry")]
-pub use enabled::*;
+        #[test]
+        fn test_snapshot_latest_value() {
+            let config = RingBufferConfig {
+                samples_per_channel: 3,
+                max_channels: 4,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            let id = recorder.register_channel("test", "unit");
+            let idle = recorder.register_channel("idle", "unit");
+
+            for i in 1..=5 {
+                recorder.log(id, f64::from(i));
+            }
+
+            let snapshot = recorder.snapshot();
+            assert_eq!(snapshot.len(), 2);
+            assert_eq!(snapshot.get(id), Some(5.0));
+            assert!(snapshot.get(idle).is_some_and(f64::is_nan));
+            assert_eq!(snapshot.get(ChannelId::INVALID), None);
+
+            // Ring buffer is untouched
+            let data = recorder.get_channel_data(id).expect("data should exist");
+            assert_eq!(data, [3.0, 4.0, 5.0]);
+        }
+
+        #[test]
+        fn test_bytes_round_trip() {
+            let config = RingBufferConfig {
+                samples_per_channel: 4,
+                max_channels: 3,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            let speed = recorder.register_channel("vehicle.speed", "m/s");
+            let slip = recorder.register_channel("tire.fl.slip", "");
+            let temp = recorder.register_channel_full(
+                ChannelDescriptor::new("engine.temp", "K")
+                    .with_description("Coolant temperature")
+                    .with_expected_max(390.0),
+            );
+
+            for i in 0..6 {
+                recorder.log(speed, f64::from(i));
+            }
+            recorder.log(slip, 0.25);
+
+            let bytes = recorder.to_bytes();
+            let decoded = MemoryRecorder::from_bytes(&bytes).expect("valid encoding");
+
+            assert_eq!(decoded.channel_count(), 3);
+            for id in [speed, slip, temp] {
+                let a = recorder.channel_metadata(id).expect("metadata");
+                let b = decoded.channel_metadata(id).expect("metadata");
+                assert_eq!(a.name, b.name);
+                assert_eq!(a.unit, b.unit);
+                assert_eq!(a.description, b.description);
+                assert_eq!(a.expected_min, b.expected_min);
+                assert_eq!(a.expected_max, b.expected_max);
+                assert_eq!(recorder.get_channel_data(id), decoded.get_channel_data(id));
+            }
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+
+        #[test]
+        fn test_from_bytes_errors() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 2,
+                max_channels: 1,
+            });
+            let id = recorder.register_channel("test", "unit");
+            recorder.log(id, 1.0);
+            let bytes = recorder.to_bytes();
+
+            for len in [0, 4, 12, bytes.len() - 1] {
+                assert_eq!(
+                    MemoryRecorder::from_bytes(&bytes[..len]).err(),
+                    Some(RecorderDecodeError::Truncated)
+                );
+            }
+
+            let mut bad_magic = bytes.clone();
+            bad_magic[0] = b'X';
+            assert_eq!(
+                MemoryRecorder::from_bytes(&bad_magic).err(),
+                Some(RecorderDecodeError::BadMagic)
+            );
+
+            let mut bad_utf8 = bytes;
+            bad_utf8[20] = 0xFF;
+            assert_eq!(
+                MemoryRecorder::from_bytes(&bad_utf8).err(),
+                Some(RecorderDecodeError::InvalidMetadata { channel: 0 })
+            );
+        }
+
+        #[test]
+        fn test_from_bytes_huge_sample_count() {
+            // A 16-byte header claiming u32::MAX samples must not allocate
+            // storage for them
+            let mut header = RECORDER_MAGIC.to_vec();
+            header.extend_from_slice(&0u32.to_ne_bytes());
+            header.extend_from_slice(&u32::MAX.to_ne_bytes());
+            let decoded = MemoryRecorder::from_bytes(&header).expect("no channels");
+            assert_eq!(decoded.channel_count(), 0);
+
+            // Neither may a channel claiming a u32::MAX capacity
+            let mut channel = [0; MIN_CHANNEL_BYTES];
+            channel[32..36].copy_from_slice(&u32::MAX.to_ne_bytes());
+            let mut one_channel = RECORDER_MAGIC.to_vec();
+            one_channel.extend_from_slice(&1u32.to_ne_bytes());
+            one_channel.extend_from_slice(&4u32.to_ne_bytes());
+            one_channel.extend_from_slice(&channel);
+            assert_eq!(
+                MemoryRecorder::from_bytes(&one_channel).err(),
+                Some(RecorderDecodeError::Truncated)
+            );
+        }
+
+        #[test]
+        fn test_from_bytes_wrapped_ring() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 4,
+                max_channels: 1,
+            });
+            let id = recorder.register_channel("ab", "c");
+            for i in 0..4 {
+                recorder.log(id, f64::from(i));
+            }
+
+            // Encodings may hold a full ring whose oldest sample is mid-buffer
+            let mut bytes = recorder.to_bytes();
+            let write_pos = 16 + (4 + 2) + (4 + 1) + 4 + 4 + 16 + 4;
+            bytes[write_pos..write_pos + 4].copy_from_slice(&1u32.to_ne_bytes());
+
+            let decoded = MemoryRecorder::from_bytes(&bytes).expect("valid encoding");
+            assert_eq!(decoded.get_channel_data(id), Some(vec![1.0, 2.0, 3.0, 0.0]));
+        }
+
+        #[test]
+        fn test_export_influxdb_line_protocol() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let speed = recorder.register_channel("vehicle speed", "m/s");
+            let slip = recorder.register_channel("tire,fl=slip", "");
+            recorder.log(speed, 12.5);
+            recorder.log(speed, 13.0);
+            recorder.log(slip, 0.1);
+            recorder.log(slip, f64::NAN);
+
+            let output = recorder.export_influxdb_line_protocol("sim");
+            let lines: Vec<&str> = output.lines().collect();
+            assert_eq!(lines.len(), 3);
+
+            // `<measurement>,channel=<name> value=<f64> <timestamp>`
+            let mut fields = lines[0].split(' ');
+            let series = fields.next().expect("series");
+            let value = fields.next().expect("value field");
+            let timestamp = fields.next().expect("timestamp");
+            assert!(fields.next().is_none());
+
+            assert_eq!(series.split(',').next(), Some("sim"));
+            let parsed: f64 = value
+                .strip_prefix("value=")
+                .expect("value prefix")
+                .parse()
+                .expect("numeric value");
+            assert!((parsed - 12.5).abs() < 1e-12);
+            assert_eq!(timestamp, "0");
+
+            assert_eq!(lines[0], "sim,channel=vehicle_speed value=12.5 0");
+            assert_eq!(lines[1], "sim,channel=vehicle_speed value=13 1");
+            assert_eq!(lines[2], "sim,channel=tire\\,fl\\=slip value=0.1 0");
+
+            let escaped = recorder.export_influxdb_line_protocol("run 1,a");
+            assert!(escaped.starts_with("run\\ 1\\,a,channel="));
+        }
+
+        #[test]
+        fn test_resize_channel() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 5,
+                max_channels: 2,
+            });
+            let id = recorder.register_channel("brake.temp", "K");
+            let other = recorder.register_channel("speed", "m/s");
+            for i in 0..5 {
+                recorder.log(id, f64::from(i));
+                recorder.log(other, f64::from(i));
+            }
+            assert!(recorder.channel_is_overflowed(id));
+
+            recorder.resize_channel(id, 20).expect("registered");
+            for i in 5..10 {
+                recorder.log(id, f64::from(i));
+                recorder.log(other, f64::from(i));
+            }
+            let expected: Vec<f64> = (0..10).map(f64::from).collect();
+            assert_eq!(recorder.get_channel_data(id), Some(expected.clone()));
+            assert_eq!(recorder.get_channel_data(other).map(|d| d.len()), Some(5));
+
+            // The encoding keeps the grown channel whole
+            let decoded = MemoryRecorder::from_bytes(&recorder.to_bytes()).expect("valid encoding");
+            assert_eq!(decoded.get_channel_data(id), Some(expected));
+
+            recorder.resize_channel(id, 3).expect("registered");
+            assert_eq!(recorder.get_channel_data(id), Some(vec![7.0, 8.0, 9.0]));
+
+            // Per-channel sizes survive encoding
+            let mut decoded =
+                MemoryRecorder::from_bytes(&recorder.to_bytes()).expect("valid encoding");
+            assert_eq!(decoded.get_channel_data(id), Some(vec![7.0, 8.0, 9.0]));
+            for i in 10..15 {
+                decoded.log(id, f64::from(i));
+                decoded.log(other, f64::from(i));
+            }
+            assert_eq!(decoded.get_channel_data(id), Some(vec![12.0, 13.0, 14.0]));
+            assert_eq!(decoded.sample_count(other), 5);
+
+            assert_eq!(
+                recorder.resize_channel(ChannelId::new(2), 10),
+                Err(RecorderError::ChannelNotFound(ChannelId::new(2)))
+            );
+        }
+
+        #[test]
+        fn test_trim_to_time_range() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 100,
+                max_channels: 4,
+            });
+            let time = recorder.register_channel("time", "s");
+            let speed = recorder.register_channel("speed", "m/s");
+            let gear = recorder.register_channel("gear", "");
+            for i in 0..100 {
+                recorder.log(time, f64::from(i));
+                recorder.log(speed, f64::from(2 * i));
+            }
+            recorder.log(gear, 3.0);
+
+            let removed = recorder.trim_to_time_range(time, 25.0, 75.0);
+            assert_eq!(removed, 2 * 49);
+            assert_eq!(recorder.sample_count(time), 51);
+            assert_eq!(recorder.sample_count(speed), 51);
+            // Not logged alongside `time`, so left alone
+            assert_eq!(recorder.sample_count(gear), 1);
+
+            let pairs = recorder
+                .get_channel_data_with_timestamps(speed, time)
+                .expect("aligned channels");
+            assert_eq!(pairs.len(), 51);
+            assert!(pairs.iter().all(|&(t, _)| (25.0..=75.0).contains(&t)));
+            assert!(pairs.iter().all(|&(t, v)| (v - 2.0 * t).abs() < 1e-12));
+            assert_eq!(recorder.get_channel_data_with_timestamps(gear, time), None);
+
+            // The capacity survives, so logging continues after the window
+            recorder.log(time, 100.0);
+            assert_eq!(recorder.sample_count(time), 52);
+            assert_eq!(recorder.trim_to_time_range(ChannelId::new(3), 0.0, 1.0), 0);
+        }
+
+        #[test]
+        fn test_deregister_channel_reuses_slot() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 4,
+                max_channels: 3,
+            });
+            let speed = recorder.register_channel("speed", "m/s");
+            let old = recorder.register_channel("engine.rpm", "rpm");
+            let temp = recorder.register_channel("brake.temp", "K");
+            for id in [speed, old, temp] {
+                recorder.log(id, 1.0);
+            }
+
+            recorder.deregister_channel(old).expect("registered");
+            assert_eq!(recorder.channel_count(), 2);
+            assert!(!recorder.channel_exists(old));
+            assert_eq!(recorder.get_channel_data(old), None);
+            assert!(recorder.channel_metadata(old).is_none());
+            assert_eq!(
+                recorder.deregister_channel(old),
+                Err(RecorderError::ChannelNotFound(old))
+            );
+            assert_eq!(
+                recorder.resize_channel(old, 8),
+                Err(RecorderError::ChannelNotFound(old))
+            );
+            recorder.log(old, 2.0);
+            assert_eq!(recorder.sample_count(old), 0);
+
+            let gear = recorder.register_channel("gear", "");
+            assert_eq!(gear.index(), 1);
+            assert_eq!(recorder.channel_count(), 3);
+            assert_eq!(recorder.get_channel_data(gear), Some(vec![]));
+            assert_eq!(
+                recorder.channel_metadata(gear).map(|m| m.name.as_str()),
+                Some("gear")
+            );
+            recorder.log(gear, 3.0);
+            assert_eq!(recorder.get_channel_data(gear), Some(vec![3.0]));
+            assert_eq!(recorder.get_channel_data(temp), Some(vec![1.0]));
+
+            // Full again, with no free slot to reuse
+            assert!(!recorder.register_channel("extra", "").is_valid());
+        }
+
+        #[test]
+        fn test_deregistered_slot_round_trip() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 4,
+                max_channels: 2,
+            });
+            let a = recorder.register_channel("a", "m");
+            let b = recorder.register_channel("b", "s");
+            recorder.log(a, 1.0);
+            recorder.log(b, 2.0);
+            recorder.deregister_channel(a).expect("registered");
+
+            let names: Vec<&str> = recorder.all_metadata().map(|m| m.name.as_str()).collect();
+            assert_eq!(names, ["b"]);
+            let columns = recorder.export_columns();
+            assert_eq!(columns.len(), 1);
+            assert_eq!(columns[0].0.name, "b");
+            assert_eq!(columns[0].1, [2.0]);
+            assert_eq!(recorder.export_columns_aligned(2).len(), 1);
+
+            let mut decoded =
+                MemoryRecorder::from_bytes(&recorder.to_bytes()).expect("valid encoding");
+            assert_eq!(decoded.channel_count(), 1);
+            assert!(!decoded.channel_exists(a));
+            assert!(decoded.channel_exists(b));
+            assert_eq!(decoded.get_channel_data(b), Some(vec![2.0]));
+            decoded.log(a, 3.0);
+            assert_eq!(decoded.sample_count(a), 0);
+
+            // The freed slot is still reusable after decoding
+            let c = decoded.register_channel("c", "");
+            assert_eq!(c, a);
+        }
+
+        #[test]
+        fn test_channel_state_helpers() {
+            let mut recorder = MemoryRecorder::new(RingBufferConfig {
+                samples_per_channel: 3,
+                max_channels: 4,
+            });
+            let fresh = recorder.register_channel("fresh", "");
+            let single = recorder.register_channel("single", "");
+            let full = recorder.register_channel("full", "");
+            recorder.log(single, 1.0);
+            for i in 0..5 {
+                recorder.log(full, f64::from(i));
+            }
+
+            assert!(recorder.channel_exists(fresh));
+            assert!(!recorder.channel_is_active(fresh));
+            assert!(!recorder.channel_is_overflowed(fresh));
+
+            assert!(recorder.channel_exists(single));
+            assert!(recorder.channel_is_active(single));
+            assert!(!recorder.channel_is_overflowed(single));
+
+            assert!(recorder.channel_exists(full));
+            assert!(recorder.channel_is_active(full));
+            assert!(recorder.channel_is_overflowed(full));
+
+            for id in [ChannelId::INVALID, ChannelId::new(3)] {
+                assert!(!recorder.channel_exists(id));
+                assert!(!recorder.channel_is_active(id));
+                assert!(!recorder.channel_is_overflowed(id));
+            }
+        }
+
+        #[test]
+        fn test_export_influxdb_tags() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let temp = recorder.register_channel_full(
+                ChannelDescriptor::new("tire.temp", "K")
+                    .with_tag("wheel", "FL")
+                    .and_then(|desc| desc.with_tag("compound", "soft"))
+                    .expect("within the tag limit"),
+            );
+            recorder.log(temp, 350.0);
+
+            let output = recorder.export_influxdb_line_protocol("sim");
+            let line = output.lines().next().expect("one line");
+            let (series, fields) = line.split_once(' ').expect("tags and fields");
+            assert_eq!(series, "sim,channel=tire.temp,wheel=FL,compound=soft");
+            assert_eq!(fields, "value=350 0");
+
+            let meta = recorder.channel_metadata(temp).expect("registered");
+            assert_eq!(meta.tags.len(), 2);
+            assert_eq!(meta.tags[0], ("wheel".into(), "FL".into()));
+
+            let decoded = MemoryRecorder::from_bytes(&recorder.to_bytes()).expect("valid encoding");
+            let decoded_meta = decoded.channel_metadata(temp).expect("decoded");
+            assert_eq!(decoded_meta.tags, meta.tags);
+        }
+
+        #[test]
+        fn test_export_columns_padding() {
+            let config = RingBufferConfig {
+                samples_per_channel: 10,
+                max_channels: 4,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            let a = recorder.register_channel("a", "m");
+            let b = recorder.register_channel("b", "s");
+            recorder.register_channel("c", "");
+            for i in 0..4 {
+                recorder.log(a, f64::from(i));
+            }
+            recorder.log(b, 7.0);
+
+            let columns = recorder.export_columns();
+            assert_eq!(columns.len(), 3);
+            assert!(columns.iter().all(|(_, data)| data.len() == 4));
+            assert_eq!(columns[0].0.name, "a");
+            assert_eq!(columns[1].0.unit, "s");
+            assert_eq!(columns[0].1, [0.0, 1.0, 2.0, 3.0]);
+            assert!((columns[1].1[0] - 7.0).abs() < 1e-12);
+            assert!(columns[1].1[1..].iter().all(|v| v.is_nan()));
+            assert!(columns[2].1.iter().all(|v| v.is_nan()));
+        }
+
+        #[test]
+        fn test_export_columns_aligned() {
+            let mut recorder = MemoryRecorder::with_defaults();
+            let a = recorder.register_channel("a", "");
+            for i in 0..5 {
+                recorder.log(a, f64::from(i));
+            }
+
+            let truncated = recorder.export_columns_aligned(3);
+            assert_eq!(truncated, [[0.0, 1.0, 2.0]]);
+
+            let padded = recorder.export_columns_aligned(7);
+            assert_eq!(padded[0].len(), 7);
+            assert_eq!(padded[0][..5], [0.0, 1.0, 2.0, 3.0, 4.0]);
+            assert!(padded[0][5..].iter().all(|v| v.is_nan()));
+        }
+
+        #[test]
+        fn test_clear() {
+            let config = RingBufferConfig {
+                samples_per_channel: 10,
+                max_channels: 4,
+            };
+            let mut recorder = MemoryRecorder::new(config);
+            let id = recorder.register_channel("test", "unit");
+
+            recorder.log(id, 42.0);
+            assert_eq!(recorder.sample_count(id), 1);
+
+            recorder.clear();
+            assert_eq!(recorder.sample_count(id), 0);
+        }
+    }
+}
+
+#[cfg(feature = "enable_telemetry")]
+pub use enabled::*;
+
+pub use fixed::StaticMemoryRecorder;
+
+#[cfg(feature = "std")]
+pub use file::FileRecorder;
+
+#[cfg(feature = "std")]
+mod file {
+    use crate::channel::{ChannelDescriptor, ChannelId};
+    use crate::TelemetryProvider;
+    use core::fmt::Write as _;
+    use std::fs::File;
+    use std::io::{self, BufWriter, Write};
+    use std::path::Path;
+    use std::string::String;
+    use std::vec::Vec;
+    use vd_math::Vec3;
+
+    /// Escapes `text` into the single whitespace-free token described on
+    /// [`FileRecorder`].
+    fn escape_token(text: &str) -> String {
+        if text.is_empty() {
+            return String::from("\\e");
+        }
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                ' ' => out.push_str("\\s"),
+                c if c.is_whitespace() => {
+                    let _ = write!(out, "\\u{{{:x}}}", u32::from(c));
+                }
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    /// Reverses [`escape_token`], returning `None` for a malformed escape.
+    fn unescape_token(token: &str) -> Option<String> {
+        if token == "\\e" {
+            return Some(String::new());
+        }
+        let mut out = String::with_capacity(token.len());
+        let mut chars = token.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                out.push(c);
+                continue;
+            }
+            match chars.next()? {
+                '\\' => out.push('\\'),
+                's' => out.push(' '),
+                'u' => {
+                    let rest = chars.as_str().strip_prefix('{')?;
+                    let (hex, tail) = rest.split_once('}')?;
+                    out.push(char::from_u32(u32::from_str_radix(hex, 16).ok()?)?);
+                    chars = tail.chars();
+                }
+                _ => return None,
+            }
+        }
+        Some(out)
+    }
+
+    /// Recorder that streams every sample to a text file.
+    ///
+    /// Registration writes a `# CH {id} {name} {unit}` comment line and each
+    /// logged value a `{id},{value}` data line, in call order. Writes are
+    /// buffered; call [`TelemetryProvider::flush`] to push them to disk.
+    /// Names and units are escaped so that each is a single
+    /// whitespace-free token: backslashes become `\\`, spaces `\s`, other
+    /// whitespace `\u{hex}`, and an empty string `\e`.
+    ///
+    /// Logging cannot report I/O errors, so the first failure is remembered
+    /// and returned by the next `flush`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vd_telemetry::{FileRecorder, TelemetryProvider};
+    ///
+    /// let mut recorder = FileRecorder::open("run.tlm")?;
+    /// let speed = recorder.register_channel("vehicle.speed", "m/s");
+    /// recorder.log(speed, 12.5);
+    /// recorder.flush().expect("flushed");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[derive(Debug)]
+    pub struct FileRecorder {
+        writer: BufWriter<File>,
+        channel_count: u32,
+        /// Set once any write fails; reported by `flush`.
+        failed: bool,
+    }
+
+    impl FileRecorder {
+        /// Creates (or truncates) the file at `path` and records into it.
+        ///
+        /// # Errors
+        ///
+        /// Returns the error from [`File::create`] if the file cannot be
+        /// opened for writing.
+        pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+            Ok(Self {
+                writer: BufWriter::new(File::create(path)?),
+                channel_count: 0,
+                failed: false,
+            })
+        }
+
+        /// Returns the number of registered channels.
+        #[must_use]
+        pub const fn channel_count(&self) -> usize {
+            self.channel_count as usize
+        }
+    }
+
+    impl TelemetryProvider for FileRecorder {
+        fn register_channel_full(&mut self, meta: ChannelDescriptor<'_>) -> ChannelId {
+            let id = ChannelId::new(self.channel_count);
+            self.channel_count += 1;
+            let result = writeln!(
+                self.writer,
+                "# CH {} {} {}",
+                id.index(),
+                escape_token(meta.name),
+                escape_token(meta.unit)
+            );
+            self.failed |= result.is_err();
+            id
+        }
+
+        #[inline]
+        fn log(&mut self, id: ChannelId, value: f64) {
+            if id.index() >= self.channel_count {
+                return;
+            }
+            let result = writeln!(self.writer, "{},{value}", id.index());
+            self.failed |= result.is_err();
+        }
+
+        #[inline]
+        fn log_vector(&mut self, id_x: ChannelId, id_y: ChannelId, id_z: ChannelId, vec: &Vec3) {
+            self.log(id_x, vec.x);
+            self.log(id_y, vec.y);
+            self.log(id_z, vec.z);
+        }
+
+        fn flush(&mut self) -> Result<(), ()> {
+            let result = self.writer.flush();
+            self.failed |= result.is_err();
+            if core::mem::take(&mut self.failed) {
+                Err(())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(feature = "enable_telemetry")]
+    impl super::MemoryRecorder {
+        /// Reads a file written by [`FileRecorder`] back into memory.
+        ///
+        /// Channels are registered in file order with their names and units;
+        /// data lines are logged in order. Blank lines and other `#` comments
+        /// are skipped. The recorder is sized from the file, with exactly
+        /// its channels and room for every sample of the longest channel.
+        ///
+        /// # Errors
+        ///
+        /// Returns an I/O error from `reader`, or one of kind
+        /// [`io::ErrorKind::InvalidData`] for a malformed line or a data line
+        /// naming an unregistered channel.
+        pub fn from_file_recording(reader: impl io::BufRead) -> io::Result<Self> {
+            let invalid = |line: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    std::format!("malformed telemetry line: {line:?}"),
+                )
+            };
+
+            // Read everything first so the recorder can be sized to fit
+            let mut channels = Vec::new();
+            let mut sample_counts: Vec<usize> = Vec::new();
+            let mut samples = Vec::new();
+            for line in reader.lines() {
+                let line = line?;
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                if let Some(header) = line.strip_prefix("# CH ") {
+                    let mut parts = header.split_whitespace();
+                    let id = parts.next().and_then(|id| id.parse::<usize>().ok());
+                    let name = parts.next().and_then(unescape_token);
+                    let unit = parts.next().map_or(Some(String::new()), unescape_token);
+                    match (id, name, unit) {
+                        (Some(id), Some(name), Some(unit)) if id == channels.len() => {
+                            channels.push((name, unit));
+                            sample_counts.push(0);
+                        }
+                        _ => return Err(invalid(line)),
+                    }
+                    continue;
+                }
+                if line.starts_with('#') {
+                    continue;
+                }
+
+                let (id, value) = line
+                    .split_once(',')
+                    .and_then(|(id, value)| Some((id.parse::<u32>().ok()?, value.parse().ok()?)))
+                    .ok_or_else(|| invalid(line))?;
+                let count = sample_counts
+                    .get_mut(id as usize)
+                    .ok_or_else(|| invalid(line))?;
+                *count += 1;
+                samples.push((ChannelId::new(id), value));
+            }
+
+            let mut recorder = Self::new(super::RingBufferConfig {
+                samples_per_channel: sample_counts.iter().copied().max().unwrap_or(0),
+                max_channels: channels.len(),
+            });
+            for (name, unit) in &channels {
+                recorder.register_channel(name, unit);
+            }
+            for (id, value) in samples {
+                recorder.log(id, value);
+            }
+            Ok(recorder)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::vec::Vec;
+
+        fn temp_path(name: &str) -> std::path::PathBuf {
+            std::env::temp_dir().join(std::format!(
+                "vd_telemetry_{}_{name}.tlm",
+                std::process::id()
+            ))
+        }
+
+        #[test]
+        fn test_file_recorder_writes_lines() {
+            let path = temp_path("lines");
+            let mut recorder = FileRecorder::open(&path).expect("temp file");
+            let speed = recorder.register_channel("vehicle.speed", "m/s");
+            let accel = crate::VectorChannelIds::register(&mut recorder, "accel", "m/s^2");
+            recorder.log(speed, 12.5);
+            accel.log(&mut recorder, &Vec3::new(1.0, -2.0, 0.5));
+            recorder.log(ChannelId::INVALID, 1.0);
+            assert_eq!(recorder.flush(), Ok(()));
+            assert_eq!(recorder.channel_count(), 4);
+
+            let contents = std::fs::read_to_string(&path).expect("readable");
+            let _ = std::fs::remove_file(&path);
+            let lines: Vec<&str> = contents.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "# CH 0 vehicle.speed m/s",
+                    "# CH 1 accel.x m/s^2",
+                    "# CH 2 accel.y m/s^2",
+                    "# CH 3 accel.z m/s^2",
+                    "0,12.5",
+                    "1,1",
+                    "2,-2",
+                    "3,0.5",
+                ]
+            );
+        }
+
+        #[cfg(feature = "enable_telemetry")]
+        #[test]
+        fn test_file_recording_round_trip() {
+            use crate::MemoryRecorder;
+
+            let path = temp_path("round_trip");
+            let mut file = FileRecorder::open(&path).expect("temp file");
+            let mut memory = MemoryRecorder::with_defaults();
+            let names = [("speed", "m/s"), ("steer", "rad")];
+            let file_ids: Vec<ChannelId> = names
+                .iter()
+                .map(|&(name, unit)| file.register_channel(name, unit))
+                .collect();
+            let memory_ids: Vec<ChannelId> = names
+                .iter()
+                .map(|&(name, unit)| memory.register_channel(name, unit))
+                .collect();
+            for i in 0..20_u32 {
+                let value = f64::from(i) * 0.1;
+                let channel = i as usize % 2;
+                file.log(file_ids[channel], value);
+                memory.log(memory_ids[channel], value);
+            }
+            assert_eq!(file.flush(), Ok(()));
+            drop(file);
+
+            let reader = std::io::BufReader::new(File::open(&path).expect("readable"));
+            let parsed = MemoryRecorder::from_file_recording(reader).expect("valid recording");
+            let _ = std::fs::remove_file(&path);
+
+            assert_eq!(parsed.channel_count(), 2);
+            for &id in &memory_ids {
+                let meta = parsed.channel_metadata(id).expect("registered");
+                let expected = memory.channel_metadata(id).expect("registered");
+                assert_eq!(meta.name, expected.name);
+                assert_eq!(meta.unit, expected.unit);
+                assert_eq!(parsed.get_channel_data(id), memory.get_channel_data(id));
+            }
+
+            let bad = MemoryRecorder::from_file_recording(&b"# CH 0 a m\n1,2.0\n"[..]);
+            assert_eq!(
+                bad.err().map(|e| e.kind()),
+                Some(std::io::ErrorKind::InvalidData)
+            );
+        }
+
+        #[cfg(feature = "enable_telemetry")]
+        #[test]
+        fn test_file_recording_escapes_names() {
+            use crate::MemoryRecorder;
+
+            let path = temp_path("escapes");
+            let mut file = FileRecorder::open(&path).expect("temp file");
+            let names = [("front left\\tyre", "N m"), ("gear", ""), ("", "\t")];
+            let ids: Vec<ChannelId> = names
+                .iter()
+                .map(|&(name, unit)| file.register_channel(name, unit))
+                .collect();
+            // More samples than the default configuration keeps
+            let samples = crate::RingBufferConfig::default().samples_per_channel + 5;
+            for i in 0..samples {
+                file.log(ids[0], i as f64);
+            }
+            file.log(ids[1], 3.0);
+            assert_eq!(file.flush(), Ok(()));
+            drop(file);
+
+            let contents = std::fs::read_to_string(&path).expect("readable");
+            let _ = std::fs::remove_file(&path);
+            let lines: Vec<&str> = contents.lines().take(3).collect();
+            assert_eq!(
+                lines,
+                [
+                    "# CH 0 front\\sleft\\\\tyre N\\sm",
+                    "# CH 1 gear \\e",
+                    "# CH 2 \\e \\u{9}",
+                ]
+            );
+
+            let parsed =
+                MemoryRecorder::from_file_recording(contents.as_bytes()).expect("valid recording");
+            assert_eq!(parsed.channel_count(), 3);
+            for (&id, &(name, unit)) in ids.iter().zip(&names) {
+                let meta = parsed.channel_metadata(id).expect("registered");
+                assert_eq!((meta.name.as_str(), meta.unit.as_str()), (name, unit));
+            }
+            assert_eq!(parsed.sample_count(ids[0]), samples);
+            assert_eq!(parsed.get_channel_data(ids[1]), Some(std::vec![3.0]));
+
+            let bad = MemoryRecorder::from_file_recording(&b"# CH 0 a\\x m\n"[..]);
+            assert_eq!(
+                bad.err().map(|e| e.kind()),
+                Some(std::io::ErrorKind::InvalidData)
+            );
+        }
+    }
+}
+
+mod fixed {
+    use crate::channel::{ChannelDescriptor, ChannelId};
+    use crate::ring_buffer::RingBuffer;
+    use crate::TelemetryProvider;
+    use vd_math::Vec3;
+
+    /// Fixed-capacity ring buffer recorder that needs no heap allocation.
+    ///
+    /// Holds up to `CHANNELS` channels of `SAMPLES` samples each, stored as
+    /// one ring per channel. Once a ring wraps, its samples are no longer
+    /// contiguous, so [`StaticMemoryRecorder::get_channel_data`] returns
+    /// them as two slices.
+    ///
+    /// Channel names and units are not stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vd_telemetry::{StaticMemoryRecorder, TelemetryProvider};
+    ///
+    /// let mut recorder = StaticMemoryRecorder::<2, 3>::new();
+    /// let id = recorder.register_channel("speed", "m/s");
+    /// for v in [1.0, 2.0, 3.0, 4.0] {
+    ///     recorder.log(id, v);
+    /// }
+    /// let (older, newer) = recorder.get_channel_data(id);
+    /// assert!(older.iter().chain(newer).copied().eq([2.0, 3.0, 4.0]));
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct StaticMemoryRecorder<const CHANNELS: usize, const SAMPLES: usize> {
+        /// Ring buffer of each channel.
+        buffers: [RingBuffer<f64, SAMPLES>; CHANNELS],
+        channel_count: usize,
+    }
+
+    impl<const CHANNELS: usize, const SAMPLES: usize> StaticMemoryRecorder<CHANNELS, SAMPLES> {
+        const EMPTY_RING: RingBuffer<f64, SAMPLES> = RingBuffer::with_storage([0.0; SAMPLES]);
+
+        /// Creates an empty recorder.
+        #[must_use]
+        pub const fn new() -> Self {
+            Self {
+                buffers: [Self::EMPTY_RING; CHANNELS],
+                channel_count: 0,
+            }
+        }
+
+        /// Returns the number of registered channels.
+        #[must_use]
+        pub const fn channel_count(&self) -> usize {
+            self.channel_count
+        }
+
+        /// Returns the number of samples stored for a channel.
+        #[must_use]
+        pub fn sample_count(&self, id: ChannelId) -> usize {
+            self.buffers
+                .get(id.index() as usize)
+                .map_or(0, RingBuffer::len)
+        }
+
+        /// Returns the recorded samples of a channel as two slices which,
+        /// concatenated, are in chronological order (oldest first).
+        ///
+        /// Returns empty slices for unknown channels.
+        #[must_use]
+        pub fn get_channel_data(&self, id: ChannelId) -> (&[f64], &[f64]) {
+            let idx = id.index() as usize;
+            if idx >= self.channel_count {
+                return (&[], &[]);
+            }
+            self.buffers[idx].as_slices()
+        }
+
+        /// Clears all recorded data but keeps channel registrations.
+        pub fn clear(&mut self) {
+            for buffer in &mut self.buffers {
+                buffer.clear();
+            }
+        }
+    }
+
+    impl<const CHANNELS: usize, const SAMPLES: usize> Default
+        for StaticMemoryRecorder<CHANNELS, SAMPLES>
+    {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<const CHANNELS: usize, const SAMPLES: usize> TelemetryProvider
+        for StaticMemoryRecorder<CHANNELS, SAMPLES>
+    {
+        fn register_channel_full(&mut self, _meta: ChannelDescriptor<'_>) -> ChannelId {
+            if self.channel_count >= CHANNELS {
+                return ChannelId::INVALID;
+            }
+            let id = ChannelId::new(self.channel_count as u32);
+            self.channel_count += 1;
+            id
+        }
+
+        #[inline]
+        fn log(&mut self, id: ChannelId, value: f64) {
+            let idx = id.index() as usize;
+            if idx < self.channel_count {
+                self.buffers[idx].push(value);
+            }
+        }
+
+        fn log_vector(&mut self, id_x: ChannelId, id_y: ChannelId, id_z: ChannelId, vec: &Vec3) {
+            self.log(id_x, vec.x);
+            self.log(id_y, vec.y);
+            self.log(id_z, vec.z);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn chronological<const C: usize, const S: usize>(
+            recorder: &StaticMemoryRecorder<C, S>,
+            id: ChannelId,
+        ) -> impl Iterator<Item = f64> + '_ {
+            let (older, newer) = recorder.get_channel_data(id);
+            older.iter().chain(newer).copied()
+        }
+
+        #[test]
+        fn test_ring_buffer_overwrites() {
+            let mut recorder = StaticMemoryRecorder::<2, 5>::new();
+            let id = recorder.register_channel("test", "");
+
+            for i in 0..3 {
+                recorder.log(id, f64::from(i));
+            }
+            assert_eq!(
+                recorder.get_channel_data(id),
+                (&[0.0, 1.0, 2.0][..], &[][..])
+            );
+
+            for i in 3..12 {
+                recorder.log(id, f64::from(i));
+            }
+            assert_eq!(recorder.sample_count(id), 5);
+            assert!(chronological(&recorder, id).eq([7.0, 8.0, 9.0, 10.0, 11.0]));
+            assert_eq!(recorder.get_channel_data(id).0, &[7.0, 8.0, 9.0]);
+
+            recorder.clear();
+            assert_eq!(chronological(&recorder, id).count(), 0);
+        }
+
+        #[test]
+        fn test_channel_capacity() {
+            let mut recorder = StaticMemoryRecorder::<2, 5>::new();
+            let a = recorder.register_channel("a", "");
+            let b = recorder.register_channel("b", "");
+            let c = recorder.register_channel("c", "");
+
+            assert!(a.is_valid() && b.is_valid());
+            assert_eq!(c, ChannelId::INVALID);
+            assert_eq!(recorder.channel_count(), 2);
+
+            recorder.log(b, 1.0);
+            recorder.log(c, 2.0);
+            assert_eq!(chronological(&recorder, a).count(), 0);
+            assert!(chronological(&recorder, b).eq([1.0]));
+            assert_eq!(chronological(&recorder, c).count(), 0);
+        }
+
+        #[test]
+        fn test_zero_samples() {
+            let mut recorder = StaticMemoryRecorder::<1, 0>::new();
+            let id = recorder.register_channel("test", "");
+            recorder.log(id, 1.0);
+            assert_eq!(chronological(&recorder, id).count(), 0);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Fixed-capacity ring buffers shared by the telemetry recorders.
+//!
+//! Both buffers keep the most recent values and overwrite the oldest once
+//! full. [`RingBuffer`] stores `N` values inline and needs no allocator;
+//! with the `alloc` feature, [`DynRingBuffer`] stores a capacity chosen at
+//! runtime on the heap.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Oldest slot and fill level of a ring with a fixed number of slots.
+#[derive(Debug, Clone, Copy)]
+struct Cursor {
+    /// Slot of the oldest value.
+    head: usize,
+    /// Number of stored values.
+    len: usize,
+}
+
+impl Cursor {
+    const EMPTY: Self = Self { head: 0, len: 0 };
+
+    /// Records a push and returns the slot it writes, or `None` if the ring
+    /// has no slots.
+    fn advance(&mut self, capacity: usize) -> Option<usize> {
+        if capacity == 0 {
+            return None;
+        }
+        let slot = (self.head + self.len) % capacity;
+        if self.len < capacity {
+            self.len += 1;
+        } else {
+            self.head = (self.head + 1) % capacity;
+        }
+        Some(slot)
+    }
+
+    /// Returns the slot of the value at `index` in chronological order.
+    const fn slot(self, index: usize, capacity: usize) -> Option<usize> {
+        if index < self.len {
+            Some((self.head + index) % capacity)
+        } else {
+            None
+        }
+    }
+
+    /// Splits the stored values out of `slots`, oldest part first.
+    fn split<T>(self, slots: &[T]) -> (&[T], &[T]) {
+        let end = self.head + self.len;
+        if end <= slots.len() {
+            (&slots[self.head..end], &[])
+        } else {
+            (&slots[self.head..], &slots[..end - slots.len()])
+        }
+    }
+}
+
+/// Ring buffer holding the last `N` pushed values inline.
+///
+/// Needs no allocator, so it is available without the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// use vd_telemetry::ring_buffer::RingBuffer;
+///
+/// let mut ring = RingBuffer::<f64, 3>::new();
+/// for v in [1.0, 2.0, 3.0, 4.0] {
+///     ring.push(v);
+/// }
+/// assert!(ring.iter_chronological().copied().eq([2.0, 3.0, 4.0]));
+/// assert_eq!(ring.latest(), Some(&4.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T, const N: usize> {
+    /// Value slots; only those covered by `cursor` are stored values.
+    slots: [T; N],
+    cursor: Cursor,
+}
+
+impl<T: Default, const N: usize> RingBuffer<T, N> {
+    /// Creates an empty buffer with capacity `N`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_storage(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates an empty buffer that uses `storage` for its slots.
+    ///
+    /// The initial slot values are never read. Unlike [`RingBuffer::new`],
+    /// this works in const contexts.
+    #[must_use]
+    pub const fn with_storage(storage: [T; N]) -> Self {
+        Self {
+            slots: storage,
+            cursor: Cursor::EMPTY,
+        }
+    }
+
+    /// Appends a value, overwriting the oldest one if the buffer is full.
+    ///
+    /// A zero-capacity buffer discards every value.
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        if let Some(slot) = self.cursor.advance(N) {
+            self.slots[slot] = val;
+        }
+    }
+
+    /// Returns the stored values as two slices which, concatenated, are in
+    /// chronological order (oldest first).
+    #[must_use]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.cursor.split(&self.slots)
+    }
+
+    /// Iterates over the stored values, oldest first.
+    pub fn iter_chronological(&self) -> impl Iterator<Item = &T> {
+        let (older, newer) = self.as_slices();
+        older.iter().chain(newer)
+    }
+
+    /// Returns the value at `index` in chronological order, where 0 is the
+    /// oldest stored value.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.cursor.slot(index, N).map(|slot| &self.slots[slot])
+    }
+
+    /// Returns the most recently pushed value.
+    #[must_use]
+    pub fn latest(&self) -> Option<&T> {
+        self.get(self.cursor.len.checked_sub(1)?)
+    }
+
+    /// Returns the number of stored values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cursor.len
+    }
+
+    /// Returns true if no values are stored.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cursor.len == 0
+    }
+
+    /// Returns true once the next push will overwrite the oldest value.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.cursor.len == N
+    }
+
+    /// Returns the maximum number of values kept.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.cursor = Cursor::EMPTY;
+    }
+}
+
+impl<T: Default, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ring buffer holding the last `capacity` pushed values on the heap.
+///
+/// Storage is allocated up front, so [`DynRingBuffer::push`] never
+/// allocates.
+///
+/// # Example
+///
+/// ```
+/// use vd_telemetry::ring_buffer::DynRingBuffer;
+///
+/// let mut ring = DynRingBuffer::with_capacity(2);
+/// for v in [1.0, 2.0, 3.0] {
+///     ring.push(v);
+/// }
+/// assert!(ring.iter_chronological().copied().eq([2.0, 3.0]));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct DynRingBuffer<T> {
+    /// Stored values; grows up to `capacity`, then is overwritten in place.
+    buf: Vec<T>,
+    cursor: Cursor,
+    /// Maximum number of values kept.
+    capacity: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DynRingBuffer<T> {
+    /// Creates an empty buffer holding up to `capacity` values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            cursor: Cursor::EMPTY,
+            capacity,
+        }
+    }
+
+    /// Appends a value, overwriting the oldest one if the buffer is full.
+    ///
+    /// A zero-capacity buffer discards every value.
+    #[inline]
+    pub fn push(&mut self, val: T) {
+        if let Some(slot) = self.cursor.advance(self.capacity) {
+            if slot == self.buf.len() {
+                self.buf.push(val);
+            } else {
+                self.buf[slot] = val;
+            }
+        }
+    }
+
+    /// Returns the stored values as two slices which, concatenated, are in
+    /// chronological order (oldest first).
+    #[must_use]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.cursor.split(&self.buf)
+    }
+
+    /// Iterates over the stored values, oldest first.
+    pub fn iter_chronological(&self) -> impl Iterator<Item = &T> {
+        let (older, newer) = self.as_slices();
+        older.iter().chain(newer)
+    }
+
+    /// Returns the value at `index` in chronological order, where 0 is the
+    /// oldest stored value.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.cursor
+            .slot(index, self.capacity)
+            .map(|slot| &self.buf[slot])
+    }
+
+    /// Returns the most recently pushed value.
+    #[must_use]
+    pub fn latest(&self) -> Option<&T> {
+        self.get(self.cursor.len.checked_sub(1)?)
+    }
+
+    /// Returns the number of stored values.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cursor.len
+    }
+
+    /// Returns true if no values are stored.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cursor.len == 0
+    }
+
+    /// Returns true once the next push will overwrite the oldest value.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.cursor.len == self.capacity
+    }
+
+    /// Returns the maximum number of values kept.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all values, keeping the allocated storage.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.cursor = Cursor::EMPTY;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_before_full() {
+        let mut ring = RingBuffer::<u32, 4>::new();
+        assert!(ring.is_empty());
+        assert_eq!(ring.latest(), None);
+
+        ring.push(1);
+        ring.push(2);
+        assert_eq!(ring.len(), 2);
+        assert!(!ring.is_full());
+        assert_eq!(ring.latest(), Some(&2));
+        assert!(ring.iter_chronological().copied().eq([1, 2]));
+    }
+
+    #[test]
+    fn test_wraps_in_chronological_order() {
+        let mut ring = RingBuffer::<u32, 3>::new();
+        for i in 0..8 {
+            ring.push(i);
+            assert_eq!(ring.latest(), Some(&i));
+        }
+        assert!(ring.is_full());
+        assert_eq!(ring.len(), 3);
+        assert!(ring.iter_chronological().copied().eq([5, 6, 7]));
+        assert_eq!(ring.get(0), Some(&5));
+        assert_eq!(ring.get(2), Some(&7));
+        assert_eq!(ring.get(3), None);
+
+        let (older, newer) = ring.as_slices();
+        assert_eq!(older.len() + newer.len(), 3);
+
+        ring.clear();
+        assert!(ring.is_empty());
+        ring.push(9);
+        assert!(ring.iter_chronological().copied().eq([9]));
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        const EMPTY: RingBuffer<f64, 0> = RingBuffer::with_storage([]);
+        let mut ring = EMPTY;
+        ring.push(1.0);
+        assert!(ring.is_empty());
+        assert!(ring.is_full());
+        assert_eq!(ring.latest(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic_capacity() {
+        let mut ring = DynRingBuffer::<f64>::with_capacity(2);
+        assert_eq!(ring.capacity(), 2);
+        for v in [1.0, 2.0, 3.0] {
+            ring.push(v);
+        }
+        assert!(ring.iter_chronological().copied().eq([2.0, 3.0]));
+        assert_eq!(ring.get(0), Some(&2.0));
+        assert_eq!(ring.latest(), Some(&3.0));
+
+        ring.clear();
+        ring.push(4.0);
+        assert!(ring.iter_chronological().copied().eq([4.0]));
+
+        let mut empty = DynRingBuffer::<f64>::with_capacity(0);
+        empty.push(1.0);
+        assert!(empty.is_empty());
+        assert!(empty.is_full());
+        assert_eq!(empty.latest(), None);
+    }
+}
@@ -0,0 +1,331 @@
+//! Online statistics for telemetry streams.
+//!
+//! These types compute running statistics without storing full history,
+//! making them suitable for outlier detection during simulation.
+
+use crate::ring_buffer::DynRingBuffer;
+
+/// Sliding-window mean and standard deviation.
+///
+/// Uses Welford's online algorithm, adding each new sample and removing the
+/// sample that falls out of the window, so every update is O(1).
+///
+/// # Example
+///
+/// ```
+/// use vd_telemetry::stats::RollingStats;
+///
+/// let mut stats = RollingStats::new(3);
+/// for v in [1.0, 2.0, 3.0, 4.0] {
+///     stats.update(v);
+/// }
+/// // Window now holds 2, 3, 4
+/// assert!((stats.mean() - 3.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    /// Samples currently in the window.
+    window: DynRingBuffer<f64>,
+    /// Running mean of the window.
+    mean: f64,
+    /// Running sum of squared deviations from the mean.
+    m2: f64,
+}
+
+impl RollingStats {
+    /// Creates a new rolling statistics tracker over `window_size` samples.
+    ///
+    /// A window size of zero is treated as one.
+    #[must_use]
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window: DynRingBuffer::with_capacity(window_size.max(1)),
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Adds a sample, evicting the oldest one if the window is full.
+    pub fn update(&mut self, value: f64) {
+        let mut count = self.window.len();
+
+        if self.window.is_full() {
+            let old = self.window.get(0).copied().unwrap_or(0.0);
+            count -= 1;
+            if count == 0 {
+                self.mean = 0.0;
+                self.m2 = 0.0;
+            } else {
+                let delta = old - self.mean;
+                self.mean -= delta / count as f64;
+                self.m2 -= delta * (old - self.mean);
+            }
+        }
+
+        count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / count as f64;
+        self.m2 += delta * (value - self.mean);
+        // Guard against tiny negative values from rounding
+        self.m2 = libm::fmax(self.m2, 0.0);
+
+        self.window.push(value);
+    }
+
+    /// Returns the number of samples currently in the window.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns the window size.
+    #[must_use]
+    pub const fn window_size(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// Returns the mean of the window (0.0 if empty).
+    #[must_use]
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the sample variance of the window (0.0 with fewer than 2 samples).
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        let count = self.window.len();
+        if count < 2 {
+            return 0.0;
+        }
+        self.m2 / (count - 1) as f64
+    }
+
+    /// Returns the sample standard deviation of the window.
+    #[must_use]
+    pub fn std_dev(&self) -> f64 {
+        libm::sqrt(self.variance())
+    }
+
+    /// Returns how many standard deviations `value` lies from the mean.
+    ///
+    /// Returns 0.0 if the standard deviation is zero.
+    #[must_use]
+    pub fn z_score(&self, value: f64) -> f64 {
+        let std_dev = self.std_dev();
+        if std_dev == 0.0 {
+            return 0.0;
+        }
+        (value - self.mean) / std_dev
+    }
+
+    /// Clears all samples from the window.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+}
+
+/// Sliding-window average for smoothing a signal inline.
+///
+/// Keeps a running sum of the window, so every update is O(1). The sum is
+/// recomputed from the window once per `window` updates to stop rounding
+/// errors accumulating over long runs.
+///
+/// # Example
+///
+/// ```
+/// use vd_telemetry::stats::MovingAverage;
+///
+/// let mut avg = MovingAverage::new(2);
+/// assert!((avg.update(4.0) - 4.0).abs() < 1e-12);
+/// assert!((avg.update(6.0) - 5.0).abs() < 1e-12);
+/// assert!((avg.update(10.0) - 8.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MovingAverage {
+    /// Samples currently in the window.
+    window: DynRingBuffer<f64>,
+    /// Sum of the samples in `window`.
+    sum: f64,
+    /// Updates since `sum` was last recomputed from scratch.
+    since_resync: usize,
+}
+
+impl MovingAverage {
+    /// Creates a moving average over the last `window` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "MovingAverage::new: window must be non-zero");
+        Self {
+            window: DynRingBuffer::with_capacity(window),
+            sum: 0.0,
+            since_resync: 0,
+        }
+    }
+
+    /// Adds a sample, evicting the oldest one if the window is full, and
+    /// returns the new mean.
+    pub fn update(&mut self, value: f64) -> f64 {
+        if self.window.is_full() {
+            self.sum -= self.window.get(0).copied().unwrap_or(0.0);
+        }
+        self.window.push(value);
+        self.sum += value;
+
+        self.since_resync += 1;
+        if self.since_resync >= self.window.capacity() {
+            self.sum = self.window.iter_chronological().sum();
+            self.since_resync = 0;
+        }
+        self.current_mean()
+    }
+
+    /// Returns the mean of the window (0.0 if empty).
+    #[must_use]
+    pub fn current_mean(&self) -> f64 {
+        if self.window.is_empty() {
+            return 0.0;
+        }
+        self.sum / self.window.len() as f64
+    }
+
+    /// Returns the number of samples in the window, which saturates at the
+    /// window size.
+    #[must_use]
+    pub const fn sample_count(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Clears all samples from the window.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.since_resync = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Deterministic standard normal samples (LCG + Box-Muller).
+    fn normal_samples(n: usize, mean: f64, std_dev: f64) -> Vec<f64> {
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut uniform = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+        };
+        (0..n)
+            .map(|_| {
+                let u1 = uniform();
+                let u2 = uniform();
+                let z = libm::sqrt(-2.0 * libm::log(u1)) * libm::cos(core::f64::consts::TAU * u2);
+                libm::fma(z, std_dev, mean)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_converges_to_distribution() {
+        let mut stats = RollingStats::new(5000);
+        for v in normal_samples(20_000, 10.0, 2.0) {
+            stats.update(v);
+        }
+
+        assert_eq!(stats.count(), 5000);
+        assert!((stats.mean() - 10.0).abs() < 0.1, "mean {}", stats.mean());
+        assert!(
+            (stats.std_dev() - 2.0).abs() < 0.1,
+            "std {}",
+            stats.std_dev()
+        );
+    }
+
+    #[test]
+    fn test_sliding_window_matches_direct() {
+        let mut stats = RollingStats::new(4);
+        for v in [100.0, -50.0, 1.0, 2.0, 3.0, 4.0] {
+            stats.update(v);
+        }
+
+        // Window holds 1, 2, 3, 4
+        assert!((stats.mean() - 2.5).abs() < 1e-10);
+        assert!((stats.variance() - 5.0 / 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_z_score_outlier() {
+        let mut stats = RollingStats::new(1000);
+        for v in normal_samples(1000, 0.0, 1.0) {
+            stats.update(v);
+        }
+
+        assert!(stats.z_score(10.0) > 6.0);
+        assert!(stats.z_score(stats.mean()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_degenerate_cases() {
+        let mut stats = RollingStats::new(0);
+        assert_eq!(stats.window_size(), 1);
+        assert!(stats.variance().abs() < 1e-10);
+
+        stats.update(5.0);
+        stats.update(7.0);
+        assert!((stats.mean() - 7.0).abs() < 1e-10);
+        assert!(stats.z_score(100.0).abs() < 1e-10);
+
+        stats.reset();
+        assert_eq!(stats.count(), 0);
+    }
+
+    #[test]
+    fn test_moving_average() {
+        let mut avg = MovingAverage::new(3);
+        assert!(avg.current_mean().abs() < 1e-12);
+        assert!((avg.update(9.0) - 9.0).abs() < 1e-12);
+        assert_eq!(avg.sample_count(), 1);
+
+        for v in [1.0, 2.0, 3.0, 4.0] {
+            avg.update(v);
+        }
+        // Window holds 2, 3, 4
+        assert_eq!(avg.sample_count(), 3);
+        assert!((avg.current_mean() - 3.0).abs() < 1e-12);
+
+        avg.reset();
+        assert_eq!(avg.sample_count(), 0);
+        for _ in 0..10 {
+            assert!((avg.update(-2.5) + 2.5).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_moving_average_long_run() {
+        let window = 1000;
+        let samples = normal_samples(1_000_000, 1e6, 3.0);
+        let mut avg = MovingAverage::new(window);
+        for &v in &samples {
+            avg.update(v);
+        }
+
+        let tail = &samples[samples.len() - window..];
+        let direct = tail.iter().sum::<f64>() / window as f64;
+        assert_eq!(avg.sample_count(), window);
+        assert!((avg.current_mean() - direct).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "window must be non-zero")]
+    fn test_moving_average_zero_window() {
+        let _ = MovingAverage::new(0);
+    }
+}
@@ -0,0 +1,76 @@
+//! Approximate equality for floating-point physical quantities.
+
+use nalgebra::Vector3;
+
+use crate::units::TypedVec3;
+
+/// Equality within an absolute tolerance.
+///
+/// Implemented for `f64`, every unit type, `nalgebra::Vector3<f64>`
+/// (`vd_math::Vec3`) and [`TypedVec3`].
+///
+/// # Example
+///
+/// ```
+/// use vd_types::units::Meters;
+/// use vd_types::ApproxEq;
+///
+/// assert!(Meters(1.0).approx_eq(Meters(1.0 + 1e-12), 1e-9));
+/// assert!(!Meters(1.0).approx_eq(Meters(1.1), 1e-9));
+/// ```
+pub trait ApproxEq {
+    /// Returns true if `self` and `other` differ by less than `eps`.
+    ///
+    /// Vectors compare component-wise.
+    fn approx_eq(self, other: Self, eps: f64) -> bool;
+}
+
+impl ApproxEq for f64 {
+    #[inline]
+    fn approx_eq(self, other: Self, eps: f64) -> bool {
+        libm::fabs(self - other) < eps
+    }
+}
+
+impl ApproxEq for Vector3<f64> {
+    #[inline]
+    fn approx_eq(self, other: Self, eps: f64) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(&a, &b)| a.approx_eq(b, eps))
+    }
+}
+
+impl<U: Copy + ApproxEq> ApproxEq for TypedVec3<U> {
+    #[inline]
+    fn approx_eq(self, other: Self, eps: f64) -> bool {
+        self.x.approx_eq(other.x, eps)
+            && self.y.approx_eq(other.y, eps)
+            && self.z.approx_eq(other.z, eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+    use crate::units::{Position3, Seconds};
+
+    #[test]
+    fn test_approx_eq_impls() {
+        assert!(1.0_f64.approx_eq(1.0 + 1e-12, 1e-10));
+        assert!(!1.0_f64.approx_eq(1.0 + 1e-8, 1e-10));
+        assert!(!f64::NAN.approx_eq(f64::NAN, 1.0));
+
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert!(v.approx_eq(Vector3::new(1.0, 2.0, 3.0 + 1e-12), 1e-10));
+        assert!(!v.approx_eq(Vector3::new(1.0, 2.5, 3.0), 1e-10));
+
+        assert_approx_eq!(
+            Position3::new(1.0, 2.0, 3.0) * 2.0,
+            Position3::new(2.0, 4.0, 6.0),
+            1e-12
+        );
+        assert_approx_eq!(Seconds(0.1) + Seconds(0.2), Seconds(0.3), 1e-12);
+    }
+}
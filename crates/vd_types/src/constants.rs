@@ -3,7 +3,9 @@
 //! All constants are defined with SI units and high precision values
 //! from recognized scientific sources.
 
-use crate::units::{Kelvin, MetersPerSecondSquared, Pascals};
+use crate::units::{
+    Kelvin, Kilograms, KilogramsPerCubicMeter, Meters, MetersPerSecondSquared, Pascals,
+};
 
 /// Standard acceleration due to gravity (m/s^2).
 ///
@@ -21,7 +23,28 @@ pub const ATMOSPHERIC_PRESSURE: Pascals = Pascals(101_325.0);
 /// - Temperature: 288.15 K (15C)
 /// - Pressure: 101325 Pa
 /// - Humidity: 0%
-pub const AIR_DENSITY_STD: f64 = 1.225;
+pub const ISA_DENSITY_SEA_LEVEL: KilogramsPerCubicMeter = KilogramsPerCubicMeter(1.225);
+
+/// Untyped alias of [`ISA_DENSITY_SEA_LEVEL`] (kg/m^3), kept for
+/// compatibility.
+pub const AIR_DENSITY_STD: f64 = ISA_DENSITY_SEA_LEVEL.0;
+
+/// ISA temperature lapse rate in the troposphere (K/m).
+///
+/// Temperature falls by 6.5 K per kilometre from sea level up to the
+/// tropopause.
+pub const ISA_LAPSE_RATE: f64 = 0.0065;
+
+/// Geopotential altitude of the ISA tropopause (m).
+pub const ISA_TROPOPAUSE_ALTITUDE: Meters = Meters(11_000.0);
+
+/// ISA temperature at and above the tropopause (K).
+///
+/// 216.65 K = -56.5 C
+pub const ISA_TROPOPAUSE_TEMPERATURE: Kelvin = Kelvin(216.65);
+
+/// ISA pressure at the tropopause (Pa).
+pub const ISA_TROPOPAUSE_PRESSURE: Pascals = Pascals(22_632.1);
 
 /// Standard temperature for ISA conditions (K).
 ///
@@ -57,6 +80,60 @@ pub const UNIVERSAL_GAS_CONSTANT: f64 = 8.314_462_618;
 /// Boltzmann constant (J/K).
 pub const BOLTZMANN_CONSTANT: f64 = 1.380_649e-23;
 
+/// Typical density of tire rubber compound (kg/m^3).
+///
+/// Approximate reference value; real compounds range from about 1100 to
+/// 1300 kg/m^3. Tune against measured data before use in a physics model.
+pub const RUBBER_DENSITY: f64 = 1200.0;
+
+/// Nominal passenger car tire inflation pressure (Pa).
+///
+/// 2.3 bar gauge. Approximate reference value, not a substitute for the
+/// vehicle manufacturer's specification.
+pub const TIRE_NOMINAL_INFLATION: Pascals = Pascals(230_000.0);
+
+/// Nominal rolling resistance coefficient on asphalt (dimensionless).
+///
+/// Approximate reference value for a passenger car tire; depends strongly
+/// on tire construction, pressure, load and surface.
+pub const ROLLING_RESISTANCE_COEFF_NOMINAL: f64 = 0.012;
+
+/// Rough Pacejka Magic Formula stiffness factor B (dimensionless).
+///
+/// Order-of-magnitude reference only; B must be fitted per tire and per
+/// load case before use in a tire model.
+pub const PACEJKA_B_NOMINAL: f64 = 10.0;
+
+/// Nominal passenger car tire rolling radius (m).
+///
+/// Approximate reference value for a 205/55 R16 class tire; use the
+/// measured loaded radius of the actual tire in a vehicle model.
+pub const TIRE_RADIUS_NOMINAL: Meters = Meters(0.33);
+
+/// Nominal passenger car tire section width (m).
+///
+/// Approximate reference value (225 mm section).
+pub const TIRE_WIDTH_NOMINAL: Meters = Meters(0.225);
+
+/// Nominal mass of a passenger car wheel with tire (kg).
+///
+/// Approximate reference value; alloy wheels with tires typically weigh
+/// 15 to 25 kg.
+pub const WHEEL_MASS_NOMINAL: Kilograms = Kilograms(20.0);
+
+/// Nominal effective brake disc radius (m).
+///
+/// Approximate reference value for the pad contact radius of a passenger
+/// car front disc.
+pub const BRAKE_DISC_RADIUS_NOMINAL: Meters = Meters(0.13);
+
+/// Nominal peak tire-road friction coefficient on dry asphalt
+/// (dimensionless).
+///
+/// Approximate reference value; wet asphalt is typically 0.5 to 0.7 and
+/// snow 0.2 to 0.3.
+pub const ROAD_FRICTION_DRY: f64 = 1.0;
+
 /// Pi constant (for convenience).
 pub const PI: f64 = core::f64::consts::PI;
 
@@ -107,7 +184,7 @@ mod tests {
 
     #[test]
     fn test_atmospheric_pressure() {
-        assert!((ATMOSPHERIC_PRESSURE.0 - 101325.0).abs() < 1e-10);
+        assert!((ATMOSPHERIC_PRESSURE.0 - 101_325.0).abs() < 1e-10);
     }
 
     #[test]
@@ -126,4 +203,42 @@ mod tests {
     fn test_temperature_constant() {
         assert!((TEMPERATURE_STD.0 - 288.15).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_isa_constants() {
+        // ICAO Doc 7488/3 tabulated values
+        assert!((ISA_DENSITY_SEA_LEVEL.0 - 1.225).abs() < 1e-10);
+        assert!((AIR_DENSITY_STD - ISA_DENSITY_SEA_LEVEL.0).abs() < 1e-15);
+        assert!((ISA_LAPSE_RATE - 0.0065).abs() < 1e-15);
+        assert!((ISA_TROPOPAUSE_ALTITUDE.0 - 11_000.0).abs() < 1e-10);
+
+        // Tropopause temperature follows from the lapse rate
+        let t11 = TEMPERATURE_STD.0 - ISA_LAPSE_RATE * ISA_TROPOPAUSE_ALTITUDE.0;
+        assert!((ISA_TROPOPAUSE_TEMPERATURE.0 - t11).abs() < 1e-10);
+
+        // Tropopause pressure from the barometric formula, g0 M / (R L)
+        let exponent = G_FORCE.0 / (GAS_CONSTANT_AIR * ISA_LAPSE_RATE);
+        let p11 = ATMOSPHERIC_PRESSURE.0 * libm::pow(t11 / TEMPERATURE_STD.0, exponent);
+        assert!((ISA_TROPOPAUSE_PRESSURE.0 - p11).abs() < 1.0, "p11 = {p11}");
+    }
+
+    #[test]
+    fn test_tire_reference_constants() {
+        assert!((1000.0..1500.0).contains(&RUBBER_DENSITY));
+        assert!((TIRE_NOMINAL_INFLATION.as_bar() - 2.3).abs() < 1e-10);
+        assert!((150_000.0..400_000.0).contains(&TIRE_NOMINAL_INFLATION.0));
+        assert!((0.005..0.03).contains(&ROLLING_RESISTANCE_COEFF_NOMINAL));
+        assert!((4.0..20.0).contains(&PACEJKA_B_NOMINAL));
+    }
+
+    #[test]
+    fn test_wheel_reference_constants() {
+        let circumference = TWO_PI * TIRE_RADIUS_NOMINAL.0;
+        assert!((circumference - 2.073).abs() < 1e-3, "{circumference}");
+
+        assert!((0.15..0.35).contains(&TIRE_WIDTH_NOMINAL.0));
+        assert!((0.1..0.2).contains(&BRAKE_DISC_RADIUS_NOMINAL.0));
+        assert!((10.0..40.0).contains(&WHEEL_MASS_NOMINAL.0));
+        assert!((0.8..1.2).contains(&ROAD_FRICTION_DRY));
+    }
 }
@@ -26,8 +26,11 @@
 #![warn(missing_docs)]
 #![deny(unsafe_code)]
 
+pub mod approx;
 pub mod constants;
+pub mod test_utils;
 pub mod units;
 
+pub use approx::ApproxEq;
 pub use constants::*;
 pub use units::*;
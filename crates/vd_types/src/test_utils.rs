@@ -0,0 +1,32 @@
+//! Assertion helpers for tests of physical calculations.
+
+/// Asserts that two values are equal within an absolute tolerance.
+///
+/// Accepts any pair of values implementing [`ApproxEq`](crate::ApproxEq)
+/// and `Debug`, and prints both values and the tolerance on failure.
+///
+/// # Example
+///
+/// ```
+/// use vd_types::assert_approx_eq;
+/// use vd_types::units::{Meters, MetersPerSecond, Seconds};
+///
+/// let v = Meters(100.0) / Seconds(9.58);
+/// assert_approx_eq!(v, MetersPerSecond(10.438), 1e-3);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr, $eps:expr $(,)?) => {{
+        let (left, right, eps) = ($left, $right, $eps);
+        assert!(
+            $crate::ApproxEq::approx_eq(left, right, eps),
+            "assertion `left ~= right` failed (eps = {:?})\n  left: {:?}\n right: {:?}",
+            eps,
+            left,
+            right,
+        );
+    }};
+}
+
+#[doc(inline)]
+pub use crate::assert_approx_eq;
@@ -55,6 +55,38 @@ impl Radians {
         Self(result)
     }
 
+    /// Returns the signed shortest rotation from `self` to `target`.
+    ///
+    /// The result lies in (-PI, PI]; positive values are counterclockwise.
+    /// An exact half turn is reported as +PI.
+    #[inline]
+    #[must_use]
+    pub fn angle_to(self, target: Self) -> Self {
+        let diff = (target - self).normalize();
+        if diff.0 > core::f64::consts::PI {
+            diff - Self::FULL_ROTATION
+        } else {
+            diff
+        }
+    }
+
+    /// Returns the direction of the shortest turn from `self` to `target`.
+    ///
+    /// `1.0` is counterclockwise, `-1.0` clockwise and `0.0` means the
+    /// angles are already equal (modulo a full rotation).
+    #[inline]
+    #[must_use]
+    pub fn shortest_turn_direction(self, target: Self) -> f64 {
+        let diff = self.angle_to(target).0;
+        if diff > 0.0 {
+            1.0
+        } else if diff < 0.0 {
+            -1.0
+        } else {
+            0.0
+        }
+    }
+
     /// Full rotation (2*PI radians).
     pub const FULL_ROTATION: Self = Self(2.0 * core::f64::consts::PI);
 
@@ -0,0 +1,69 @@
+//! Linear blending between two values of the same unit.
+//!
+//! Used to cross-fade between models, e.g. dry and wet tyre grip as a
+//! function of track wetness.
+
+/// Blends `a` towards `b` by factor `t`, returning `a + t * (b - a)`.
+///
+/// `t` is not clamped, so values outside `[0, 1]` extrapolate. Use
+/// [`lerp_units_clamped`] to stay between `a` and `b`.
+///
+/// # Example
+///
+/// ```
+/// use vd_types::units::{lerp_units, Newtons};
+///
+/// let dry = Newtons(4000.0);
+/// let wet = Newtons(2800.0);
+/// let grip = lerp_units(dry, wet, 0.25);
+/// assert!((grip.0 - 3700.0).abs() < 1e-9);
+/// ```
+#[inline]
+#[must_use]
+pub fn lerp_units<T>(a: T, b: T, t: f64) -> T
+where
+    T: Into<f64> + From<f64>,
+{
+    let (a, b) = (a.into(), b.into());
+    T::from(a + t * (b - a))
+}
+
+/// Like [`lerp_units`], but clamps `t` to `[0, 1]` so the result never
+/// leaves the range between `a` and `b`.
+#[inline]
+#[must_use]
+pub fn lerp_units_clamped<T>(a: T, b: T, t: f64) -> T
+where
+    T: Into<f64> + From<f64>,
+{
+    lerp_units(a, b, t.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Meters, Radians};
+
+    #[test]
+    fn test_lerp_units() {
+        assert_eq!(lerp_units(Meters(0.0), Meters(10.0), 0.5), Meters(5.0));
+        assert_eq!(lerp_units(Meters(0.0), Meters(10.0), 2.0), Meters(20.0));
+        assert_eq!(lerp_units(Radians(1.0), Radians(3.0), 0.0), Radians(1.0));
+    }
+
+    #[test]
+    fn test_lerp_units_clamped() {
+        assert_eq!(
+            lerp_units_clamped(Meters(0.0), Meters(10.0), 2.0),
+            Meters(10.0)
+        );
+        assert_eq!(
+            lerp_units_clamped(Meters(0.0), Meters(10.0), -1.0),
+            Meters(0.0)
+        );
+        assert_eq!(
+            lerp_units_clamped(Meters(0.0), Meters(10.0), 0.3),
+            Meters(3.0)
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Conversions between raw values in common non-SI and SI units.
+//!
+//! Used when importing logged data whose channels mix units, e.g. a CSV with
+//! speeds in km/h and pressures in bar. Each channel gets a
+//! [`UnitConversion`] and its column is converted in one pass.
+
+use super::RPM;
+use crate::constants::{BAR_TO_PA, DEG_TO_RAD, KMH_TO_MS, MS_TO_KMH, PSI_TO_PA, RAD_TO_DEG};
+
+/// A conversion from one unit to another, applied to raw `f64` values.
+///
+/// # Example
+///
+/// ```
+/// use vd_types::units::UnitConversion;
+///
+/// let speeds_kmh = [36.0, 72.0];
+/// let mut speeds_ms = [0.0; 2];
+/// UnitConversion::KmhToMs.apply_slice(&speeds_kmh, &mut speeds_ms);
+/// assert!((speeds_ms[1] - 20.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnitConversion {
+    /// km/h to m/s.
+    KmhToMs,
+    /// m/s to km/h.
+    MsToKmh,
+    /// Degrees to radians.
+    DegToRad,
+    /// Radians to degrees.
+    RadToDeg,
+    /// Revolutions per minute to rad/s.
+    RpmToRadPerSec,
+    /// PSI to pascals.
+    PsiToPa,
+    /// Bar to pascals.
+    BarToPa,
+    /// Degrees Fahrenheit to degrees Celsius.
+    FahrenheitToCelsius,
+    /// Leaves the value unchanged.
+    #[default]
+    Identity,
+}
+
+impl UnitConversion {
+    /// Converts a single value.
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            Self::KmhToMs => value * KMH_TO_MS,
+            Self::MsToKmh => value * MS_TO_KMH,
+            Self::DegToRad => value * DEG_TO_RAD,
+            Self::RadToDeg => value * RAD_TO_DEG,
+            Self::RpmToRadPerSec => RPM(value).to_rad_per_sec().0,
+            Self::PsiToPa => value * PSI_TO_PA,
+            Self::BarToPa => value * BAR_TO_PA,
+            Self::FahrenheitToCelsius => (value - 32.0) * 5.0 / 9.0,
+            Self::Identity => value,
+        }
+    }
+
+    /// Converts every value of `input` into the matching slot of `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `output` have different lengths.
+    pub fn apply_slice(&self, input: &[f64], output: &mut [f64]) {
+        assert_eq!(
+            input.len(),
+            output.len(),
+            "UnitConversion::apply_slice: input and output lengths differ"
+        );
+        for (out, &value) in output.iter_mut().zip(input) {
+            *out = self.apply(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [UnitConversion; 9] = [
+        UnitConversion::KmhToMs,
+        UnitConversion::MsToKmh,
+        UnitConversion::DegToRad,
+        UnitConversion::RadToDeg,
+        UnitConversion::RpmToRadPerSec,
+        UnitConversion::PsiToPa,
+        UnitConversion::BarToPa,
+        UnitConversion::FahrenheitToCelsius,
+        UnitConversion::Identity,
+    ];
+
+    #[test]
+    fn test_apply_known_values() {
+        assert!((UnitConversion::KmhToMs.apply(36.0) - 10.0).abs() < 1e-12);
+        assert!((UnitConversion::MsToKmh.apply(10.0) - 36.0).abs() < 1e-12);
+        assert!((UnitConversion::DegToRad.apply(180.0) - core::f64::consts::PI).abs() < 1e-12);
+        assert!(
+            (UnitConversion::RadToDeg.apply(core::f64::consts::FRAC_PI_2) - 90.0).abs() < 1e-12
+        );
+        assert!(
+            (UnitConversion::RpmToRadPerSec.apply(60.0) - core::f64::consts::TAU).abs() < 1e-12
+        );
+        assert!((UnitConversion::PsiToPa.apply(1.0) - 6_894.757).abs() < 1e-9);
+        assert!((UnitConversion::BarToPa.apply(2.5) - 250_000.0).abs() < 1e-9);
+        assert!(UnitConversion::FahrenheitToCelsius.apply(32.0).abs() < 1e-12);
+        assert!((UnitConversion::FahrenheitToCelsius.apply(212.0) - 100.0).abs() < 1e-12);
+
+        for x in [-3.5, 0.0, 1e9] {
+            assert!((UnitConversion::Identity.apply(x) - x).abs() < 1e-12);
+        }
+        assert!(UnitConversion::Identity.apply(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_apply_slice_matches_apply() {
+        let input = [-40.0, 0.0, 12.5, 100.0, 6000.0];
+        let mut output = [0.0; 5];
+        for conversion in ALL {
+            conversion.apply_slice(&input, &mut output);
+            for (&x, &y) in input.iter().zip(&output) {
+                assert!((y - conversion.apply(x)).abs() < 1e-12, "{conversion:?}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths differ")]
+    fn test_apply_slice_length_mismatch() {
+        UnitConversion::Identity.apply_slice(&[1.0, 2.0], &mut [0.0]);
+    }
+}
@@ -103,3 +103,8 @@ define_unit!(
     /// Power in Watts (J/s = kg*m^2/s^3).
     Watts, "W"
 );
+
+define_unit!(
+    /// Mass density in kilograms per cubic meter.
+    KilogramsPerCubicMeter, "kg/m^3"
+);
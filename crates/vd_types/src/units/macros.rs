@@ -70,6 +70,27 @@ macro_rules! define_unit {
                 self.0.is_nan()
             }
 
+            /// Compares two values with a total order.
+            ///
+            /// Follows [`f64::total_cmp`], except that every NaN sorts
+            /// after all other values (including positive infinity) and
+            /// compares equal to any other NaN.
+            #[inline]
+            #[must_use]
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                match (self.0.is_nan(), other.0.is_nan()) {
+                    (false, false) => self.0.total_cmp(&other.0),
+                    (lhs, rhs) => lhs.cmp(&rhs),
+                }
+            }
+
+            /// Sorts a slice in ascending order using [`Self::total_cmp`],
+            /// placing NaN values last.
+            #[inline]
+            pub fn sort_slice(s: &mut [Self]) {
+                s.sort_unstable_by(Self::total_cmp);
+            }
+
             /// Zero value.
             pub const ZERO: Self = Self(0.0);
 
@@ -77,6 +98,20 @@ macro_rules! define_unit {
             pub const UNIT: &'static str = $unit;
         }
 
+        impl From<f64> for $name {
+            #[inline]
+            fn from(value: f64) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for f64 {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
         impl core::ops::Add for $name {
             type Output = Self;
             #[inline]
@@ -133,6 +168,13 @@ macro_rules! define_unit {
             }
         }
 
+        impl $crate::ApproxEq for $name {
+            #[inline]
+            fn approx_eq(self, other: Self, eps: f64) -> bool {
+                libm::fabs(self.0 - other.0) < eps
+            }
+        }
+
         impl core::fmt::Display for $name {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(f, "{} {}", self.0, Self::UNIT)
@@ -8,18 +8,25 @@ mod macros;
 
 mod angular;
 mod base;
+mod blend;
+mod conversion;
 mod derived;
 mod motion;
 mod ops;
+mod vector;
 
 pub use angular::*;
 pub use base::*;
+pub use blend::*;
+pub use conversion::*;
 pub use derived::*;
 pub use motion::*;
+pub use vector::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_approx_eq;
 
     const EPSILON: f64 = 1e-10;
 
@@ -32,10 +39,10 @@ mod tests {
         let m1 = Meters(10.0);
         let m2 = Meters(5.0);
 
-        assert!(approx_eq((m1 + m2).0, 15.0));
-        assert!(approx_eq((m1 - m2).0, 5.0));
-        assert!(approx_eq((m1 * 2.0).0, 20.0));
-        assert!(approx_eq((m1 / 2.0).0, 5.0));
+        assert_approx_eq!(m1 + m2, Meters(15.0), EPSILON);
+        assert_approx_eq!(m1 - m2, Meters(5.0), EPSILON);
+        assert_approx_eq!(m1 * 2.0, Meters(20.0), EPSILON);
+        assert_approx_eq!(m1 / 2.0, Meters(5.0), EPSILON);
     }
 
     #[test]
@@ -44,7 +51,7 @@ mod tests {
         let time = Seconds(10.0);
         let velocity = distance / time;
 
-        assert!(approx_eq(velocity.0, 10.0));
+        assert_approx_eq!(velocity, MetersPerSecond(10.0), EPSILON);
     }
 
     #[test]
@@ -53,7 +60,7 @@ mod tests {
         let time = Seconds(5.0);
         let distance = velocity * time;
 
-        assert!(approx_eq(distance.0, 100.0));
+        assert_approx_eq!(distance, Meters(100.0), EPSILON);
     }
 
     #[test]
@@ -62,7 +69,7 @@ mod tests {
         let time = Seconds(3.0);
         let acceleration = velocity / time;
 
-        assert!(approx_eq(acceleration.0, 10.0));
+        assert_approx_eq!(acceleration, MetersPerSecondSquared(10.0), EPSILON);
     }
 
     #[test]
@@ -71,7 +78,7 @@ mod tests {
         let acceleration = MetersPerSecondSquared(9.806_65);
         let force = mass * acceleration;
 
-        assert!(approx_eq(force.0, 98.0665));
+        assert_approx_eq!(force, Newtons(98.0665), EPSILON);
     }
 
     #[test]
@@ -92,10 +99,14 @@ mod tests {
         let rad_per_sec = rpm.to_rad_per_sec();
 
         // 60 RPM = 1 revolution per second = 2*PI rad/s
-        assert!(approx_eq(rad_per_sec.0, 2.0 * core::f64::consts::PI));
+        assert_approx_eq!(
+            rad_per_sec,
+            RadiansPerSecond(2.0 * core::f64::consts::PI),
+            EPSILON
+        );
 
         let rpm_back = RPM::from_rad_per_sec(rad_per_sec);
-        assert!(approx_eq(rpm_back.0, 60.0));
+        assert_approx_eq!(rpm_back, RPM(60.0), EPSILON);
     }
 
     #[test]
@@ -147,7 +158,7 @@ mod tests {
         let angular_velocity = RadiansPerSecond(100.0);
         let power = torque * angular_velocity;
 
-        assert!(approx_eq(power.0, 10_000.0));
+        assert_approx_eq!(power, Watts(10_000.0), EPSILON);
     }
 
     #[test]
@@ -173,4 +184,69 @@ mod tests {
         assert!((angle90.sin() - 1.0).abs() < 1e-10);
         assert!(angle90.cos().abs() < 1e-10);
     }
+
+    #[test]
+    fn test_radians_angle_to() {
+        use core::f64::consts::{FRAC_PI_2, PI};
+
+        // Wrapping backwards through zero is shorter than going forward
+        let diff = Radians(0.1).angle_to(Radians(6.1));
+        assert!(approx_eq(diff.0, 6.0 - 2.0 * PI));
+        assert!((diff.0 + 0.28).abs() < 0.01);
+        assert!(approx_eq(
+            Radians(0.1).shortest_turn_direction(Radians(6.1)),
+            -1.0
+        ));
+
+        assert!(approx_eq(
+            Radians(0.0).angle_to(Radians(FRAC_PI_2)).0,
+            FRAC_PI_2
+        ));
+        assert!(approx_eq(
+            Radians(0.0).shortest_turn_direction(Radians(FRAC_PI_2)),
+            1.0
+        ));
+
+        assert!(approx_eq(
+            Radians(1.0).angle_to(Radians(1.0 + 4.0 * PI)).0,
+            0.0
+        ));
+        assert!(approx_eq(
+            Radians(1.0).shortest_turn_direction(Radians(1.0)),
+            0.0
+        ));
+        assert!(approx_eq(Radians(0.0).angle_to(Radians(PI)).0, PI));
+    }
+
+    #[test]
+    fn test_sort_slice_total_order() {
+        use core::cmp::Ordering;
+
+        let mut lengths = [
+            Meters(3.0),
+            Meters(f64::NAN),
+            Meters(-1.5),
+            Meters(f64::INFINITY),
+            Meters(-f64::NAN),
+            Meters(0.25),
+            Meters(f64::NEG_INFINITY),
+        ];
+        Meters::sort_slice(&mut lengths);
+
+        let expected = [f64::NEG_INFINITY, -1.5, 0.25, 3.0, f64::INFINITY];
+        for (m, e) in lengths.iter().zip(expected) {
+            assert_eq!(m.total_cmp(&Meters(e)), Ordering::Equal, "{m:?} != {e}");
+        }
+        assert!(lengths[5].is_nan() && lengths[6].is_nan());
+
+        assert_eq!(Meters(1.0).total_cmp(&Meters(2.0)), Ordering::Less);
+        assert_eq!(
+            Meters(f64::NAN).total_cmp(&Meters(f64::INFINITY)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Meters(f64::NAN).total_cmp(&Meters(-f64::NAN)),
+            Ordering::Equal
+        );
+    }
 }
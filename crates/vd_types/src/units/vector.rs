@@ -0,0 +1,143 @@
+//! Three-dimensional vectors of unit-typed components.
+//!
+//! `TypedVec3` keeps the unit of each component in the type, so a position
+//! cannot be added to a velocity by accident. Convert to a raw
+//! `nalgebra::Vector3<f64>` (`vd_math::Vec3`) at the boundary with linear
+//! algebra code.
+//!
+//! Mixing units does not compile:
+//!
+//! ```compile_fail
+//! use vd_types::units::{Position3, Velocity3};
+//!
+//! let p = Position3::new(1.0, 2.0, 3.0);
+//! let v = Velocity3::new(1.0, 2.0, 3.0);
+//! let _ = p + v;
+//! ```
+
+use core::ops::{Add, Mul, Sub};
+
+use nalgebra::Vector3;
+
+use super::{Meters, MetersPerSecond, MetersPerSecondSquared, Newtons};
+
+/// A 3D vector whose components all share the unit `U`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypedVec3<U: Copy> {
+    /// X component.
+    pub x: U,
+    /// Y component.
+    pub y: U,
+    /// Z component.
+    pub z: U,
+}
+
+/// Position vector in meters.
+pub type Position3 = TypedVec3<Meters>;
+/// Velocity vector in meters per second.
+pub type Velocity3 = TypedVec3<MetersPerSecond>;
+/// Force vector in Newtons.
+pub type Force3 = TypedVec3<Newtons>;
+/// Acceleration vector in meters per second squared.
+pub type Acceleration3 = TypedVec3<MetersPerSecondSquared>;
+
+impl<U: Copy + Add<Output = U>> Add for TypedVec3<U> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<U: Copy + Sub<Output = U>> Sub for TypedVec3<U> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<U: Copy + Mul<f64, Output = U>> Mul<f64> for TypedVec3<U> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+/// Implements raw-value construction and conversion to `Vector3<f64>`.
+macro_rules! impl_typed_vec3 {
+    ($($unit:ident),*) => {
+        $(
+            impl TypedVec3<$unit> {
+                /// Creates a vector from raw component values.
+                #[inline]
+                #[must_use]
+                pub const fn new(x: f64, y: f64, z: f64) -> Self {
+                    Self {
+                        x: $unit(x),
+                        y: $unit(y),
+                        z: $unit(z),
+                    }
+                }
+
+                /// Zero vector.
+                pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+            }
+
+            impl From<TypedVec3<$unit>> for Vector3<f64> {
+                #[inline]
+                fn from(v: TypedVec3<$unit>) -> Self {
+                    Self::new(v.x.0, v.y.0, v.z.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_vec3!(Meters, MetersPerSecond, Newtons, MetersPerSecondSquared);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_unit_arithmetic() {
+        let a = Position3::new(1.0, 2.0, 3.0);
+        let b = Position3::new(0.5, 0.5, 0.5);
+
+        let sum: Position3 = a + b;
+        assert_eq!(sum, Position3::new(1.5, 2.5, 3.5));
+
+        let diff: Position3 = a - b;
+        assert_eq!(diff, Position3::new(0.5, 1.5, 2.5));
+
+        let scaled: Position3 = a * 2.0;
+        assert_eq!(scaled, Position3::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_into_raw_vector() {
+        let force = Force3::new(10.0, -20.0, 9.5);
+        let raw: Vector3<f64> = force.into();
+        assert_eq!(raw, Vector3::new(10.0, -20.0, 9.5));
+
+        let vel: Vector3<f64> = Velocity3::new(1.0, 0.0, 0.0).into();
+        let acc: Vector3<f64> = Acceleration3::ZERO.into();
+        assert_eq!(vel, Vector3::x());
+        assert_eq!(acc, Vector3::zeros());
+    }
+}